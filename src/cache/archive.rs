@@ -0,0 +1,465 @@
+use std::{
+
+    path::{
+
+        Component,
+        PathBuf,
+        Path,
+    },
+
+    io::{
+
+        BufWriter,
+        Write,
+    },
+
+    fs::{self, File},
+};
+
+use glob::{Pattern};
+
+use tar::{
+
+    EntryType,
+    Builder,
+    Archive,
+    Header,
+};
+
+use super::{CacheError};
+
+/// Compression level used by `zstdmt` in `@actions/cache`.
+const COMPRESSION_LEVEL: i32 = 3;
+
+/// Long distance matching window, equivalent to `zstd --long=30`.
+const WINDOW_LOG: u32 = 30;
+
+/// A file or directory that is part of a cache archive, along with the
+/// patterns excluding some of its children.
+#[derive(Clone, Debug)]
+pub struct ArchiveEntry {
+    path: PathBuf,
+    name: String,
+    excludes: Vec<Pattern>,
+}
+
+impl ArchiveEntry {
+    pub fn get_path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    pub fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+}
+
+/// Resolves glob patterns relative to the workspace, patterns starting with
+/// `!` exclude matches and a leading `~` expands to the home directory.
+pub fn resolve_paths<P: AsRef<str>>(workspace: &Path, patterns: impl AsRef<[P]>) -> Result<Vec<ArchiveEntry>, CacheError> {
+    let mut includes = Vec::new();
+    let mut excludes = Vec::new();
+
+    for pattern in patterns.as_ref() {
+        let pattern = pattern.as_ref().trim();
+
+        if pattern.is_empty() || pattern.starts_with('#') {
+            continue
+        }
+
+        match pattern.strip_prefix('!') {
+            Some(pattern) => excludes.push(Pattern::new({
+                expand_pattern(workspace, pattern)?
+                    .as_str()
+            })?),
+            None => includes.push({
+                expand_pattern(workspace, pattern)?
+            }),
+        }
+    }
+
+    let mut collection: Vec<ArchiveEntry> = Vec::new();
+
+    for pattern in includes {
+        for path in glob::glob(pattern.as_str())?.flatten() {
+            if excludes.iter().any(|exclude| exclude.matches_path(path.as_path())) {
+                continue
+            }
+
+            let name = get_relative_name(workspace, path.as_path());
+            if collection.iter().any(|entry| entry.name == name) {
+                continue
+            }
+
+            collection.push(ArchiveEntry {
+                excludes: excludes.clone(),
+                path,
+                name,
+            });
+        }
+    }
+
+    Ok(collection)
+}
+
+/// Writes a zstd compressed tarball of the given entries, paths are stored
+/// relative to the workspace the way `tar -P -C <workspace>` would.
+pub fn create(archive: &Path, workspace: &Path, entries: impl AsRef<[ArchiveEntry]>) -> Result<(), CacheError> {
+    let mut encoder = zstd::Encoder::new(BufWriter::new(File::create(archive)?), {
+        COMPRESSION_LEVEL
+    })?;
+
+    encoder.long_distance_matching(true)?;
+    encoder.window_log(WINDOW_LOG)?;
+
+    let mut builder = Builder::new(encoder);
+
+    for ArchiveEntry { path, name, excludes } in entries.as_ref() {
+        append_recursive(&mut builder, workspace, path.as_path(), name.as_str(), excludes.as_slice())?;
+    }
+
+    builder.into_inner()?
+        .finish()?
+        .flush()?;
+
+    Ok(())
+}
+
+/// Resolves the directories a cache may be restored into, the part of each
+/// included pattern before its first wildcard.
+pub fn resolve_roots<P: AsRef<str>>(workspace: &Path, patterns: impl AsRef<[P]>) -> Result<Vec<PathBuf>, CacheError> {
+    let mut collection = Vec::new();
+
+    for pattern in patterns.as_ref() {
+        let pattern = pattern.as_ref().trim();
+
+        if pattern.is_empty() || pattern.starts_with('#') || pattern.starts_with('!') {
+            continue
+        }
+
+        let pattern = expand_pattern(workspace, pattern)?;
+
+        let root: PathBuf = Path::new(pattern.as_str()).components()
+            .take_while(|component| {
+                !(component.as_os_str().to_string_lossy().contains(['*', '?', '[', '{']))
+            })
+            .collect();
+
+        collection.push(normalize_path(root.as_path()));
+    }
+
+    Ok(collection)
+}
+
+/// Unpacks a zstd compressed tarball into the workspace, rejecting entries
+/// which would end up outside of the given roots.
+pub fn extract(archive: &Path, workspace: &Path, roots: impl AsRef<[PathBuf]>) -> Result<(), CacheError> {
+    let mut decoder = zstd::Decoder::new(File::open(archive)?)?;
+    decoder.window_log_max(WINDOW_LOG + 1)?;
+
+    let mut archive = Archive::new(decoder);
+
+    archive.set_preserve_permissions(true);
+    archive.set_preserve_mtime(true);
+    archive.set_overwrite(true);
+
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+
+        let path = entry.path()?
+            .into_owned();
+
+        let rejection = || CacheError::Entry {
+            path: path.to_string_lossy()
+                .into_owned()
+        };
+
+        let supported = matches!(entry.header().entry_type(),
+            EntryType::Regular | EntryType::Directory | EntryType::Symlink);
+
+        if !(supported) || path.has_root() {
+            return Err(rejection())
+        }
+
+        let destination = normalize_path(workspace.join(path.as_path())
+            .as_path());
+
+        let Some(root) = roots.as_ref().iter().find(|root| destination.starts_with(root)) else {
+            return Err(rejection())
+        };
+
+        // Symbolic links restored earlier must not lead writes out of the root.
+        if destination != *root {
+            let (Some(parent), Some(name)) = (destination.parent(), destination.file_name()) else {
+                return Err(rejection())
+            };
+
+            fs::create_dir_all(parent)?;
+
+            let root = match root.canonicalize() {
+                Ok(root) => root,
+                Err(_) => return Err(rejection()),
+            };
+
+            if !(parent.canonicalize()?.join(name).starts_with(root)) {
+                return Err(rejection())
+            }
+        }
+
+        else if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        entry.unpack(destination.as_path())?;
+    }
+
+    Ok(())
+}
+
+fn append_recursive<W: Write>(builder: &mut Builder<W>, workspace: &Path, path: &Path, name: &str, excludes: &[Pattern]) -> Result<(), CacheError> {
+    let metadata = fs::symlink_metadata(path)?;
+
+    let mut header = Header::new_gnu();
+    header.set_metadata(&metadata);
+
+    if metadata.file_type().is_symlink() {
+        header.set_link_name(fs::read_link(path)?)?;
+        append_header(builder, &mut header, name, std::io::empty())?;
+    }
+
+    else if metadata.is_dir() {
+        append_header(builder, &mut header, format!("{name}/").as_str(), std::io::empty())?;
+
+        let mut children: Vec<_> = fs::read_dir(path)?
+            .collect::<Result<_, _>>()?;
+
+        children.sort_by_key(|child| child.file_name());
+
+        for child in children {
+            let path = child.path();
+
+            if excludes.iter().any(|exclude| exclude.matches_path(path.as_path())) {
+                continue
+            }
+
+            let name = get_relative_name(workspace, path.as_path());
+
+            append_recursive(builder, workspace, path.as_path(), name.as_str(), excludes)?;
+        }
+    }
+
+    else {
+        append_header(builder, &mut header, name, File::open(path)?)?;
+    }
+
+    Ok(())
+}
+
+/// Appends an entry without the path validation of the `tar` crate, which
+/// rejects the `..` components needed for paths outside of the workspace.
+fn append_header<W: Write>(builder: &mut Builder<W>, header: &mut Header, name: &str, data: impl std::io::Read) -> Result<(), CacheError> {
+    let bytes = name.as_bytes();
+    let slot = &mut header.as_old_mut().name;
+
+    if bytes.len() > slot.len() {
+        let mut long = Header::new_gnu();
+
+        long.as_old_mut().name[..13].copy_from_slice(b"././@LongLink");
+        long.set_entry_type(EntryType::GNULongName);
+        long.set_mode(0o644);
+        long.set_mtime(0);
+        long.set_size(bytes.len() as u64 + 1);
+        long.set_cksum();
+
+        let mut data = bytes.to_vec();
+        data.push(0);
+
+        builder.append(&long, data.as_slice())?;
+    }
+
+    let length = bytes.len().min(slot.len());
+
+    slot.fill(0);
+    slot[..length].copy_from_slice(&bytes[..length]);
+
+    header.set_cksum();
+
+    Ok(builder.append(header, data)?)
+}
+
+fn expand_pattern(workspace: &Path, pattern: &str) -> Result<String, CacheError> {
+    let pattern = match pattern.strip_prefix('~') {
+        Some(rest) => {
+            let home = std::env::var("HOME").map_err(|_| CacheError::MissingVariable {
+                variable: "HOME".to_owned()
+            })?;
+
+            format!("{home}{rest}")
+        },
+        None => pattern.to_owned(),
+    };
+
+    if Path::new(pattern.as_str()).is_absolute() { Ok(pattern) } else {
+        Ok(workspace.join(pattern)
+            .to_string_lossy()
+            .into_owned())
+    }
+}
+
+/// Resolves `.` and `..` components without touching the file system.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normal = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::ParentDir => { normal.pop(); },
+            Component::CurDir => {},
+            component => normal.push(component),
+        }
+    }
+
+    normal
+}
+
+/// Computes a `/` separated path relative to the workspace, stepping out
+/// with `..` components when the path lives outside of it.
+fn get_relative_name(workspace: &Path, path: &Path) -> String {
+    let workspace: Vec<Component> = workspace.components().collect();
+    let path: Vec<Component> = path.components().collect();
+
+    let common = workspace.iter().zip(path.iter())
+        .take_while(|(one, two)| one == two)
+        .count();
+
+    let mut components = Vec::new();
+
+    for _ in common..workspace.len() {
+        components.push("..".to_owned());
+    }
+
+    for component in &path[common..] {
+        components.push(component.as_os_str()
+            .to_string_lossy()
+            .into_owned());
+    }
+
+    components.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::{
+
+        path::{Path},
+        io::{Write},
+        fs::{self, File},
+    };
+
+    use tar::{
+
+        EntryType,
+        Builder,
+        Header,
+    };
+
+    use uuid::{Uuid};
+
+    use crate::cache::{CacheError};
+
+    use super::{
+
+        append_header,
+        resolve_roots,
+        resolve_paths,
+        extract,
+        create,
+    };
+
+    #[test]
+    fn test_round_trip() {
+        let directory = std::env::temp_dir().join(Uuid::new_v4().to_string());
+        let source = directory.join("source");
+        let target = directory.join("target");
+
+        fs::create_dir_all(source.join("cache/skip")).unwrap();
+        fs::create_dir_all(target.as_path()).unwrap();
+        fs::write(source.join("cache/keep.txt"), "keep").unwrap();
+        fs::write(source.join("cache/skip/file.txt"), "skip").unwrap();
+
+        let patterns = ["cache", "!cache/skip"];
+        let archive = directory.join("cache.tzst");
+
+        let entries = resolve_paths(source.as_path(), patterns).unwrap();
+        create(archive.as_path(), source.as_path(), entries).unwrap();
+
+        let roots = resolve_roots(target.as_path(), patterns).unwrap();
+        extract(archive.as_path(), target.as_path(), roots).unwrap();
+
+        assert_eq!(fs::read_to_string(target.join("cache/keep.txt")).unwrap(), "keep");
+        assert!(!(target.join("cache/skip").exists()));
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_extract_outside() {
+        let directory = std::env::temp_dir().join(Uuid::new_v4().to_string());
+        let target = directory.join("target");
+
+        fs::create_dir_all(target.as_path()).unwrap();
+
+        let write_archive = |path: &Path, entries: &[(&str, Option<&Path>)]| {
+            let encoder = zstd::Encoder::new(File::create(path).unwrap(), 3).unwrap();
+            let mut builder = Builder::new(encoder);
+
+            for (name, link) in entries {
+                let mut header = Header::new_gnu();
+                header.set_mode(0o644);
+
+                let data: &[u8] = match link {
+                    Some(link) => {
+                        header.set_entry_type(EntryType::Symlink);
+                        header.set_link_name(link).unwrap();
+                        header.set_size(0);
+                        b""
+                    },
+                    None => {
+                        header.set_entry_type(EntryType::Regular);
+                        header.set_size(4);
+                        b"evil"
+                    },
+                };
+
+                append_header(&mut builder, &mut header, name, data).unwrap();
+            }
+
+            builder.into_inner().unwrap()
+                .finish().unwrap()
+                .flush().unwrap();
+        };
+
+        let roots = resolve_roots(target.as_path(), ["cache"]).unwrap();
+        let archive = directory.join("parent.tzst");
+
+        write_archive(archive.as_path(), &[("../evil.txt", None)]);
+
+        let result = extract(archive.as_path(), target.as_path(), roots.as_slice());
+
+        assert!(matches!(result, Err(CacheError::Entry { .. })));
+        assert!(!(directory.join("evil.txt").exists()));
+
+        let archive = directory.join("link.tzst");
+
+        write_archive(archive.as_path(), &[
+            ("cache/link", Some(directory.as_path())),
+            ("cache/link/evil.txt", None),
+        ]);
+
+        let result = extract(archive.as_path(), target.as_path(), roots.as_slice());
+
+        assert!(matches!(result, Err(CacheError::Entry { .. })));
+        assert!(!(directory.join("evil.txt").exists()));
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,287 @@
+use std::{
+
+    path::{PathBuf},
+
+    io::{Error as IoError},
+    fs::{self},
+};
+
+use glob::{PatternError};
+use sha2::{Digest, Sha256};
+use thiserror::{Error};
+use uuid::{Uuid};
+
+use crate::{
+
+    client::{Number},
+    core::{log},
+};
+
+pub mod archive;
+pub mod service;
+
+use service::{CacheService};
+
+/// Version salt used by `@actions/cache`, bumping it invalidates every cache entry.
+const VERSION_SALT: &str = "1.0";
+
+/// Maximum size of a single cache archive accepted by the cache service.
+const SIZE_LIMIT: u64 = 10 * 1024 * 1024 * 1024;
+
+/// Name of the temporary archive, matches the one used by `@actions/cache`.
+const ARCHIVE_NAME: &str = "cache.tzst";
+
+/// Maximum number of keys (primary and restore keys) per lookup.
+const KEY_LIMIT: usize = 10;
+
+/// Maximum length of a single cache key.
+const KEY_LENGTH_LIMIT: usize = 512;
+
+#[derive(Error, Debug)]
+pub enum CacheError {
+    #[error("File error, reason: {0}")]
+    File(#[from] IoError),
+    #[error("Pattern error, reason: {0}")]
+    Pattern(#[from] PatternError),
+    #[error("Missing variable: {variable}")]
+    MissingVariable { variable: String },
+    #[error("Key validation failed, reason: '{reason}'")]
+    Validation { reason: String },
+    #[error("Path validation failed, none of the specified paths exist!")]
+    Nothing,
+    #[error("Cache size of {size} bytes exceeds the limit of {limit} bytes")]
+    Size { size: u64, limit: u64 },
+    #[error("Unable to reserve cache with key '{key}', another job may be creating this cache")]
+    Reserve { key: String },
+    #[error("{code} Cache service failed, reason: '{message}'")]
+    Service { code: u16, message: String },
+    #[error("Archive entry outside of the cached paths: '{path}'")]
+    Entry { path: String },
+    #[error("Malformed response, reason: '{reason}'")]
+    Malformed { reason: String },
+    #[error("Cache service is unavailable!")]
+    Unavailable,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompressionMethod {
+    Zstd,
+}
+
+impl CompressionMethod {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            CompressionMethod::Zstd => "zstd",
+        }
+    }
+}
+
+/// Computes the cache version the same way `@actions/cache` does, so entries
+/// can be shared with actions written against the JavaScript toolkit.
+pub fn get_cache_version<P: AsRef<str>>(paths: impl AsRef<[P]>, compression: CompressionMethod) -> String {
+    let mut components: Vec<&str> = paths.as_ref().iter()
+        .map(|path| path.as_ref())
+        .collect();
+
+    components.push(compression.to_str());
+
+    if cfg!(windows) {
+        components.push("windows-only");
+    }
+
+    components.push(VERSION_SALT);
+
+    format!("{:x}", Sha256::digest(components.join("|")))
+}
+
+/// Archives the given paths and saves them to the cache under `key`,
+/// returns the identifier of the created cache entry.
+pub fn save<P: AsRef<str>>(paths: impl AsRef<[P]>, key: impl AsRef<str>) -> Result<Number, CacheError> {
+    let paths = paths.as_ref();
+    let key = key.as_ref();
+
+    validate_key(key)?;
+
+    let workspace = get_workspace()?;
+    let files = archive::resolve_paths(workspace.as_path(), paths)?;
+
+    if files.is_empty() {
+        return Err(CacheError::Nothing)
+    }
+
+    let compression = CompressionMethod::Zstd;
+    let version = get_cache_version(paths, compression);
+
+    let directory = get_archive_directory()?;
+    let path = directory.join(ARCHIVE_NAME);
+
+    let result = (|| {
+
+        archive::create(path.as_path(), workspace.as_path(), files.as_slice())?;
+
+        let size = fs::metadata(path.as_path())?
+            .len();
+
+        log::info(format!("Cache size: ~{megabytes} MB ({size} B)", megabytes = {
+            size / (1024 * 1024)
+        }));
+
+        if size > SIZE_LIMIT {
+            return Err(CacheError::Size {
+                size, limit: SIZE_LIMIT
+            })
+        }
+
+        let service = CacheService::try_from_env()?;
+        let reservation = service.try_reserve(key, version.as_str(), size)?;
+
+        service.try_upload(&reservation, path.as_path())?;
+        service.try_commit(reservation, key, version.as_str(), size)
+    })();
+
+    let _ = fs::remove_dir_all(directory.as_path());
+
+    let number = result?;
+
+    log::info(format!("Cache saved with key: {key}"));
+
+    Ok(number)
+}
+
+/// Restores the cache entry matching `primary_key` or the first matching
+/// `restore_keys` prefix, returns the matched key or `None` on a cache miss.
+///
+/// Like `@actions/cache`, an exact hit is when the returned key equals
+/// `primary_key`, anything else is a partial hit from a restore key.
+pub fn restore<P, K>(paths: impl AsRef<[P]>, primary_key: impl AsRef<str>, restore_keys: impl AsRef<[K]>) -> Result<Option<String>, CacheError>
+where P: AsRef<str>, K: AsRef<str> {
+    let primary_key = primary_key.as_ref();
+    let paths = paths.as_ref();
+
+    let mut keys = vec![primary_key.to_owned()];
+
+    keys.extend(restore_keys.as_ref().iter()
+        .map(|key| key.as_ref().to_owned()));
+
+    if keys.len() > KEY_LIMIT {
+        return Err(CacheError::Validation {
+            reason: format!("keys are limited to {KEY_LIMIT} entries, got {count}", count = {
+                keys.len()
+            })
+        })
+    }
+
+    for key in keys.iter() {
+        validate_key(key)?;
+    }
+
+    let compression = CompressionMethod::Zstd;
+    let version = get_cache_version(paths, compression);
+
+    let service = CacheService::try_from_env()?;
+    let Some(entry) = service.try_lookup(keys.as_slice(), version.as_str())? else {
+
+        log::info(format!("Cache not found for input keys: {keys}", keys = {
+            keys.join(", ")
+        }));
+
+        return Ok(None)
+    };
+
+    let workspace = get_workspace()?;
+    let roots = archive::resolve_roots(workspace.as_path(), paths)?;
+
+    let directory = get_archive_directory()?;
+    let path = directory.join(ARCHIVE_NAME);
+
+    let result = (|| {
+
+        service.try_download(&entry, path.as_path())?;
+
+        let size = fs::metadata(path.as_path())?
+            .len();
+
+        log::info(format!("Cache size: ~{megabytes} MB ({size} B)", megabytes = {
+            size / (1024 * 1024)
+        }));
+
+        archive::extract(path.as_path(), workspace.as_path(), roots.as_slice())
+    })();
+
+    let _ = fs::remove_dir_all(directory.as_path());
+
+    result?;
+
+    log::info(format!("Cache restored from key: {key}", key = entry.get_key()));
+
+    Ok(Some(entry.get_key()
+        .to_owned()))
+}
+
+fn validate_key(key: &str) -> Result<(), CacheError> {
+    if key.len() > KEY_LENGTH_LIMIT {
+        return Err(CacheError::Validation {
+            reason: format!("key '{key}' cannot be larger than {KEY_LENGTH_LIMIT} characters")
+        })
+    }
+
+    if key.contains(',') {
+        return Err(CacheError::Validation {
+            reason: format!("key '{key}' cannot contain commas")
+        })
+    }
+
+    Ok(())
+}
+
+fn get_workspace() -> Result<PathBuf, CacheError> {
+    match std::env::var("GITHUB_WORKSPACE") {
+        Ok(workspace) if !(workspace.is_empty()) => Ok(PathBuf::from(workspace)),
+        _ => Ok(std::env::current_dir()?),
+    }
+}
+
+fn get_archive_directory() -> Result<PathBuf, CacheError> {
+    let directory = match std::env::var("RUNNER_TEMP") {
+        Ok(temporary) if !(temporary.is_empty()) => PathBuf::from(temporary),
+        _ => std::env::temp_dir(),
+    };
+
+    let directory = directory.join(Uuid::new_v4()
+        .to_string());
+
+    fs::create_dir_all(directory.as_path())?;
+
+    Ok(directory)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{
+
+        CompressionMethod,
+        get_cache_version,
+        validate_key,
+    };
+
+    #[test]
+    fn test_cache_version() {
+        // The version `@actions/cache` computes for the same paths and compression.
+        let version = get_cache_version(["node_modules"], {
+            CompressionMethod::Zstd
+        });
+
+        assert_eq!(version, "273877e14fd65d270b87a198edbfa2db5a43de567c9a548d2a2505b408befe24");
+        assert_ne!(version, get_cache_version(["node_modules", "dist"], {
+            CompressionMethod::Zstd
+        }));
+    }
+
+    #[test]
+    fn test_validate_key() {
+        assert!(validate_key("linux-cargo-abc123").is_ok());
+        assert!(validate_key("linux,cargo").is_err());
+        assert!(validate_key("x".repeat(513).as_str()).is_err());
+    }
+}
@@ -0,0 +1,520 @@
+use std::{
+
+    io::{
+
+        Write as IoWrite,
+        Read as IoRead,
+    },
+
+    time::{Duration},
+    path::{Path},
+    fs::{File},
+};
+
+use backoff::{
+
+    ExponentialBackoff as BackoffExponential,
+    Error as BackoffError,
+};
+
+use reqwest::{
+
+    blocking::{
+
+        Client as ReqwestClient,
+        RequestBuilder,
+        Response,
+    },
+
+    header::{
+
+        CONTENT_RANGE,
+        CONTENT_TYPE,
+        ACCEPT,
+        RANGE,
+    },
+
+    Url,
+};
+
+use secrecy::{
+
+    ExposeSecret,
+    Secret,
+};
+
+use serde::{
+
+    de::{DeserializeOwned},
+
+    Deserialize,
+    Serialize,
+};
+
+use crate::client::{Number};
+
+use super::{CacheError};
+
+/// Size of the chunks uploaded to and downloaded from the cache service.
+const CHUNK_SIZE: u64 = 32 * 1024 * 1024;
+
+/// Accept header required by the legacy artifact cache service.
+const LEGACY_ACCEPT: &str = "application/json;api-version=6.0-preview.1";
+
+/// Twirp service prefix of the results backed cache service.
+const RESULTS_SERVICE: &str = "twirp/github.actions.results.api.v1.CacheService";
+
+/// A cache entry found by a lookup, with a location it can be downloaded from.
+#[derive(Clone, Debug)]
+pub struct CacheEntry {
+    key: String,
+    location: Url,
+}
+
+impl CacheEntry {
+    pub fn get_key(&self) -> &str {
+        self.key.as_str()
+    }
+
+    pub fn get_location(&self) -> &Url {
+        &(self.location)
+    }
+}
+
+/// A reserved cache entry which is ready to receive the archive.
+#[derive(Clone, Debug)]
+pub enum CacheReservation {
+    Legacy { number: Number },
+    Results { location: Url },
+}
+
+/// The runtime cache service, either the legacy artifact cache service
+/// (`ACTIONS_CACHE_URL`) or the results backed service (`ACTIONS_RESULTS_URL`).
+#[derive(Clone, Debug)]
+pub enum CacheService {
+    Legacy { client: ReqwestClient, url: Url, token: Secret<String> },
+    Results { client: ReqwestClient, url: Url, token: Secret<String> },
+}
+
+impl CacheService {
+    pub fn try_from_env() -> Result<CacheService, CacheError> {
+        let token = Secret::new(get_variable("ACTIONS_RUNTIME_TOKEN")?);
+
+        let client = ReqwestClient::builder().user_agent("general-action")
+            .timeout(None::<Duration>).build().map_err(|_| {
+                CacheError::Unavailable
+            })?;
+
+        let results = std::env::var("ACTIONS_CACHE_SERVICE_V2")
+            .map(|variable| !(variable.is_empty()))
+            .unwrap_or_default();
+
+        if results {
+            let url = parse_url(get_variable("ACTIONS_RESULTS_URL")?)?;
+            return Ok(CacheService::Results { client, url, token })
+        }
+
+        let url = parse_url(get_variable("ACTIONS_CACHE_URL")?)?;
+        Ok(CacheService::Legacy { client, url, token })
+    }
+
+    /// Looks up the first entry matching one of the keys, where every key
+    /// after the first is treated as a prefix.
+    pub fn try_lookup(&self, keys: impl AsRef<[String]>, version: &str) -> Result<Option<CacheEntry>, CacheError> {
+        let keys = keys.as_ref();
+
+        match self {
+            CacheService::Legacy { client, url, token } => {
+
+                #[derive(Debug)]
+                #[derive(Deserialize)]
+                #[serde(rename_all = "camelCase")]
+                struct Capsule {
+                    cache_key: Option<String>,
+                    archive_location: Option<String>,
+                }
+
+                let response = send(client.get(join_url(url, "_apis/artifactcache/cache")?)
+                    .query(&[("keys", keys.join(",").as_str()), ("version", version)])
+                    .header(ACCEPT, LEGACY_ACCEPT)
+                    .bearer_auth(token.expose_secret()))?;
+
+                if response.status().as_u16() == 204 {
+                    return Ok(None)
+                }
+
+                match parse_json(response)? {
+                    Capsule { cache_key: Some(key), archive_location: Some(location) } => {
+                        Ok(Some(CacheEntry { key, location: parse_url(location)? }))
+                    },
+                    _ => Ok(None),
+                }
+            },
+            CacheService::Results { client, url, token } => {
+                let Some((key, restore_keys)) = keys.split_first() else {
+                    return Ok(None)
+                };
+
+                #[derive(Debug)]
+                #[derive(Serialize)]
+                struct Request<'r> {
+                    key: &'r str,
+                    restore_keys: &'r [String],
+                    version: &'r str,
+                }
+
+                #[derive(Debug)]
+                #[derive(Deserialize)]
+                struct Capsule {
+                    ok: bool,
+                    #[serde(default)]
+                    signed_download_url: String,
+                    #[serde(default)]
+                    matched_key: String,
+                }
+
+                let Capsule { ok, signed_download_url, matched_key } = {
+                    call(client, url, token, "GetCacheEntryDownloadURL", &Request {
+                        key, restore_keys, version,
+                    })?
+                };
+
+                if !(ok) || signed_download_url.is_empty() {
+                    return Ok(None)
+                }
+
+                Ok(Some(CacheEntry {
+                    location: parse_url(signed_download_url)?,
+                    key: matched_key,
+                }))
+            },
+        }
+    }
+
+    pub fn try_reserve(&self, key: &str, version: &str, size: u64) -> Result<CacheReservation, CacheError> {
+        match self {
+            CacheService::Legacy { client, url, token } => {
+
+                #[derive(Debug)]
+                #[derive(Deserialize)]
+                #[serde(rename_all = "camelCase")]
+                struct Capsule {
+                    cache_id: Number,
+                }
+
+                let payload = serde_json::json!({
+                    "key": key,
+                    "version": version,
+                    "cacheSize": size,
+                });
+
+                let result = send(client.post(join_url(url, "_apis/artifactcache/caches")?)
+                    .header(ACCEPT, LEGACY_ACCEPT)
+                    .bearer_auth(token.expose_secret())
+                    .json(&payload));
+
+                match result {
+                    Err(CacheError::Service { code: 409, .. }) => Err(CacheError::Reserve {
+                        key: key.to_owned()
+                    }),
+                    Err(error) => Err(error),
+                    Ok(response) => {
+                        let Capsule { cache_id } = parse_json(response)?;
+                        Ok(CacheReservation::Legacy { number: cache_id })
+                    },
+                }
+            },
+            CacheService::Results { client, url, token } => {
+
+                #[derive(Debug)]
+                #[derive(Deserialize)]
+                struct Capsule {
+                    ok: bool,
+                    #[serde(default)]
+                    signed_upload_url: String,
+                }
+
+                let payload = serde_json::json!({
+                    "key": key,
+                    "version": version,
+                });
+
+                let result: Result<Capsule, CacheError> = {
+                    call(client, url, token, "CreateCacheEntry", &payload)
+                };
+
+                match result {
+                    Err(CacheError::Service { code: 409, .. }) |
+                    Ok(Capsule { ok: false, .. }) => Err(CacheError::Reserve {
+                        key: key.to_owned()
+                    }),
+                    Err(error) => Err(error),
+                    Ok(Capsule { signed_upload_url, .. }) => Ok(CacheReservation::Results {
+                        location: parse_url(signed_upload_url)?
+                    }),
+                }
+            },
+        }
+    }
+
+    pub fn try_upload(&self, reservation: &CacheReservation, path: &Path) -> Result<(), CacheError> {
+        let client = self.get_client();
+
+        let mut file = File::open(path)?;
+        let mut blocks = Vec::new();
+        let mut start = 0;
+
+        loop {
+
+            let mut chunk = Vec::new();
+
+            (&mut file).take(CHUNK_SIZE)
+                .read_to_end(&mut chunk)?;
+
+            if chunk.is_empty() {
+                break
+            }
+
+            let end = start + chunk.len() as u64 - 1;
+
+            match (self, reservation) {
+                (CacheService::Legacy { url, token, .. }, CacheReservation::Legacy { number }) => {
+                    let location = join_url(url, format!("_apis/artifactcache/caches/{number}"))?;
+
+                    retry(|| client.patch(location.clone())
+                        .header(ACCEPT, LEGACY_ACCEPT)
+                        .header(CONTENT_TYPE, "application/octet-stream")
+                        .header(CONTENT_RANGE, format!("bytes {start}-{end}/*"))
+                        .bearer_auth(token.expose_secret())
+                        .body(chunk.clone()))?;
+                },
+                (_, CacheReservation::Results { location }) => {
+                    use base64::{
+
+                        engine::general_purpose::{STANDARD},
+                        Engine,
+                    };
+
+                    let block = STANDARD.encode(format!("{index:032}", index = {
+                        blocks.len()
+                    }));
+
+                    let mut location = location.clone();
+
+                    location.query_pairs_mut()
+                        .append_pair("comp", "block")
+                        .append_pair("blockid", block.as_str());
+
+                    retry(|| client.put(location.clone())
+                        .body(chunk.clone()))?;
+
+                    blocks.push(block);
+                },
+                _ => return Err(CacheError::Malformed {
+                    reason: "reservation does not belong to this cache service".to_owned()
+                }),
+            }
+
+            start = end + 1;
+        }
+
+        if let CacheReservation::Results { location } = reservation {
+            let mut location = location.clone();
+
+            location.query_pairs_mut()
+                .append_pair("comp", "blocklist");
+
+            let mut list = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?><BlockList>");
+
+            for block in blocks.iter() {
+                list.push_str(format!("<Latest>{block}</Latest>").as_str());
+            }
+
+            list.push_str("</BlockList>");
+
+            retry(|| client.put(location.clone())
+                .header(CONTENT_TYPE, "application/xml")
+                .body(list.clone()))?;
+        }
+
+        Ok(())
+    }
+
+    pub fn try_commit(&self, reservation: CacheReservation, key: &str, version: &str, size: u64) -> Result<Number, CacheError> {
+        match (self, reservation) {
+            (CacheService::Legacy { client, url, token }, CacheReservation::Legacy { number }) => {
+                let payload = serde_json::json!({
+                    "size": size,
+                });
+
+                send(client.post(join_url(url, format!("_apis/artifactcache/caches/{number}"))?)
+                    .header(ACCEPT, LEGACY_ACCEPT)
+                    .bearer_auth(token.expose_secret())
+                    .json(&payload))?;
+
+                Ok(number)
+            },
+            (CacheService::Results { client, url, token }, CacheReservation::Results { .. }) => {
+
+                #[derive(Debug)]
+                #[derive(Deserialize)]
+                struct Capsule {
+                    ok: bool,
+                    #[serde(default)]
+                    entry_id: String,
+                }
+
+                let payload = serde_json::json!({
+                    "key": key,
+                    "version": version,
+                    "size_bytes": size.to_string(),
+                });
+
+                let Capsule { ok, entry_id } = {
+                    call(client, url, token, "FinalizeCacheEntryUpload", &payload)?
+                };
+
+                if !(ok) {
+                    return Err(CacheError::Reserve {
+                        key: key.to_owned()
+                    })
+                }
+
+                entry_id.parse().map_err(|_| CacheError::Malformed {
+                    reason: format!("invalid cache entry identifier: '{entry_id}'")
+                })
+            },
+            _ => Err(CacheError::Malformed {
+                reason: "reservation does not belong to this cache service".to_owned()
+            }),
+        }
+    }
+
+    /// Downloads an entry in ranged chunks, the total size is taken from the
+    /// `Content-Range` header of the first chunk.
+    pub fn try_download(&self, entry: &CacheEntry, path: &Path) -> Result<(), CacheError> {
+        let client = self.get_client();
+        let location = entry.get_location();
+
+        let mut file = File::create(path)?;
+        let mut start = 0;
+        let mut total = None;
+
+        loop {
+
+            let end = start + CHUNK_SIZE - 1;
+
+            let response = retry(|| client.get(location.clone())
+                .header(RANGE, format!("bytes={start}-{end}")))?;
+
+            let partial = response.status().as_u16() == 206;
+
+            if total.is_none() {
+                total = response.headers().get(CONTENT_RANGE)
+                    .and_then(|range| range.to_str().ok())
+                    .and_then(|range| range.rsplit_once('/'))
+                    .and_then(|(_, total)| total.parse::<u64>().ok());
+            }
+
+            let bytes = response.bytes().map_err(|_| {
+                CacheError::Unavailable
+            })?;
+
+            file.write_all(bytes.as_ref())?;
+
+            start += bytes.len() as u64;
+
+            match total {
+                Some(total) if partial && start < total && !(bytes.is_empty()) => continue,
+                _ => break,
+            }
+        }
+
+        Ok(file.flush()?)
+    }
+
+    fn get_client(&self) -> &ReqwestClient {
+        match self {
+            CacheService::Legacy { client, .. } => client,
+            CacheService::Results { client, .. } => client,
+        }
+    }
+}
+
+fn get_variable(variable: &str) -> Result<String, CacheError> {
+    match std::env::var(variable) {
+        Ok(value) if !(value.is_empty()) => Ok(value),
+        _ => Err(CacheError::MissingVariable {
+            variable: variable.to_owned()
+        }),
+    }
+}
+
+fn parse_url(url: impl AsRef<str>) -> Result<Url, CacheError> {
+    let url = url.as_ref();
+
+    Url::parse(url).map_err(|_| CacheError::Malformed {
+        reason: format!("invalid url: '{url}'")
+    })
+}
+
+fn join_url(url: &Url, endpoint: impl AsRef<str>) -> Result<Url, CacheError> {
+    let endpoint = endpoint.as_ref();
+
+    let base = match url.as_str().ends_with('/') {
+        false => parse_url(format!("{url}/"))?,
+        true => url.clone(),
+    };
+
+    base.join(endpoint).map_err(|_| CacheError::Malformed {
+        reason: format!("invalid endpoint: '{endpoint}'")
+    })
+}
+
+fn call<T, R>(client: &ReqwestClient, url: &Url, token: &Secret<String>, method: &str, payload: &T) -> Result<R, CacheError>
+where T: Serialize + ?Sized, R: DeserializeOwned {
+    parse_json(send(client.post(join_url(url, format!("{RESULTS_SERVICE}/{method}"))?)
+        .bearer_auth(token.expose_secret())
+        .json(payload))?)
+}
+
+fn send(request: RequestBuilder) -> Result<Response, CacheError> {
+    let response = request.send().map_err(|_| {
+        CacheError::Unavailable
+    })?;
+
+    if response.status().is_success() { Ok(response) } else {
+        Err(CacheError::Service {
+            code: response.status().as_u16(),
+            message: response.text()
+                .unwrap_or_default(),
+        })
+    }
+}
+
+fn retry(request: impl Fn() -> RequestBuilder) -> Result<Response, CacheError> {
+    backoff::retry(BackoffExponential::default(), || {
+        match send(request()) {
+            Err(error @ CacheError::Service { code: 400..=499, .. }) => {
+                Err(BackoffError::permanent(error))
+            },
+            Err(error) => Err(BackoffError::transient(error)),
+            Ok(response) => Ok(response),
+        }
+    }).map_err(|error| match error {
+        BackoffError::Transient { err, .. } => err,
+        BackoffError::Permanent(err) => err,
+    })
+}
+
+fn parse_json<T: DeserializeOwned>(response: Response) -> Result<T, CacheError> {
+    let notation = response.text().map_err(|_| {
+        CacheError::Unavailable
+    })?;
+
+    serde_json::from_str(notation.as_str()).map_err(|error| {
+        CacheError::Malformed {
+            reason: error.to_string()
+        }
+    })
+}
@@ -47,7 +47,11 @@ use crate::client::{
         Client,
     },
 
-    models::common::user::{User},
+    models::common::{
+
+        repository::{RepositoryDraft},
+        user::{User},
+    },
 
     GitHubProperties,
     GitHubResult,
@@ -94,6 +98,19 @@ impl Account {
         }
     }
 
+    /// The account of a user as reported by the API, `None` for bots and mannequins.
+    pub(crate) fn from_user(client: &Client, user: &User) -> Option<Account> {
+        match user {
+            User::Organization { .. } => Some(Account::Organization({
+                HandleOrganization { client: client.clone(), name: user.get_name().to_lowercase() }
+            })),
+            User::User { .. } => Some(Account::User({
+                HandleUser { client: client.clone(), name: user.get_name().to_lowercase() }
+            })),
+            _ => None,
+        }
+    }
+
     pub(crate) fn get_client<'a>(&'a self) -> &'a Client {
         match self {
             Account::Organization(organization) => organization.get_client(),
@@ -127,6 +144,8 @@ impl Account {
     pub fn try_get_repository(&self, name: impl AsRef<str>) -> GitHubResult<HandleRepository, AccountError> { Ok(HandleRepository::try_fetch(self, name)?) }
 
     pub fn try_get_all_repositories(&self) -> GitHubResult<Vec<HandleRepository>, AccountError> { Ok(HandleRepository::try_fetch_all(self)?) }
+
+    pub fn try_create_repository(&self, draft: impl Into<RepositoryDraft>) -> GitHubResult<HandleRepository, AccountError> { Ok(HandleRepository::try_create(self, &(draft.into()))?) }
 }
 
 impl From<HandleOrganization> for Account {
@@ -1,6 +1,7 @@
 use std::{
 
     borrow::{Cow}, 
+    path::{Path},
 
     fmt::{
 
@@ -16,10 +17,73 @@ use serde::{Deserialize};
 
 use crate::client::{
     
-    repository::{HandleRepositoryError},
+    repository::{
+
+        HandleRepositoryError,
+        HandleRepository,
+    },
+
+    account::{Account},
+
+    actions::{
+
+        variable::{
+
+            VariableReport,
+            VariableError,
+            HandleVariable,
+        },
+
+        secret::{
+
+            SecretError,
+            SecretValue,
+            HandleSecret,
+        },
+
+        ActionsScope,
+    },
+
+    ruleset::{
+
+        RulesetScope,
+        RulesetError,
+        HandleRuleset,
+    },
+
+    hook::{
+
+        HookScope,
+        HookError,
+        HandleHook,
+    },
+
+    models::common::{
+
+        actions::{ActionsVisibility},
+        ruleset::{Ruleset},
+        repository::{RepositoryDraft},
+        team::{
+
+            TeamDefinition,
+            TeamContent,
+        },
+
+        membership::{
+
+            InvitationDraft,
+            OrganizationRole,
+            MemberFilter,
+            Membership,
+            Invitation,
+        },
+
+        hook::{Hook},
+    },
 
     client::{
 
+        ClientResponseError,
         ClientError,
         Client, 
     },
@@ -28,6 +92,7 @@ use crate::client::{
     
     GitHubProperties,
     GitHubResult, GitHubEndpoint,
+    Number,
 };
 
 pub mod actions;
@@ -35,6 +100,13 @@ pub mod team;
 
 use self::{actions::{HandleActions}, team::{HandleTeamError, HandleTeam}};
 
+use self::team::reconcile::{
+
+    ReconcileOptions,
+    ReconcileError,
+    TeamPlan,
+};
+
 
 #[derive(Error, Debug)]
 pub enum HandleOrganizationError {
@@ -42,8 +114,18 @@ pub enum HandleOrganizationError {
     Client(#[from] ClientError),
     #[error("Team error!")]
     Team(#[from] HandleTeamError),
+    #[error("Team reconciliation error!")]
+    Reconcile(#[from] ReconcileError),
     #[error("Repository error!")]
     Repository(#[from] HandleRepositoryError),
+    #[error("Secret error!")]
+    Secret(#[from] SecretError),
+    #[error("Variable error!")]
+    Variable(#[from] VariableError),
+    #[error("Ruleset error!")]
+    Ruleset(#[from] RulesetError),
+    #[error("Hook error!")]
+    Hook(#[from] HookError),
     #[error("Not an organization, got: '{account:?}'")]
     Organization { account: User },
 }
@@ -70,17 +152,320 @@ impl HandleOrganization {
         Ok(is_verified)
     }
 
+    fn try_fetch_users(&self, endpoint: impl AsRef<str>, query: &[(&'static str, String)]) -> GitHubResult<Vec<User>, HandleOrganizationError> {
+        let endpoint = endpoint.as_ref();
+
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let users: Vec<User> = {
+
+                self.client.get(endpoint)?
+                    .query(query)
+                    .query(&[("per_page", 100), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = users.len();
+
+            collection.extend(users);
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub fn try_get_members(&self, filter: &MemberFilter) -> GitHubResult<Vec<User>, HandleOrganizationError> {
+        self.try_fetch_users(format!("orgs/{self}/members"), filter.get_query().as_slice())
+    }
+
+    pub fn try_get_all_members(&self) -> GitHubResult<Vec<User>, HandleOrganizationError> {
+        self.try_get_members(&MemberFilter::default())
+    }
+
+    /// Whether the user is a member, members with a pending invitation are not.
+    pub fn try_is_member(&self, user: impl AsRef<str>) -> GitHubResult<bool, HandleOrganizationError> {
+        let user = user.as_ref();
+
+        let result = {
+
+            self.client.get(format!("orgs/{self}/members/{user}"))?
+                .send()
+        };
+
+        match result {
+            Err(ClientError::Response(ClientResponseError::Nothing { .. })) => Ok(false),
+            Err(error) => Err(error.into()),
+            Ok(_) => Ok(true),
+        }
+    }
+
+    /// Gets the membership of a user, `None` if they are neither a member nor invited.
+    pub fn try_get_membership(&self, user: impl AsRef<str>) -> GitHubResult<Option<Membership>, HandleOrganizationError> {
+        let user = user.as_ref();
+
+        let result = {
+
+            self.client.get(format!("orgs/{self}/memberships/{user}"))?
+                .send()
+        };
+
+        match result {
+            Err(ClientError::Response(ClientResponseError::Nothing { .. })) => Ok(None),
+            Err(error) => Err(error.into()),
+            Ok(response) => Ok(Some(response.json()?)),
+        }
+    }
+
+    /// Changes the role of a member, users who are not members yet are invited.
+    pub fn try_set_membership(&self, user: impl AsRef<str>, role: OrganizationRole) -> GitHubResult<Membership, HandleOrganizationError> {
+        let user = user.as_ref();
+        let payload = serde_json::json!({ "role": role });
+
+        Ok(self.client.put(format!("orgs/{self}/memberships/{user}"))?
+            .json(&payload)
+            .send()?.json()?)
+    }
+
+    /// Removes a member from the organization and its teams, or cancels their invitation.
+    pub fn try_remove_membership(&self, user: impl AsRef<str>) -> GitHubResult<(), HandleOrganizationError> {
+        let user = user.as_ref();
+
+        self.client.delete(format!("orgs/{self}/memberships/{user}"))?
+            .send()?;
+
+        Ok(())
+    }
+
+    /// Lists the invitations which were neither accepted nor cancelled.
+    pub fn try_get_invitations(&self) -> GitHubResult<Vec<Invitation>, HandleOrganizationError> {
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let invitations: Vec<Invitation> = {
+
+                self.client.get(format!("orgs/{self}/invitations"))?
+                    .query(&[("per_page", 100), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = invitations.len();
+
+            collection.extend(invitations);
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub fn try_create_invitation(&self, draft: &InvitationDraft) -> GitHubResult<Invitation, HandleOrganizationError> {
+        Ok(self.client.post(format!("orgs/{self}/invitations"))?
+            .json(draft)
+            .send()?.json()?)
+    }
+
+    pub fn try_cancel_invitation(&self, invitation: impl Into<Number>) -> GitHubResult<(), HandleOrganizationError> {
+        let invitation = invitation.into();
+
+        self.client.delete(format!("orgs/{self}/invitations/{invitation}"))?
+            .send()?;
+
+        Ok(())
+    }
+
+    /// Lists users with access to repositories of the organization who are not
+    /// members, the role of the filter is ignored.
+    pub fn try_get_outside_collaborators(&self, filter: &MemberFilter) -> GitHubResult<Vec<User>, HandleOrganizationError> {
+        let query: Vec<_> = filter.get_query()
+            .into_iter()
+            .filter(|(key, _)| *key == "filter")
+            .collect();
+
+        self.try_fetch_users(format!("orgs/{self}/outside_collaborators"), query.as_slice())
+    }
+
+    /// Turns a member into an outside collaborator, they keep their access to
+    /// repositories but lose their team memberships.
+    pub fn try_convert_to_outside_collaborator(&self, user: impl AsRef<str>) -> GitHubResult<(), HandleOrganizationError> {
+        let user = user.as_ref();
+
+        self.client.put(format!("orgs/{self}/outside_collaborators/{user}"))?
+            .send()?;
+
+        Ok(())
+    }
+
+    /// Removes an outside collaborator from all repositories of the organization.
+    pub fn try_remove_outside_collaborator(&self, user: impl AsRef<str>) -> GitHubResult<(), HandleOrganizationError> {
+        let user = user.as_ref();
+
+        self.client.delete(format!("orgs/{self}/outside_collaborators/{user}"))?
+            .send()?;
+
+        Ok(())
+    }
+
     pub fn try_get_team(&self, slug: impl AsRef<str>) -> GitHubResult<HandleTeam, HandleOrganizationError> {
         Ok(HandleTeam::try_fetch(self, slug.as_ref())?)
     }
 
+    pub fn try_has_team(&self, slug: impl AsRef<str>) -> GitHubResult<bool, HandleOrganizationError> {
+        match HandleTeam::try_fetch(self, slug.as_ref()) {
+            Err(HandleTeamError::Nothing { .. }) => Ok(false),
+            Err(error) => Err(error.into()),
+            Ok(_) => Ok(true),
+        }
+    }
+
     pub fn try_get_all_teams(&self) -> GitHubResult<Vec<HandleTeam>, HandleOrganizationError> {
         Ok(HandleTeam::try_fetch_all(self)?)
     }
 
+    /// Creates a team, its name is required.
+    pub fn try_create_team(&self, content: &TeamContent) -> GitHubResult<HandleTeam, HandleOrganizationError> {
+        Ok(HandleTeam::try_create(self, content)?)
+    }
+
+    pub fn try_delete_team(&self, team: HandleTeam) -> GitHubResult<(), HandleOrganizationError> {
+        Ok(team.try_delete()?)
+    }
+
+    /// Compares team definitions with the teams of the organization, with
+    /// `prune` undefined teams are deleted.
+    pub fn try_plan_teams(&self, definitions: impl AsRef<[TeamDefinition]>, prune: bool) -> GitHubResult<TeamPlan, HandleOrganizationError> {
+        Ok(TeamPlan::try_fetch(self, definitions.as_ref(), prune)?)
+    }
+
+    /// Prints the plan for the team definitions and applies it, see [`ReconcileOptions`].
+    pub fn try_reconcile_teams(&self, definitions: impl AsRef<[TeamDefinition]>, options: &ReconcileOptions) -> GitHubResult<TeamPlan, HandleOrganizationError> {
+        Ok(TeamPlan::try_reconcile(self, definitions.as_ref(), options)?)
+    }
+
+    pub fn try_reconcile_teams_from_file(&self, path: impl AsRef<Path>, options: &ReconcileOptions) -> GitHubResult<TeamPlan, HandleOrganizationError> {
+        let definitions = team::reconcile::try_read_definitions(path)?;
+        self.try_reconcile_teams(definitions, options)
+    }
+
+    pub fn try_create_repository(&self, draft: impl Into<RepositoryDraft>) -> GitHubResult<HandleRepository, HandleOrganizationError> {
+        Ok(HandleRepository::try_create(&(Account::Organization(self.clone())), &(draft.into()))?)
+    }
+
     pub fn get_actions(&self) -> HandleActions {
         HandleActions::from(self)
     }
+
+    pub fn get_actions_scope(&self) -> ActionsScope {
+        ActionsScope::Organization { organization: self.clone() }
+    }
+
+    pub fn try_get_secret(&self, name: impl AsRef<str>) -> GitHubResult<HandleSecret, HandleOrganizationError> {
+        Ok(self.get_actions_scope().try_get_secret(name)?)
+    }
+
+    pub fn try_has_secret(&self, name: impl AsRef<str>) -> GitHubResult<bool, HandleOrganizationError> {
+        Ok(self.get_actions_scope().try_has_secret(name)?)
+    }
+
+    pub fn try_get_all_secrets(&self) -> GitHubResult<Vec<HandleSecret>, HandleOrganizationError> {
+        Ok(self.get_actions_scope().try_get_all_secrets()?)
+    }
+
+    pub fn try_create_secret(&self, name: impl AsRef<str>, value: &SecretValue, visibility: ActionsVisibility) -> GitHubResult<HandleSecret, HandleOrganizationError> {
+        Ok(self.get_actions_scope().try_create_secret_with_visibility(name, value, visibility)?)
+    }
+
+    pub fn try_delete_secret(&self, secret: HandleSecret) -> GitHubResult<(), HandleOrganizationError> {
+        Ok(secret.try_delete()?)
+    }
+
+    pub fn try_get_variable(&self, name: impl AsRef<str>) -> GitHubResult<HandleVariable, HandleOrganizationError> {
+        Ok(self.get_actions_scope().try_get_variable(name)?)
+    }
+
+    pub fn try_has_variable(&self, name: impl AsRef<str>) -> GitHubResult<bool, HandleOrganizationError> {
+        Ok(self.get_actions_scope().try_has_variable(name)?)
+    }
+
+    pub fn try_get_all_variables(&self) -> GitHubResult<Vec<HandleVariable>, HandleOrganizationError> {
+        Ok(self.get_actions_scope().try_get_all_variables()?)
+    }
+
+    pub fn try_create_variable(&self, name: impl AsRef<str>, value: impl AsRef<str>, visibility: ActionsVisibility) -> GitHubResult<HandleVariable, HandleOrganizationError> {
+        Ok(self.get_actions_scope().try_create_variable_with_visibility(name, value, visibility)?)
+    }
+
+    pub fn try_delete_variable(&self, variable: HandleVariable) -> GitHubResult<(), HandleOrganizationError> {
+        Ok(variable.try_delete()?)
+    }
+
+    pub fn try_ensure_variables<K, V>(&self, variables: impl IntoIterator<Item = (K, V)>) -> GitHubResult<VariableReport, HandleOrganizationError>
+    where K: AsRef<str>, V: AsRef<str> {
+        Ok(self.get_actions_scope().try_ensure_variables(variables)?)
+    }
+
+    pub fn get_ruleset_scope(&self) -> RulesetScope {
+        RulesetScope::Organization { organization: self.clone() }
+    }
+
+    pub fn try_get_ruleset(&self, number: impl Into<Number>) -> GitHubResult<HandleRuleset, HandleOrganizationError> {
+        Ok(self.get_ruleset_scope().try_get_ruleset(number)?)
+    }
+
+    pub fn try_get_all_rulesets(&self) -> GitHubResult<Vec<HandleRuleset>, HandleOrganizationError> {
+        Ok(self.get_ruleset_scope().try_get_all_rulesets()?)
+    }
+
+    /// Creates an organization ruleset, use repository name conditions to pick the repositories.
+    pub fn try_create_ruleset(&self, ruleset: &Ruleset) -> GitHubResult<HandleRuleset, HandleOrganizationError> {
+        Ok(self.get_ruleset_scope().try_create_ruleset(ruleset)?)
+    }
+
+    pub fn try_delete_ruleset(&self, ruleset: HandleRuleset) -> GitHubResult<(), HandleOrganizationError> {
+        Ok(ruleset.try_delete()?)
+    }
+
+    pub fn get_hook_scope(&self) -> HookScope {
+        HookScope::Organization { organization: self.clone() }
+    }
+
+    pub fn try_get_hook(&self, number: impl Into<Number>) -> GitHubResult<HandleHook, HandleOrganizationError> {
+        Ok(self.get_hook_scope().try_get_hook(number)?)
+    }
+
+    pub fn try_has_hook(&self, number: impl Into<Number>) -> GitHubResult<bool, HandleOrganizationError> {
+        Ok(self.get_hook_scope().try_has_hook(number)?)
+    }
+
+    pub fn try_get_all_hooks(&self) -> GitHubResult<Vec<HandleHook>, HandleOrganizationError> {
+        Ok(self.get_hook_scope().try_get_all_hooks()?)
+    }
+
+    /// Gets all hooks with their configuration, such as to find the one for a URL.
+    pub fn try_get_all_hook_content(&self) -> GitHubResult<Vec<Hook>, HandleOrganizationError> {
+        Ok(self.get_hook_scope().try_get_all_hook_content()?)
+    }
+
+    pub fn try_create_hook(&self, hook: &Hook) -> GitHubResult<HandleHook, HandleOrganizationError> {
+        Ok(self.get_hook_scope().try_create_hook(hook)?)
+    }
+
+    pub fn try_delete_hook(&self, hook: HandleHook) -> GitHubResult<(), HandleOrganizationError> {
+        Ok(hook.try_delete()?)
+    }
 }
 
 impl<'a> GitHubEndpoint<'a> for HandleOrganization {
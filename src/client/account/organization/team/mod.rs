@@ -13,7 +13,12 @@ use std::{
 
 use thiserror::{Error};
 
-use serde::de::{DeserializeOwned};
+use serde::{
+
+    de::{DeserializeOwned},
+
+    Deserialize,
+};
 
 use crate::client::{
     
@@ -21,14 +26,23 @@ use crate::client::{
 
     client::{
 
+        ClientResponseError,
         ClientError,
         Client, 
     },
 
     models::common::{
 
+        team::{
+
+            TeamRepository,
+            TeamContent,
+            TeamRole,
+            Team,
+        },
+
+        permission::{Permission},
         user::{User}, 
-        team::{Team},
     },
     
     GitHubProperties,
@@ -37,6 +51,8 @@ use crate::client::{
 
 use super::{HandleOrganization};
 
+pub mod reconcile;
+
 #[derive(Error, Debug)]
 pub enum HandleTeamError {
     #[error("Client error!")]
@@ -45,6 +61,8 @@ pub enum HandleTeamError {
     Repository(#[from] HandleRepositoryError),
     #[error("Not an organization, got: '{account:?}'")]
     Organization { account: User },
+    #[error("Team not found: '{slug}'")]
+    Nothing { slug: String },
 }
 
 #[derive(Clone, Debug)]
@@ -59,9 +77,21 @@ impl HandleTeam {
             .to_owned();
 
         let Team { slug, .. } = {
-            organization.get_client()
-                .get(format!("orgs/{organization}/teams/{slug}"))?
-                .send()?.json()?
+
+            let result = {
+
+                organization.get_client()
+                    .get(format!("orgs/{organization}/teams/{slug}"))?
+                    .send()
+            };
+
+            match result {
+                Err(ClientError::Response(ClientResponseError::Nothing { .. })) => {
+                    return Err(HandleTeamError::Nothing { slug })
+                },
+                Err(error) => return Err(error.into()),
+                Ok(response) => response.json()?,
+            }
         };
 
         Ok(HandleTeam { 
@@ -70,7 +100,7 @@ impl HandleTeam {
         })
     }
 
-    pub(crate) fn try_fetch_all(organization: &HandleOrganization) -> GitHubResult<Vec<HandleTeam>, HandleTeamError> {
+    pub(crate) fn try_fetch_all_content(organization: &HandleOrganization) -> GitHubResult<Vec<Team>, HandleTeamError> {
         let client = organization.get_client();
         
         let mut collection = Vec::new();
@@ -100,7 +130,11 @@ impl HandleTeam {
             }
         }
 
-        Ok(collection.into_iter()
+        Ok(collection)
+    }
+
+    pub(crate) fn try_fetch_all(organization: &HandleOrganization) -> GitHubResult<Vec<HandleTeam>, HandleTeamError> {
+        Ok(HandleTeam::try_fetch_all_content(organization)?.into_iter()
             .map(|Team { slug, .. }| HandleTeam { 
                 organization: organization.clone(),
                 slug,
@@ -118,12 +152,279 @@ impl HandleTeam {
 
     pub fn try_get_team_members<T>(&self) -> GitHubResult<Vec<T>, HandleTeamError> 
     where T: DeserializeOwned + FmtDebug {
-        let organization = self.get_parent();
-        let client = self.get_client();
+        self.try_fetch_members(None)
+    }
+
+    /// Lists the members with a role, members of child teams are included.
+    pub fn try_get_team_members_with_role<T>(&self, role: TeamRole) -> GitHubResult<Vec<T>, HandleTeamError>
+    where T: DeserializeOwned + FmtDebug {
+        self.try_fetch_members(Some(role))
+    }
+
+    fn try_fetch_members<T>(&self, role: Option<TeamRole>) -> GitHubResult<Vec<T>, HandleTeamError>
+    where T: DeserializeOwned + FmtDebug {
+        let role = role.map(|role| role.as_str())
+            .unwrap_or("all");
+
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let members: Vec<T> = {
+
+                self.get_client()
+                    .get(format!("{endpoint}/members", endpoint = self.get_endpoint()))?
+                    .query(&[("role", role)])
+                    .query(&[("per_page", 100), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = members.len();
+
+            collection.extend(members);
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub(crate) fn try_create(organization: &HandleOrganization, content: &TeamContent) -> GitHubResult<HandleTeam, HandleTeamError> {
+        let Team { slug, .. } = {
+
+            organization.get_client()
+                .post(format!("orgs/{organization}/teams"))?
+                .json(content)
+                .send()?.json()?
+        };
 
-        Ok(client.get(format!("orgs/{organization}/teams/{self}/members"))?
+        Ok(HandleTeam {
+            organization: organization.clone(),
+            slug,
+        })
+    }
+
+    pub fn try_get_team(&self) -> GitHubResult<Team, HandleTeamError> {
+        Ok(self.get_client()
+            .get(self.get_endpoint())?
             .send()?.json()?)
     }
+
+    /// Updates the settings, renaming the team changes its slug so the
+    /// returned handle should be used from then on.
+    pub fn try_update(&self, content: &TeamContent) -> GitHubResult<HandleTeam, HandleTeamError> {
+        let Team { slug, .. } = {
+
+            self.get_client()
+                .patch(self.get_endpoint())?
+                .json(content)
+                .send()?.json()?
+        };
+
+        Ok(HandleTeam {
+            organization: self.organization.clone(),
+            slug,
+        })
+    }
+
+    /// Deletes the team along with its child teams.
+    pub fn try_delete(&self) -> GitHubResult<(), HandleTeamError> {
+        self.get_client()
+            .delete(self.get_endpoint())?
+            .send()?;
+
+        Ok(())
+    }
+
+    /// Adds a member or changes their role, users outside of the organization are invited.
+    pub fn try_add_team_member(&self, user: impl AsRef<str>, role: TeamRole) -> GitHubResult<(), HandleTeamError> {
+        let user = user.as_ref();
+        let payload = serde_json::json!({ "role": role });
+
+        self.get_client()
+            .put(format!("{endpoint}/memberships/{user}", endpoint = self.get_endpoint()))?
+            .json(&payload)
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_remove_team_member(&self, user: impl AsRef<str>) -> GitHubResult<(), HandleTeamError> {
+        let user = user.as_ref();
+
+        self.get_client()
+            .delete(format!("{endpoint}/memberships/{user}", endpoint = self.get_endpoint()))?
+            .send()?;
+
+        Ok(())
+    }
+
+    /// Gets the role of a member, `None` if the user is not a member. Pending
+    /// invitations already report their role.
+    pub fn try_get_team_member_role(&self, user: impl AsRef<str>) -> GitHubResult<Option<TeamRole>, HandleTeamError> {
+        let user = user.as_ref();
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            role: TeamRole,
+        }
+
+        let result = {
+
+            self.get_client()
+                .get(format!("{endpoint}/memberships/{user}", endpoint = self.get_endpoint()))?
+                .send()
+        };
+
+        match result {
+            Err(ClientError::Response(ClientResponseError::Nothing { .. })) => Ok(None),
+            Err(error) => Err(error.into()),
+            Ok(response) => {
+                let Capsule { role } = response.json()?;
+                Ok(Some(role))
+            },
+        }
+    }
+
+    /// Lists the repositories the team has access to, with its permission on each.
+    pub fn try_get_repositories(&self) -> GitHubResult<Vec<TeamRepository>, HandleTeamError> {
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let repositories: Vec<TeamRepository> = {
+
+                self.get_client()
+                    .get(format!("{endpoint}/repos", endpoint = self.get_endpoint()))?
+                    .query(&[("per_page", 100), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = repositories.len();
+
+            collection.extend(repositories);
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    /// Gets the permission of the team on a repository given as `owner/name` or
+    /// a repository handle, `None` without access.
+    pub fn try_get_repository_permission(&self, repository: impl FmtDisplay) -> GitHubResult<Option<Permission>, HandleTeamError> {
+        let result = {
+
+            self.get_client()
+                .get(format!("{endpoint}/repos/{repository}", endpoint = self.get_endpoint()))?
+                .header("Accept", "application/vnd.github.v3.repository+json")
+                .send()
+        };
+
+        match result {
+            Err(ClientError::Response(ClientResponseError::Nothing { .. })) => Ok(None),
+            Err(error) => Err(error.into()),
+            Ok(response) => {
+                let repository: TeamRepository = response.json()?;
+                Ok(repository.get_permission())
+            },
+        }
+    }
+
+    /// Grants the team access to a repository of the organization, or changes its permission.
+    pub fn try_set_repository_permission(&self, repository: impl FmtDisplay, permission: Permission) -> GitHubResult<(), HandleTeamError> {
+        let payload = serde_json::json!({ "permission": permission });
+
+        self.get_client()
+            .put(format!("{endpoint}/repos/{repository}", endpoint = self.get_endpoint()))?
+            .json(&payload)
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_remove_repository(&self, repository: impl FmtDisplay) -> GitHubResult<(), HandleTeamError> {
+        self.get_client()
+            .delete(format!("{endpoint}/repos/{repository}", endpoint = self.get_endpoint()))?
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_get_parent_team(&self) -> GitHubResult<Option<HandleTeam>, HandleTeamError> {
+        Ok(self.try_get_team()?
+            .parent
+            .map(|parent| HandleTeam {
+                organization: self.organization.clone(),
+                slug: parent.slug,
+            }))
+    }
+
+    /// Lists the teams directly below this one.
+    pub fn try_get_child_teams(&self) -> GitHubResult<Vec<HandleTeam>, HandleTeamError> {
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let teams: Vec<Team> = {
+
+                self.get_client()
+                    .get(format!("{endpoint}/teams", endpoint = self.get_endpoint()))?
+                    .query(&[("per_page", 100), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = teams.len();
+
+            collection.extend(teams.into_iter()
+                .map(|Team { slug, .. }| HandleTeam {
+                    organization: self.organization.clone(),
+                    slug,
+                }));
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    /// Lists all teams below this one, each team before its own children.
+    pub fn try_get_descendant_teams(&self) -> GitHubResult<Vec<HandleTeam>, HandleTeamError> {
+        let mut collection = Vec::new();
+        let mut pending = self.try_get_child_teams()?;
+
+        pending.reverse();
+
+        while let Some(team) = pending.pop() {
+            let mut children = team.try_get_child_teams()?;
+
+            children.reverse();
+            pending.extend(children);
+            collection.push(team);
+        }
+
+        Ok(collection)
+    }
+
+    pub fn get_slug(&self) -> String {
+        self.slug.clone()
+    }
 }
 
 impl<'a> GitHubEndpoint<'a> for HandleTeam {
@@ -0,0 +1,827 @@
+use std::{
+
+    collections::{
+
+        BTreeMap,
+        BTreeSet,
+    },
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+
+    path::{Path},
+};
+
+use thiserror::{Error};
+
+use crate::{
+
+    client::{
+
+        account::organization::{HandleOrganization},
+
+        models::common::{
+
+            team::{
+
+                TeamDefinition,
+                TeamContent,
+                TeamRole,
+                Team,
+            },
+
+            permission::{Permission},
+            user::{User},
+        },
+
+        GitHubResult,
+        Number,
+    },
+
+    core::log,
+};
+
+use super::{
+
+    HandleTeamError,
+    HandleTeam,
+};
+
+#[derive(Error, Debug)]
+pub enum ReconcileError {
+    #[error("Team error!")]
+    Team(#[from] HandleTeamError),
+    #[error("Failed to read team definitions!")]
+    File(#[from] std::io::Error),
+    #[error("Failed to parse team definitions!")]
+    Definition(#[from] serde_yaml::Error),
+    #[error("Team defined more than once: '{name}'")]
+    Duplicate { name: String },
+    #[error("Unknown parent of team '{team}': '{parent}'")]
+    Parent { team: String, parent: String },
+    #[error("Team is its own ancestor: '{team}'")]
+    Cycle { team: String },
+    #[error("Plan has {count} deletions, more than the limit of {limit}")]
+    Deletions { count: usize, limit: usize },
+}
+
+/// A single step of a plan. Teams are named by slug, or by name for teams the
+/// plan creates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TeamChange {
+    CreateTeam { team: String, content: TeamContent, parent: Option<String> },
+    /// Changes the settings set in `content`, `parent` is only set when it changes.
+    UpdateTeam { team: String, content: TeamContent, parent: Option<Option<String>> },
+    DeleteTeam { team: String },
+    AddMember { team: String, user: String, role: TeamRole },
+    SetMemberRole { team: String, user: String, role: TeamRole },
+    RemoveMember { team: String, user: String },
+    SetRepository { team: String, repository: String, permission: Permission, previous: Option<Permission> },
+    RemoveRepository { team: String, repository: String },
+}
+
+impl TeamChange {
+    pub fn get_team(&self) -> String {
+        match self {
+            TeamChange::CreateTeam { team, .. } |
+            TeamChange::UpdateTeam { team, .. } |
+            TeamChange::DeleteTeam { team } |
+            TeamChange::AddMember { team, .. } |
+            TeamChange::SetMemberRole { team, .. } |
+            TeamChange::RemoveMember { team, .. } |
+            TeamChange::SetRepository { team, .. } |
+            TeamChange::RemoveRepository { team, .. } => team.clone(),
+        }
+    }
+
+    /// Whether the change takes something away, counted against the deletion limit.
+    pub fn is_deletion(&self) -> bool {
+        matches!(self, TeamChange::DeleteTeam { .. }
+            | TeamChange::RemoveMember { .. }
+            | TeamChange::RemoveRepository { .. })
+    }
+}
+
+impl FmtDisplay for TeamChange {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        match self {
+            TeamChange::CreateTeam { team, parent: Some(parent), .. } => write!(fmt, "+ team {team} under {parent}"),
+            TeamChange::CreateTeam { team, .. } => write!(fmt, "+ team {team}"),
+            TeamChange::UpdateTeam { team, content, parent } => {
+                let mut fields = Vec::new();
+
+                if content.description.is_some() {
+                    fields.push("description".to_owned());
+                }
+
+                if let Some(privacy) = content.privacy {
+                    fields.push(format!("privacy: {privacy}", privacy = privacy.as_str()));
+                }
+
+                if let Some(notification) = content.notification_setting {
+                    fields.push(format!("notification: {notification}", notification = notification.as_str()));
+                }
+
+                match parent {
+                    Some(Some(parent)) => fields.push(format!("parent: {parent}")),
+                    Some(None) => fields.push("no parent".to_owned()),
+                    None => {},
+                }
+
+                write!(fmt, "~ team {team} ({fields})", fields = fields.join(", "))
+            },
+            TeamChange::DeleteTeam { team } => write!(fmt, "- team {team}"),
+            TeamChange::AddMember { team, user, role } => write!(fmt, "+ member {user} of {team} as {role}"),
+            TeamChange::SetMemberRole { team, user, role } => write!(fmt, "~ member {user} of {team} as {role}"),
+            TeamChange::RemoveMember { team, user } => write!(fmt, "- member {user} of {team}"),
+            TeamChange::SetRepository { team, repository, permission, previous: Some(previous) } => {
+                write!(fmt, "~ repository {repository} of {team} from {previous} to {permission}")
+            },
+            TeamChange::SetRepository { team, repository, permission, .. } => {
+                write!(fmt, "+ repository {repository} of {team} with {permission}")
+            },
+            TeamChange::RemoveRepository { team, repository } => write!(fmt, "- repository {repository} of {team}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct ReconcileOptions {
+    pub(crate) prune: bool,
+    pub(crate) dry_run: bool,
+    pub(crate) deletion_limit: Option<usize>,
+}
+
+impl ReconcileOptions {
+    pub fn new() -> ReconcileOptions {
+        ReconcileOptions::default()
+    }
+
+    /// Deletes teams which are neither defined nor the parent of a defined team.
+    pub fn with_prune(mut self, prune: bool) -> Self {
+        self.prune = prune;
+        self
+    }
+
+    /// Only prints the plan.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Refuses to apply a plan with more deletions than this.
+    pub fn with_deletion_limit(mut self, limit: usize) -> Self {
+        self.deletion_limit = Some(limit);
+        self
+    }
+}
+
+/// The live state of a defined team that a plan is computed from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct TeamState {
+    /// Members by lowercase login, including those of child teams.
+    pub(crate) members: BTreeMap<String, (String, TeamRole)>,
+    /// Lowercase logins of the members of descendant teams.
+    pub(crate) inherited: BTreeSet<String>,
+    /// Repositories by lowercase full name.
+    pub(crate) repositories: BTreeMap<String, (String, Option<Permission>)>,
+}
+
+impl TeamState {
+    pub(crate) fn try_fetch(handle: &HandleTeam) -> GitHubResult<TeamState, ReconcileError> {
+        let maintainers: BTreeSet<String> = {
+
+            handle.try_get_team_members_with_role::<User>(TeamRole::Maintainer)?
+                .into_iter()
+                .map(|user| user.get_name().to_lowercase())
+                .collect()
+        };
+
+        let members = {
+
+            handle.try_get_team_members::<User>()?
+                .into_iter()
+                .map(|user| {
+                    let role = match maintainers.contains(&(user.get_name().to_lowercase())) {
+                        true => TeamRole::Maintainer,
+                        _ => TeamRole::Member,
+                    };
+
+                    (user.get_name().to_lowercase(), (user.get_name(), role))
+                })
+                .collect()
+        };
+
+        let mut inherited = BTreeSet::new();
+
+        for child in handle.try_get_descendant_teams()? {
+            inherited.extend(child.try_get_team_members::<User>()?
+                .into_iter()
+                .map(|user| user.get_name().to_lowercase()));
+        }
+
+        let repositories = {
+
+            handle.try_get_repositories()?
+                .into_iter()
+                .map(|repository| {
+                    (repository.full_name.to_lowercase(), (repository.get_full_name(), repository.get_permission()))
+                })
+                .collect()
+        };
+
+        Ok(TeamState { members, inherited, repositories })
+    }
+}
+
+/// The changes that make the teams of an organization match their definitions.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TeamPlan {
+    pub(crate) changes: Vec<TeamChange>,
+}
+
+impl TeamPlan {
+    /// Compares the definitions with the teams of the organization.
+    pub(crate) fn try_fetch(organization: &HandleOrganization, definitions: &[TeamDefinition], prune: bool) -> GitHubResult<TeamPlan, ReconcileError> {
+        let teams = HandleTeam::try_fetch_all_content(organization)?;
+        let mut states = BTreeMap::new();
+
+        for team in teams.iter() {
+            if definitions.iter().any(|definition| definition.is_defining(team)) {
+                let handle = HandleTeam {
+                    organization: organization.clone(),
+                    slug: team.get_slug(),
+                };
+
+                states.insert(team.get_slug(), TeamState::try_fetch(&handle)?);
+            }
+        }
+
+        TeamPlan::try_from_state(organization.to_string().as_str(), definitions, teams.as_slice(), &states, prune)
+    }
+
+    /// Computes the plan from the teams of the organization and the state of
+    /// those defined, by slug. Members of child teams are listed as members of
+    /// their parents, so undefined members are kept as long as they belong to a
+    /// child team, and defined ones who also belong to a child team are added
+    /// again, which changes nothing when they already are direct members.
+    pub(crate) fn try_from_state(organization: &str, definitions: &[TeamDefinition], teams: &[Team], states: &BTreeMap<String, TeamState>, prune: bool) -> GitHubResult<TeamPlan, ReconcileError> {
+        for (index, definition) in definitions.iter().enumerate() {
+            let duplicate = definitions[..index].iter().any(|other| {
+                other.name.eq_ignore_ascii_case(definition.name.as_str())
+            });
+
+            if duplicate {
+                return Err(ReconcileError::Duplicate {
+                    name: definition.get_name()
+                })
+            }
+        }
+
+        let existing: Vec<Option<&Team>> = definitions.iter()
+            .map(|definition| teams.iter().find(|team| definition.is_defining(team)))
+            .collect();
+
+        // Existing teams are known by slug, new ones by the name they get.
+        let keys: Vec<String> = definitions.iter().zip(existing.iter())
+            .map(|(definition, team)| match team {
+                Some(team) => team.get_slug(),
+                None => definition.get_name(),
+            })
+            .collect();
+
+        let mut parents: Vec<Option<usize>> = Vec::new();
+        let mut parent_keys: Vec<Option<String>> = Vec::new();
+
+        for definition in definitions {
+            let Some(ref parent) = definition.parent else {
+                parents.push(None);
+                parent_keys.push(None);
+                continue
+            };
+
+            let defined = definitions.iter().zip(existing.iter()).position(|(other, team)| {
+                other.name.eq_ignore_ascii_case(parent)
+                    || team.is_some_and(|team| team.slug.eq_ignore_ascii_case(parent))
+            });
+
+            if let Some(index) = defined {
+                parents.push(Some(index));
+                parent_keys.push(Some(keys[index].clone()));
+                continue
+            }
+
+            let team = teams.iter().find(|team| {
+                team.name.eq_ignore_ascii_case(parent) || team.slug.eq_ignore_ascii_case(parent)
+            });
+
+            let Some(team) = team else {
+                return Err(ReconcileError::Parent {
+                    team: definition.get_name(),
+                    parent: parent.clone(),
+                })
+            };
+
+            parents.push(None);
+            parent_keys.push(Some(team.get_slug()));
+        }
+
+        // Parents are handled before their children, so new parents exist in time.
+        let mut depths = Vec::new();
+
+        for (index, definition) in definitions.iter().enumerate() {
+            let mut depth = 0;
+            let mut current = parents[index];
+
+            while let Some(parent) = current {
+                depth += 1;
+
+                if depth > definitions.len() {
+                    return Err(ReconcileError::Cycle {
+                        team: definition.get_name()
+                    })
+                }
+
+                current = parents[parent];
+            }
+
+            depths.push(depth);
+        }
+
+        let mut order: Vec<usize> = (0..definitions.len()).collect();
+        order.sort_by_key(|index| depths[*index]);
+
+        let mut plan = TeamPlan::default();
+
+        for index in order {
+            let definition = &(definitions[index]);
+            let parent = parent_keys[index].clone();
+            let team = keys[index].clone();
+
+            let repositories: Vec<(String, Permission)> = definition.repositories.iter()
+                .map(|(repository, permission)| match repository.contains('/') {
+                    true => (repository.clone(), *permission),
+                    _ => (format!("{organization}/{repository}"), *permission),
+                })
+                .collect();
+
+            let Some(live) = existing[index] else {
+                plan.changes.push(TeamChange::CreateTeam {
+                    team: team.clone(),
+                    content: TeamContent {
+                        name: Some(definition.get_name()),
+                        description: definition.get_description(),
+                        privacy: definition.privacy,
+                        notification_setting: definition.notification,
+                        parent: None,
+                    },
+                    parent,
+                });
+
+                for (user, role) in definition.members.iter() {
+                    plan.changes.push(TeamChange::AddMember {
+                        team: team.clone(),
+                        user: user.clone(),
+                        role: *role,
+                    });
+                }
+
+                for (repository, permission) in repositories {
+                    plan.changes.push(TeamChange::SetRepository {
+                        team: team.clone(),
+                        permission,
+                        repository,
+                        previous: None,
+                    });
+                }
+
+                continue
+            };
+
+            let mut content = TeamContent::new();
+
+            if definition.description.as_ref().is_some_and(|description| *description != live.description) {
+                content.description = definition.get_description();
+            }
+
+            if definition.privacy.is_some() && definition.privacy != live.privacy {
+                content.privacy = definition.privacy;
+            }
+
+            if definition.notification.is_some() && definition.notification != live.notification_setting {
+                content.notification_setting = definition.notification;
+            }
+
+            let live_parent = live.parent.as_ref()
+                .map(|parent| parent.get_slug());
+
+            let moved = match (parent.as_ref(), live_parent.as_ref()) {
+                (Some(parent), Some(live_parent)) => !(parent.eq_ignore_ascii_case(live_parent)),
+                (None, None) => false,
+                _ => true,
+            };
+
+            if moved || content != TeamContent::default() {
+                plan.changes.push(TeamChange::UpdateTeam {
+                    team: team.clone(),
+                    parent: moved.then(|| parent.clone()),
+                    content,
+                });
+            }
+
+            let state = states.get(&(live.get_slug()))
+                .cloned()
+                .unwrap_or_default();
+
+            let TeamState { mut members, inherited, repositories: mut granted } = state;
+
+            for (user, role) in definition.members.iter() {
+                let key = user.to_lowercase();
+
+                // Members of child teams might not be direct members.
+                match members.remove(&key) {
+                    Some((_, live_role)) if !(inherited.contains(&key)) => {
+                        if live_role != *role {
+                            plan.changes.push(TeamChange::SetMemberRole {
+                                team: team.clone(),
+                                user: user.clone(),
+                                role: *role,
+                            });
+                        }
+                    },
+                    _ => plan.changes.push(TeamChange::AddMember {
+                        team: team.clone(),
+                        user: user.clone(),
+                        role: *role,
+                    }),
+                }
+            }
+
+            for (key, (user, _)) in members {
+                if !(inherited.contains(&key)) {
+                    plan.changes.push(TeamChange::RemoveMember {
+                        team: team.clone(),
+                        user,
+                    });
+                }
+            }
+
+            for (repository, permission) in repositories {
+                match granted.remove(&(repository.to_lowercase())) {
+                    None => plan.changes.push(TeamChange::SetRepository {
+                        team: team.clone(),
+                        permission,
+                        repository,
+                        previous: None,
+                    }),
+                    Some((_, previous)) if previous != Some(permission) => plan.changes.push(TeamChange::SetRepository {
+                        team: team.clone(),
+                        permission,
+                        repository,
+                        previous,
+                    }),
+                    Some(_) => {},
+                }
+            }
+
+            for (_, (repository, _)) in granted {
+                plan.changes.push(TeamChange::RemoveRepository {
+                    team: team.clone(),
+                    repository,
+                });
+            }
+        }
+
+        if prune {
+            let kept: BTreeSet<String> = keys.iter()
+                .chain(parent_keys.iter().flatten())
+                .map(|key| key.to_lowercase())
+                .collect();
+
+            let parents: BTreeMap<String, String> = teams.iter()
+                .filter_map(|team| team.parent.as_ref().map(|parent| (team.get_slug(), parent.get_slug())))
+                .collect();
+
+            let depth = |slug: &String| {
+                let mut depth = 0;
+                let mut current = parents.get(slug);
+
+                while let Some(parent) = current {
+                    depth += 1;
+
+                    if depth > teams.len() {
+                        break
+                    }
+
+                    current = parents.get(parent);
+                }
+
+                depth
+            };
+
+            // Children go first, deleting a team also deletes the teams below it.
+            let mut deleted: Vec<&Team> = teams.iter()
+                .filter(|team| !(kept.contains(&(team.slug.to_lowercase()))))
+                .collect();
+
+            deleted.sort_by_key(|team| std::cmp::Reverse(depth(&(team.slug))));
+
+            for team in deleted {
+                plan.changes.push(TeamChange::DeleteTeam {
+                    team: team.get_slug()
+                });
+            }
+        }
+
+        Ok(plan)
+    }
+
+    /// Applies the changes in order. Creating a team makes the authenticated
+    /// user one of its maintainers, who is removed again unless the plan adds them.
+    pub(crate) fn try_apply(&self, organization: &HandleOrganization) -> GitHubResult<(), ReconcileError> {
+        let mut created: BTreeMap<String, HandleTeam> = BTreeMap::new();
+
+        let get_handle = |created: &BTreeMap<String, HandleTeam>, team: &String| {
+
+            created.get(team)
+                .cloned()
+                .unwrap_or_else(|| HandleTeam {
+                    organization: organization.clone(),
+                    slug: team.clone(),
+                })
+        };
+
+        let get_number = |created: &BTreeMap<String, HandleTeam>, team: &String| -> GitHubResult<Number, ReconcileError> {
+            Ok(get_handle(created, team).try_get_team()?
+                .get_number())
+        };
+
+        for change in self.changes.iter() {
+            match change {
+                TeamChange::CreateTeam { team, content, parent } => {
+                    let mut content = content.clone();
+
+                    if let Some(parent) = parent {
+                        content.parent = Some(Some(get_number(&created, parent)?));
+                    }
+
+                    let handle = HandleTeam::try_create(organization, &content)?;
+
+                    let defined: BTreeSet<String> = self.changes.iter()
+                        .filter_map(|change| match change {
+                            TeamChange::AddMember { team: other, user, .. } if other == team => Some(user.to_lowercase()),
+                            _ => None,
+                        })
+                        .collect();
+
+                    for user in handle.try_get_team_members::<User>()? {
+                        if !(defined.contains(&(user.get_name().to_lowercase()))) {
+                            handle.try_remove_team_member(user.get_name())?;
+                        }
+                    }
+
+                    created.insert(team.clone(), handle);
+                },
+                TeamChange::UpdateTeam { team, content, parent } => {
+                    let mut content = content.clone();
+
+                    match parent {
+                        Some(Some(parent)) => content.parent = Some(Some(get_number(&created, parent)?)),
+                        Some(None) => content.parent = Some(None),
+                        None => {},
+                    }
+
+                    get_handle(&created, team).try_update(&content)?;
+                },
+                TeamChange::DeleteTeam { team } => {
+                    get_handle(&created, team).try_delete()?;
+                },
+                TeamChange::AddMember { team, user, role } |
+                TeamChange::SetMemberRole { team, user, role } => {
+                    get_handle(&created, team).try_add_team_member(user, *role)?;
+                },
+                TeamChange::RemoveMember { team, user } => {
+                    get_handle(&created, team).try_remove_team_member(user)?;
+                },
+                TeamChange::SetRepository { team, repository, permission, .. } => {
+                    get_handle(&created, team).try_set_repository_permission(repository, *permission)?;
+                },
+                TeamChange::RemoveRepository { team, repository } => {
+                    get_handle(&created, team).try_remove_repository(repository)?;
+                },
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Prints the plan, then applies it unless it is a dry run or has more
+    /// deletions than allowed.
+    pub(crate) fn try_reconcile(organization: &HandleOrganization, definitions: &[TeamDefinition], options: &ReconcileOptions) -> GitHubResult<TeamPlan, ReconcileError> {
+        let plan = TeamPlan::try_fetch(organization, definitions, options.prune)?;
+
+        log::begin_group(format!("Team plan for {organization}"));
+
+        match plan.has_changes() {
+            true => log::info(plan.to_string()),
+            _ => log::info("No changes"),
+        }
+
+        log::end_group();
+
+        if options.dry_run {
+            return Ok(plan)
+        }
+
+        plan.check_deletions(options)?;
+        plan.try_apply(organization)?;
+
+        Ok(plan)
+    }
+
+    /// Fails when the plan has more deletions than the options allow.
+    pub(crate) fn check_deletions(&self, options: &ReconcileOptions) -> GitHubResult<(), ReconcileError> {
+        let count = self.get_deletions();
+
+        match options.deletion_limit {
+            Some(limit) if count > limit => Err(ReconcileError::Deletions { count, limit }),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn get_changes(&self) -> &[TeamChange] {
+        self.changes.as_slice()
+    }
+
+    pub fn has_changes(&self) -> bool {
+        !(self.changes.is_empty())
+    }
+
+    pub fn get_deletions(&self) -> usize {
+        self.changes.iter()
+            .filter(|change| change.is_deletion())
+            .count()
+    }
+}
+
+impl FmtDisplay for TeamPlan {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        let lines: Vec<String> = self.changes.iter()
+            .map(|change| change.to_string())
+            .collect();
+
+        write!(fmt, "{lines}", lines = lines.join("\n"))
+    }
+}
+
+/// Reads team definitions from a YAML or JSON file holding a list of teams.
+pub fn try_read_definitions(path: impl AsRef<Path>) -> GitHubResult<Vec<TeamDefinition>, ReconcileError> {
+    let content = std::fs::read_to_string(path)?;
+    Ok(serde_yaml::from_str(content.as_str())?)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::{
+
+        BTreeMap,
+        BTreeSet,
+    };
+
+    use crate::client::models::common::{
+
+        permission::{Permission},
+
+        team::{
+
+            TeamDefinition,
+            TeamRole,
+            Team,
+        },
+    };
+
+    use super::{
+
+        ReconcileOptions,
+        ReconcileError,
+        TeamChange,
+        TeamState,
+        TeamPlan,
+    };
+
+    fn get_state(members: &[(&str, TeamRole)], inherited: &[&str], repositories: &[(&str, Permission)]) -> TeamState {
+        TeamState {
+            members: members.iter()
+                .map(|(user, role)| (user.to_lowercase(), (user.to_string(), *role)))
+                .collect(),
+            inherited: inherited.iter()
+                .map(|user| user.to_lowercase())
+                .collect::<BTreeSet<String>>(),
+            repositories: repositories.iter()
+                .map(|(repository, permission)| (repository.to_lowercase(), (repository.to_string(), Some(*permission))))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_plan() {
+        let teams: Vec<Team> = serde_json::from_value(serde_json::json!([
+            { "name": "Platform", "slug": "platform", "id": 1, "description": "Old" },
+            { "name": "Web", "slug": "web", "id": 2, "parent": { "name": "Platform", "slug": "platform", "id": 1 } },
+            { "name": "Legacy", "slug": "legacy", "id": 3 },
+            { "name": "Legacy Child", "slug": "legacy-child", "id": 4, "parent": { "name": "Legacy", "slug": "legacy", "id": 3 } },
+        ])).unwrap();
+
+        let definitions = vec![
+            TeamDefinition::new("Platform")
+                .with_description("New")
+                .with_member("alice", TeamRole::Maintainer)
+                .with_member("bob", TeamRole::Member)
+                .with_member("carol", TeamRole::Member)
+                .with_repository("hello-world", Permission::Admin),
+            TeamDefinition::new("Web")
+                .with_parent("Platform")
+                .with_member("dave", TeamRole::Member),
+            TeamDefinition::new("Mobile")
+                .with_parent("Platform")
+                .with_member("erin", TeamRole::Maintainer)
+                .with_repository("octo-org/app", Permission::Push),
+        ];
+
+        let states = BTreeMap::from([
+            ("platform".to_owned(), get_state(&[
+                ("alice", TeamRole::Member),
+                ("bob", TeamRole::Member),
+                ("carol", TeamRole::Member),
+                ("dave", TeamRole::Member),
+                ("mallory", TeamRole::Member),
+            ], &["carol", "dave"], &[
+                ("octo-org/hello-world", Permission::Push),
+                ("octo-org/old", Permission::Pull),
+            ])),
+            ("web".to_owned(), get_state(&[("dave", TeamRole::Member)], &[], &[])),
+        ]);
+
+        let plan = TeamPlan::try_from_state("octo-org", &definitions, &teams, &states, true).unwrap();
+
+        assert_eq!(plan.to_string(), concat!(
+            "~ team platform (description)\n",
+            "~ member alice of platform as maintainer\n",
+            "+ member carol of platform as member\n",
+            "- member mallory of platform\n",
+            "~ repository octo-org/hello-world of platform from push to admin\n",
+            "- repository octo-org/old of platform\n",
+            "+ team Mobile under platform\n",
+            "+ member erin of Mobile as maintainer\n",
+            "+ repository octo-org/app of Mobile with push\n",
+            "- team legacy-child\n",
+            "- team legacy",
+        ));
+
+        assert_eq!(plan.get_deletions(), 4);
+        assert!(matches!(plan.check_deletions(&ReconcileOptions::new().with_deletion_limit(3)), Err(ReconcileError::Deletions { count: 4, limit: 3 })));
+        assert!(plan.check_deletions(&ReconcileOptions::new().with_deletion_limit(4)).is_ok());
+
+        let plan = TeamPlan::try_from_state("octo-org", &definitions, &teams, &states, false).unwrap();
+        assert_eq!(plan.get_deletions(), 2);
+
+        let duplicate = [TeamDefinition::new("Web"), TeamDefinition::new("web")];
+        let result = TeamPlan::try_from_state("octo-org", &duplicate, &teams, &states, false);
+
+        assert!(matches!(result, Err(ReconcileError::Duplicate { .. })));
+    }
+
+    #[test]
+    fn test_display() {
+        let plan = TeamPlan {
+            changes: vec![
+                TeamChange::AddMember {
+                    team: "platform".to_owned(),
+                    user: "octocat".to_owned(),
+                    role: TeamRole::Maintainer,
+                },
+                TeamChange::SetRepository {
+                    team: "platform".to_owned(),
+                    repository: "octo-org/hello-world".to_owned(),
+                    permission: Permission::Admin,
+                    previous: Some(Permission::Push),
+                },
+                TeamChange::DeleteTeam {
+                    team: "legacy".to_owned(),
+                },
+            ],
+        };
+
+        assert_eq!(plan.get_deletions(), 1);
+        assert_eq!(plan.to_string(), concat!(
+            "+ member octocat of platform as maintainer\n",
+            "~ repository octo-org/hello-world of platform from push to admin\n",
+            "- team legacy",
+        ));
+    }
+}
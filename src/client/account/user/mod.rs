@@ -17,8 +17,12 @@ use crate::client::{
     repository::{
 
         HandleRepositoryError,
+        HandleRepository,
     },
 
+    models::common::repository::{RepositoryDraft},
+    account::{Account},
+
     client::{
 
         ClientError,
@@ -27,7 +31,7 @@ use crate::client::{
 
     models::common::user::{User},
     
-    GitHubProperties, GitHubEndpoint,
+    GitHubProperties, GitHubEndpoint, GitHubResult,
 };
 
 #[derive(Error, Debug)]
@@ -46,6 +50,13 @@ pub struct HandleUser {
     pub(crate) name: String,
 }
 
+impl HandleUser {
+    /// Creates a repository, fails unless this is the authenticated user.
+    pub fn try_create_repository(&self, draft: impl Into<RepositoryDraft>) -> GitHubResult<HandleRepository, HandleUserError> {
+        Ok(HandleRepository::try_create(&(Account::User(self.clone())), &(draft.into()))?)
+    }
+}
+
 impl<'a> GitHubEndpoint<'a> for HandleUser {
     fn get_endpoint(&'a self) -> Cow<'a, str> {
         format!("users/{self}").into()
@@ -0,0 +1,289 @@
+use std::{
+
+    borrow::{Cow},
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+};
+
+use crate::client::{
+
+    account::{
+
+        organization::{HandleOrganization},
+        Account,
+    },
+
+    repository::{HandleRepository},
+
+    models::common::actions::{
+
+        ActionsVisibility,
+        PublicKey,
+        Variable,
+    },
+
+    client::{
+
+        ClientError,
+        Client,
+    },
+
+    common::{encode_segment},
+
+    GitHubProperties,
+    GitHubEndpoint,
+    GitHubResult,
+    Number,
+};
+
+use serde::{Deserialize};
+
+pub mod variable;
+pub mod secret;
+
+use variable::{
+
+    VariableReport,
+    VariableError,
+    HandleVariable,
+};
+
+use secret::{
+
+    SecretValue,
+    SecretError,
+    HandleSecret,
+};
+
+/// Where Actions secrets and variables live, those of an environment are only visible
+/// to jobs referencing that environment.
+#[derive(Clone, Debug)]
+pub enum ActionsScope {
+    Organization { organization: HandleOrganization },
+    Environment { repository: HandleRepository, environment: String },
+    Repository { repository: HandleRepository },
+}
+
+impl ActionsScope {
+    pub(crate) fn get_client(&self) -> &Client {
+        match self {
+            ActionsScope::Organization { organization } => organization.get_client(),
+            ActionsScope::Environment { repository, .. } => repository.get_client(),
+            ActionsScope::Repository { repository } => repository.get_client(),
+        }
+    }
+
+    /// Wraps repositories of an organization scope, used for selected repository lists.
+    pub(crate) fn get_repository(&self, name: impl AsRef<str>) -> HandleRepository {
+        let owner = match self {
+            ActionsScope::Organization { organization } => Account::Organization(organization.clone()),
+            ActionsScope::Environment { repository, .. } |
+            ActionsScope::Repository { repository } => repository.owner.clone(),
+        };
+
+        HandleRepository {
+            name: name.as_ref().to_lowercase(),
+            owner,
+        }
+    }
+
+    pub(crate) fn try_fetch_selected_repositories(&self, endpoint: impl AsRef<str>) -> GitHubResult<Vec<HandleRepository>, ClientError> {
+        let endpoint = endpoint.as_ref();
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleRepository {
+            name: String,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            repositories: Vec<CapsuleRepository>,
+        }
+
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let Capsule { repositories } = {
+
+                self.get_client()
+                    .get(format!("{endpoint}/repositories"))?
+                    .query(&[("per_page", 100), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = repositories.len();
+
+            collection.extend(repositories.into_iter()
+                .map(|CapsuleRepository { name }| self.get_repository(name)));
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub(crate) fn try_set_selected_repositories(&self, endpoint: impl AsRef<str>, repositories: impl AsRef<[HandleRepository]>) -> GitHubResult<(), ClientError> {
+        let endpoint = endpoint.as_ref();
+
+        let mut numbers: Vec<Number> = Vec::new();
+        for repository in repositories.as_ref() {
+            numbers.push(repository.try_fetch_number()?);
+        }
+
+        let payload = serde_json::json!({
+            "selected_repository_ids": numbers,
+        });
+
+        self.get_client()
+            .put(format!("{endpoint}/repositories"))?
+            .json(&payload)
+            .send()?;
+
+        Ok(())
+    }
+
+    pub(crate) fn try_add_selected_repository(&self, endpoint: impl AsRef<str>, repository: &HandleRepository) -> GitHubResult<(), ClientError> {
+        let endpoint = endpoint.as_ref();
+        let number = repository.try_fetch_number()?;
+
+        self.get_client()
+            .put(format!("{endpoint}/repositories/{number}"))?
+            .send()?;
+
+        Ok(())
+    }
+
+    pub(crate) fn try_remove_selected_repository(&self, endpoint: impl AsRef<str>, repository: &HandleRepository) -> GitHubResult<(), ClientError> {
+        let endpoint = endpoint.as_ref();
+        let number = repository.try_fetch_number()?;
+
+        self.get_client()
+            .delete(format!("{endpoint}/repositories/{number}"))?
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn is_organization(&self) -> bool {
+        matches!(self, ActionsScope::Organization { .. })
+    }
+
+    pub fn is_environment(&self) -> bool {
+        matches!(self, ActionsScope::Environment { .. })
+    }
+
+    pub fn is_repository(&self) -> bool {
+        matches!(self, ActionsScope::Repository { .. })
+    }
+
+    pub fn try_get_secret_public_key(&self) -> GitHubResult<PublicKey, SecretError> {
+        HandleSecret::try_fetch_public_key(self)
+    }
+
+    pub fn try_get_secret(&self, name: impl AsRef<str>) -> GitHubResult<HandleSecret, SecretError> {
+        HandleSecret::try_fetch(self, name)
+    }
+
+    pub fn try_has_secret(&self, name: impl AsRef<str>) -> GitHubResult<bool, SecretError> {
+        match HandleSecret::try_fetch(self, name) {
+            Err(SecretError::Nothing { .. }) => Ok(false),
+            Err(error) => Err(error),
+            Ok(_) => Ok(true),
+        }
+    }
+
+    pub fn try_get_all_secrets(&self) -> GitHubResult<Vec<HandleSecret>, SecretError> {
+        HandleSecret::try_fetch_all(self)
+    }
+
+    /// Creates or updates a secret, organization secrets default to private visibility.
+    pub fn try_create_secret(&self, name: impl AsRef<str>, value: &SecretValue) -> GitHubResult<HandleSecret, SecretError> {
+        HandleSecret::try_create(self, name, value, None)
+    }
+
+    /// Creates or updates an organization secret with the given visibility.
+    pub fn try_create_secret_with_visibility(&self, name: impl AsRef<str>, value: &SecretValue, visibility: ActionsVisibility) -> GitHubResult<HandleSecret, SecretError> {
+        HandleSecret::try_create(self, name, value, Some(visibility))
+    }
+
+    pub fn try_delete_secret(&self, name: impl AsRef<str>) -> GitHubResult<(), SecretError> {
+        HandleSecret::try_fetch(self, name)?
+            .try_delete()
+    }
+
+    pub fn try_get_variable(&self, name: impl AsRef<str>) -> GitHubResult<HandleVariable, VariableError> {
+        HandleVariable::try_fetch(self, name)
+    }
+
+    pub fn try_has_variable(&self, name: impl AsRef<str>) -> GitHubResult<bool, VariableError> {
+        match HandleVariable::try_fetch(self, name) {
+            Err(VariableError::Nothing { .. }) => Ok(false),
+            Err(error) => Err(error),
+            Ok(_) => Ok(true),
+        }
+    }
+
+    pub fn try_get_all_variables(&self) -> GitHubResult<Vec<HandleVariable>, VariableError> {
+        HandleVariable::try_fetch_all(self)
+    }
+
+    /// Gets all variables of the scope including their values.
+    pub fn try_get_all_variable_values(&self) -> GitHubResult<Vec<Variable>, VariableError> {
+        HandleVariable::try_fetch_all_content(self)
+    }
+
+    /// Creates a variable, organization variables default to private visibility.
+    pub fn try_create_variable(&self, name: impl AsRef<str>, value: impl AsRef<str>) -> GitHubResult<HandleVariable, VariableError> {
+        HandleVariable::try_create(self, name, value, None)
+    }
+
+    /// Creates an organization variable with the given visibility.
+    pub fn try_create_variable_with_visibility(&self, name: impl AsRef<str>, value: impl AsRef<str>, visibility: ActionsVisibility) -> GitHubResult<HandleVariable, VariableError> {
+        HandleVariable::try_create(self, name, value, Some(visibility))
+    }
+
+    pub fn try_delete_variable(&self, name: impl AsRef<str>) -> GitHubResult<(), VariableError> {
+        HandleVariable::try_fetch(self, name)?
+            .try_delete()
+    }
+
+    /// Makes sure every variable exists with the given value, creating missing
+    /// variables and updating those with a different value.
+    pub fn try_ensure_variables<K, V>(&self, variables: impl IntoIterator<Item = (K, V)>) -> GitHubResult<VariableReport, VariableError>
+    where K: AsRef<str>, V: AsRef<str> {
+        HandleVariable::try_ensure(self, variables)
+    }
+}
+
+impl<'a> GitHubEndpoint<'a> for ActionsScope {
+    fn get_endpoint(&'a self) -> Cow<'a, str> {
+        match self {
+            ActionsScope::Organization { organization } => format!("orgs/{organization}/actions").into(),
+            ActionsScope::Environment { repository, environment } => format!("repos/{repository}/environments/{environment}", environment = encode_segment(environment)).into(),
+            ActionsScope::Repository { repository } => format!("repos/{repository}/actions").into(),
+        }
+    }
+}
+
+impl FmtDisplay for ActionsScope {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        match self {
+            ActionsScope::Organization { organization } => write!(fmt, "{organization}"),
+            ActionsScope::Environment { repository, environment } => write!(fmt, "{repository}:{environment}"),
+            ActionsScope::Repository { repository } => write!(fmt, "{repository}"),
+        }
+    }
+}
@@ -0,0 +1,362 @@
+use std::{
+
+    borrow::{Cow},
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+};
+
+use crypto_box::{
+
+    aead::{OsRng},
+    PublicKey as BoxPublicKey,
+};
+
+use secrecy::{
+
+    ExposeSecret,
+    Secret,
+};
+
+use serde::{Deserialize};
+use thiserror::{Error};
+
+use crate::client::{
+
+    repository::{HandleRepository},
+
+    client::{
+
+        ClientResponseError,
+        ClientError,
+        Client,
+    },
+
+    models::common::actions::{
+
+        ActionsVisibility,
+        SecretMetadata,
+        PublicKey,
+    },
+
+    GitHubProperties,
+    GitHubEndpoint,
+    GitHubResult,
+};
+
+use super::{ActionsScope};
+
+/// Plaintext value of a secret, it is only ever exposed to be encrypted.
+pub type SecretValue = Secret<String>;
+
+#[derive(Error, Debug)]
+pub enum SecretError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Invalid public key: '{key_id}'")]
+    PublicKey { key_id: String },
+    #[error("Failed to encrypt secret!")]
+    Encryption,
+    #[error("Only available for organization secrets, got: '{scope}'")]
+    Organization { scope: String },
+    #[error("Secret not found: '{name}'")]
+    Nothing { name: String },
+}
+
+/// Encrypts a value with a libsodium compatible sealed box for the given public key,
+/// returns the base64 encoded ciphertext expected by the secrets endpoints.
+pub fn seal(public_key: &PublicKey, value: &SecretValue) -> GitHubResult<String, SecretError> {
+    use base64::{
+
+        engine::general_purpose::{STANDARD},
+        Engine,
+    };
+
+    let PublicKey { key_id, key } = { public_key };
+
+    let bytes: [u8; 32] = STANDARD.decode(key.as_str()).ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| SecretError::PublicKey {
+            key_id: key_id.to_owned()
+        })?;
+
+    let sealed = BoxPublicKey::from(bytes).seal(&mut OsRng, {
+        value.expose_secret()
+            .as_bytes()
+    }).map_err(|_| SecretError::Encryption)?;
+
+    Ok(STANDARD.encode(sealed))
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleSecret {
+    scope: ActionsScope,
+    name: String,
+}
+
+impl HandleSecret {
+    pub(crate) fn try_fetch_public_key(scope: &ActionsScope) -> GitHubResult<PublicKey, SecretError> {
+        let endpoint = scope.get_endpoint();
+
+        Ok(scope.get_client()
+            .get(format!("{endpoint}/secrets/public-key"))?
+            .send()?.json()?)
+    }
+
+    pub(crate) fn try_fetch(scope: &ActionsScope, name: impl AsRef<str>) -> GitHubResult<HandleSecret, SecretError> {
+        let endpoint = scope.get_endpoint();
+        let name = name.as_ref();
+
+        let SecretMetadata { name, .. } = {
+
+            let result = {
+
+                scope.get_client()
+                    .get(format!("{endpoint}/secrets/{name}"))?
+                    .send()
+            };
+
+            match result {
+                Err(ClientError::Response(ClientResponseError::Nothing { .. })) => {
+                    return Err(SecretError::Nothing { name: name.to_owned() })
+                },
+                Err(error) => return Err(error.into()),
+                Ok(response) => response.json()?,
+            }
+        };
+
+        Ok(HandleSecret {
+            scope: scope.clone(),
+            name,
+        })
+    }
+
+    pub(crate) fn try_fetch_all(scope: &ActionsScope) -> GitHubResult<Vec<HandleSecret>, SecretError> {
+        let endpoint = scope.get_endpoint();
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            secrets: Vec<SecretMetadata>,
+        }
+
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let Capsule { secrets } = {
+
+                scope.get_client()
+                    .get(format!("{endpoint}/secrets"))?
+                    .query(&[("per_page", 100), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = secrets.len();
+
+            collection.extend(secrets.into_iter()
+                .map(|SecretMetadata { name, .. }| HandleSecret {
+                    scope: scope.clone(),
+                    name,
+                }));
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub(crate) fn try_create(scope: &ActionsScope, name: impl AsRef<str>, value: &SecretValue, visibility: Option<ActionsVisibility>) -> GitHubResult<HandleSecret, SecretError> {
+        let secret = HandleSecret {
+            name: name.as_ref().to_owned(),
+            scope: scope.clone(),
+        };
+
+        let visibility = match (scope, visibility) {
+            (ActionsScope::Organization { .. }, visibility) => {
+                Some(visibility.unwrap_or(ActionsVisibility::Private))
+            },
+            (_, None) => None,
+            (_, Some(_)) => return Err(SecretError::Organization {
+                scope: scope.to_string()
+            }),
+        };
+
+        secret.try_put(value, visibility)?;
+
+        Ok(secret)
+    }
+
+    fn try_put(&self, value: &SecretValue, visibility: Option<ActionsVisibility>) -> GitHubResult<(), SecretError> {
+        let public_key = HandleSecret::try_fetch_public_key(&(self.scope))?;
+        let encrypted = seal(&public_key, value)?;
+
+        let PublicKey { key_id, .. } = { public_key };
+
+        let mut payload = serde_json::json!({
+            "encrypted_value": encrypted,
+            "key_id": key_id,
+        });
+
+        if let Some(visibility) = visibility {
+            payload["visibility"] = serde_json::to_value(visibility)
+                .unwrap_or_default();
+        }
+
+        self.get_client()
+            .put(self.get_endpoint())?
+            .json(&payload)
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_get_metadata(&self) -> GitHubResult<SecretMetadata, SecretError> {
+        Ok(self.get_client()
+            .get(self.get_endpoint())?
+            .send()?.json()?)
+    }
+
+    /// Replaces the value of the secret, organization secrets keep their visibility.
+    pub fn try_update(&self, value: &SecretValue) -> GitHubResult<(), SecretError> {
+        let SecretMetadata { visibility, .. } = self.try_get_metadata()?;
+
+        self.try_put(value, visibility)
+    }
+
+    /// Replaces the value and visibility of an organization secret.
+    pub fn try_update_with_visibility(&self, value: &SecretValue, visibility: ActionsVisibility) -> GitHubResult<(), SecretError> {
+        self.require_organization()?;
+        self.try_put(value, Some(visibility))
+    }
+
+    pub fn try_delete(&self) -> GitHubResult<(), SecretError> {
+        self.get_client()
+            .delete(self.get_endpoint())?
+            .send()?;
+
+        Ok(())
+    }
+
+    /// Gets the repositories which can access an organization secret with selected visibility.
+    pub fn try_get_selected_repositories(&self) -> GitHubResult<Vec<HandleRepository>, SecretError> {
+        self.require_organization()?;
+
+        Ok(self.scope.try_fetch_selected_repositories({
+            self.get_endpoint()
+        })?)
+    }
+
+    /// Replaces the repositories which can access an organization secret with selected visibility.
+    pub fn try_set_selected_repositories(&self, repositories: impl AsRef<[HandleRepository]>) -> GitHubResult<(), SecretError> {
+        self.require_organization()?;
+
+        Ok(self.scope.try_set_selected_repositories(self.get_endpoint(), {
+            repositories
+        })?)
+    }
+
+    pub fn try_add_selected_repository(&self, repository: &HandleRepository) -> GitHubResult<(), SecretError> {
+        self.require_organization()?;
+
+        Ok(self.scope.try_add_selected_repository(self.get_endpoint(), {
+            repository
+        })?)
+    }
+
+    pub fn try_remove_selected_repository(&self, repository: &HandleRepository) -> GitHubResult<(), SecretError> {
+        self.require_organization()?;
+
+        Ok(self.scope.try_remove_selected_repository(self.get_endpoint(), {
+            repository
+        })?)
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn require_organization(&self) -> GitHubResult<(), SecretError> {
+        if self.scope.is_organization() { Ok(()) } else {
+            Err(SecretError::Organization {
+                scope: self.scope.to_string()
+            })
+        }
+    }
+}
+
+impl<'a> GitHubEndpoint<'a> for HandleSecret {
+    fn get_endpoint(&'a self) -> Cow<'a, str> {
+        let HandleSecret { scope, name } = { self };
+        format!("{endpoint}/secrets/{name}", endpoint = scope.get_endpoint()).into()
+    }
+}
+
+impl<'a> GitHubProperties<'a> for HandleSecret {
+    type Content = SecretMetadata;
+    type Parent = ActionsScope;
+
+    fn get_client(&'a self) -> &'a Client {
+        self.scope.get_client()
+    }
+
+    fn get_parent(&'a self) -> &'a Self::Parent {
+        &(self.scope)
+    }
+}
+
+impl FmtDisplay for HandleSecret {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        let HandleSecret { name, .. } = { self };
+        write!(fmt, "{name}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crypto_box::{
+
+        aead::{OsRng},
+        SecretKey,
+    };
+
+    use secrecy::{Secret};
+
+    use crate::client::models::common::actions::{PublicKey};
+
+    use super::{seal};
+
+    #[test]
+    fn test_seal() {
+        use base64::{
+
+            engine::general_purpose::{STANDARD},
+            Engine,
+        };
+
+        let secret_key = SecretKey::generate(&mut OsRng);
+        let public_key = PublicKey {
+            key_id: "568250167242549743".to_owned(),
+            key: STANDARD.encode(secret_key.public_key()
+                .as_bytes()),
+        };
+
+        let sealed = seal(&public_key, &Secret::new("hunter2".to_owned()))
+            .unwrap();
+
+        let opened = secret_key.unseal(STANDARD.decode(sealed).unwrap().as_slice())
+            .unwrap();
+
+        assert_eq!(opened.as_slice(), b"hunter2");
+    }
+}
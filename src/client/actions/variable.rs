@@ -0,0 +1,515 @@
+use std::{
+
+    borrow::{Cow},
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+};
+
+use serde::{Deserialize};
+use serde_json::{Value};
+use thiserror::{Error};
+
+use crate::client::{
+
+    repository::{HandleRepository},
+
+    client::{
+
+        ClientResponseError,
+        ClientError,
+        Client,
+    },
+
+    models::common::actions::{
+
+        ActionsVisibility,
+        Variable,
+    },
+
+    GitHubProperties,
+    GitHubEndpoint,
+    GitHubResult,
+};
+
+use super::{ActionsScope};
+
+#[derive(Error, Debug)]
+pub enum VariableError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Only available for organization variables, got: '{scope}'")]
+    Organization { scope: String },
+    #[error("Variable not found: '{name}'")]
+    Nothing { name: String },
+}
+
+/// Outcome of ensuring a set of variables, holds the names of the variables
+/// which were created, updated or already had the desired value.
+#[derive(Clone, Debug, Default)]
+pub struct VariableReport {
+    pub(crate) created: Vec<String>,
+    pub(crate) updated: Vec<String>,
+    pub(crate) unchanged: Vec<String>,
+}
+
+impl VariableReport {
+    pub fn get_created(&self) -> &[String] {
+        self.created.as_slice()
+    }
+
+    pub fn get_updated(&self) -> &[String] {
+        self.updated.as_slice()
+    }
+
+    pub fn get_unchanged(&self) -> &[String] {
+        self.unchanged.as_slice()
+    }
+
+    pub fn has_changes(&self) -> bool {
+        !(self.created.is_empty() && self.updated.is_empty())
+    }
+}
+
+/// What ensuring a variable does, given the existing variables.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum EnsureAction {
+    Create { name: String },
+    Update { name: String },
+    Keep { name: String },
+}
+
+fn get_ensure_action(existing: &[Variable], name: &str, value: &str) -> EnsureAction {
+    // Variable names are case insensitive and reported in upper case.
+    let candidate = existing.iter().find(|variable| {
+        variable.name.eq_ignore_ascii_case(name)
+    });
+
+    match candidate {
+        Some(Variable { name, value: current, .. }) if current == value => {
+            EnsureAction::Keep { name: name.clone() }
+        },
+        Some(Variable { name, .. }) => {
+            EnsureAction::Update { name: name.clone() }
+        },
+        None => EnsureAction::Create { name: name.to_owned() },
+    }
+}
+
+fn get_create_payload(scope: &ActionsScope, name: &str, value: &str, visibility: Option<ActionsVisibility>) -> GitHubResult<Value, VariableError> {
+    let mut payload = serde_json::json!({
+        "name": name,
+        "value": value,
+    });
+
+    match (scope, visibility) {
+        (ActionsScope::Organization { .. }, visibility) => {
+            payload["visibility"] = serde_json::to_value(visibility.unwrap_or(ActionsVisibility::Private))
+                .unwrap_or_default();
+        },
+        (_, None) => (),
+        (_, Some(_)) => return Err(VariableError::Organization {
+            scope: scope.to_string()
+        }),
+    }
+
+    Ok(payload)
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleVariable {
+    scope: ActionsScope,
+    name: String,
+}
+
+impl HandleVariable {
+    pub(crate) fn try_fetch(scope: &ActionsScope, name: impl AsRef<str>) -> GitHubResult<HandleVariable, VariableError> {
+        let endpoint = scope.get_endpoint();
+        let name = name.as_ref();
+
+        let Variable { name, .. } = {
+
+            let result = {
+
+                scope.get_client()
+                    .get(format!("{endpoint}/variables/{name}"))?
+                    .send()
+            };
+
+            match result {
+                Err(ClientError::Response(ClientResponseError::Nothing { .. })) => {
+                    return Err(VariableError::Nothing { name: name.to_owned() })
+                },
+                Err(error) => return Err(error.into()),
+                Ok(response) => response.json()?,
+            }
+        };
+
+        Ok(HandleVariable {
+            scope: scope.clone(),
+            name,
+        })
+    }
+
+    pub(crate) fn try_fetch_all_content(scope: &ActionsScope) -> GitHubResult<Vec<Variable>, VariableError> {
+        let endpoint = scope.get_endpoint();
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            variables: Vec<Variable>,
+        }
+
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            // The variables endpoints are limited to 30 entries per page.
+            let Capsule { variables } = {
+
+                scope.get_client()
+                    .get(format!("{endpoint}/variables"))?
+                    .query(&[("per_page", 30), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = variables.len();
+
+            collection.extend(variables);
+
+            if count < 30 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub(crate) fn try_fetch_all(scope: &ActionsScope) -> GitHubResult<Vec<HandleVariable>, VariableError> {
+        Ok(HandleVariable::try_fetch_all_content(scope)?.into_iter()
+            .map(|Variable { name, .. }| HandleVariable {
+                scope: scope.clone(),
+                name,
+            }).collect())
+    }
+
+    pub(crate) fn try_create(scope: &ActionsScope, name: impl AsRef<str>, value: impl AsRef<str>, visibility: Option<ActionsVisibility>) -> GitHubResult<HandleVariable, VariableError> {
+        let endpoint = scope.get_endpoint();
+        let name = name.as_ref();
+
+        let payload = get_create_payload(scope, name, value.as_ref(), visibility)?;
+
+        scope.get_client()
+            .post(format!("{endpoint}/variables"))?
+            .json(&payload)
+            .send()?;
+
+        Ok(HandleVariable {
+            name: name.to_owned(),
+            scope: scope.clone(),
+        })
+    }
+
+    pub(crate) fn try_ensure<K, V>(scope: &ActionsScope, variables: impl IntoIterator<Item = (K, V)>) -> GitHubResult<VariableReport, VariableError>
+    where K: AsRef<str>, V: AsRef<str> {
+        let existing = HandleVariable::try_fetch_all_content(scope)?;
+        let mut report = VariableReport::default();
+
+        for (name, value) in variables {
+            let value = value.as_ref();
+            let name = name.as_ref();
+
+            match get_ensure_action(existing.as_slice(), name, value) {
+                EnsureAction::Keep { name } => {
+                    report.unchanged.push(name);
+                },
+                EnsureAction::Update { name } => {
+                    let variable = HandleVariable {
+                        scope: scope.clone(),
+                        name: name.clone(),
+                    };
+
+                    variable.try_set_value(value)?;
+                    report.updated.push(name);
+                },
+                EnsureAction::Create { name } => {
+                    HandleVariable::try_create(scope, name.as_str(), value, None)?;
+                    report.created.push(name);
+                },
+            }
+        }
+
+        Ok(report)
+    }
+
+    pub fn try_get_variable(&self) -> GitHubResult<Variable, VariableError> {
+        Ok(self.get_client()
+            .get(self.get_endpoint())?
+            .send()?.json()?)
+    }
+
+    pub fn try_get_value(&self) -> GitHubResult<String, VariableError> {
+        let Variable { value, .. } = self.try_get_variable()?;
+        Ok(value)
+    }
+
+    pub fn try_set_value(&self, value: impl AsRef<str>) -> GitHubResult<(), VariableError> {
+        let payload = serde_json::json!({
+            "value": value.as_ref(),
+        });
+
+        self.get_client()
+            .patch(self.get_endpoint())?
+            .json(&payload)
+            .send()?;
+
+        Ok(())
+    }
+
+    /// Renames the variable, returns a handle to the renamed variable.
+    pub fn try_rename(&self, name: impl AsRef<str>) -> GitHubResult<HandleVariable, VariableError> {
+        let name = name.as_ref();
+
+        let payload = serde_json::json!({
+            "name": name,
+        });
+
+        self.get_client()
+            .patch(self.get_endpoint())?
+            .json(&payload)
+            .send()?;
+
+        Ok(HandleVariable {
+            scope: self.scope.clone(),
+            name: name.to_owned(),
+        })
+    }
+
+    /// Changes the visibility of an organization variable.
+    pub fn try_set_visibility(&self, visibility: ActionsVisibility) -> GitHubResult<(), VariableError> {
+        self.require_organization()?;
+
+        let payload = serde_json::json!({
+            "visibility": visibility,
+        });
+
+        self.get_client()
+            .patch(self.get_endpoint())?
+            .json(&payload)
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_delete(&self) -> GitHubResult<(), VariableError> {
+        self.get_client()
+            .delete(self.get_endpoint())?
+            .send()?;
+
+        Ok(())
+    }
+
+    /// Gets the repositories which can access an organization variable with selected visibility.
+    pub fn try_get_selected_repositories(&self) -> GitHubResult<Vec<HandleRepository>, VariableError> {
+        self.require_organization()?;
+
+        Ok(self.scope.try_fetch_selected_repositories({
+            self.get_endpoint()
+        })?)
+    }
+
+    /// Replaces the repositories which can access an organization variable with selected visibility.
+    pub fn try_set_selected_repositories(&self, repositories: impl AsRef<[HandleRepository]>) -> GitHubResult<(), VariableError> {
+        self.require_organization()?;
+
+        Ok(self.scope.try_set_selected_repositories(self.get_endpoint(), {
+            repositories
+        })?)
+    }
+
+    pub fn try_add_selected_repository(&self, repository: &HandleRepository) -> GitHubResult<(), VariableError> {
+        self.require_organization()?;
+
+        Ok(self.scope.try_add_selected_repository(self.get_endpoint(), {
+            repository
+        })?)
+    }
+
+    pub fn try_remove_selected_repository(&self, repository: &HandleRepository) -> GitHubResult<(), VariableError> {
+        self.require_organization()?;
+
+        Ok(self.scope.try_remove_selected_repository(self.get_endpoint(), {
+            repository
+        })?)
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn require_organization(&self) -> GitHubResult<(), VariableError> {
+        if self.scope.is_organization() { Ok(()) } else {
+            Err(VariableError::Organization {
+                scope: self.scope.to_string()
+            })
+        }
+    }
+}
+
+impl<'a> GitHubEndpoint<'a> for HandleVariable {
+    fn get_endpoint(&'a self) -> Cow<'a, str> {
+        let HandleVariable { scope, name } = { self };
+        format!("{endpoint}/variables/{name}", endpoint = scope.get_endpoint()).into()
+    }
+}
+
+impl<'a> GitHubProperties<'a> for HandleVariable {
+    type Content = Variable;
+    type Parent = ActionsScope;
+
+    fn get_client(&'a self) -> &'a Client {
+        self.scope.get_client()
+    }
+
+    fn get_parent(&'a self) -> &'a Self::Parent {
+        &(self.scope)
+    }
+}
+
+impl FmtDisplay for HandleVariable {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        let HandleVariable { name, .. } = { self };
+        write!(fmt, "{name}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::client::{
+
+        account::{
+
+            organization::{HandleOrganization},
+            user::{HandleUser},
+            Account,
+        },
+
+        repository::{HandleRepository},
+        client::{Client},
+
+        models::common::actions::{
+
+            ActionsVisibility,
+            Variable,
+        },
+    };
+
+    use super::{
+
+        get_create_payload,
+        get_ensure_action,
+        ActionsScope,
+        EnsureAction,
+        VariableError,
+    };
+
+    fn get_variable(name: &str, value: &str) -> Variable {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "value": value,
+            "created_at": "2019-08-10T14:59:22Z",
+            "updated_at": "2020-01-10T14:59:22Z",
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let variable: Variable = serde_json::from_value(serde_json::json!({
+            "name": "USERNAME",
+            "value": "octocat",
+            "created_at": "2019-08-10T14:59:22Z",
+            "updated_at": "2020-01-10T14:59:22Z",
+            "visibility": "selected",
+            "selected_repositories_url": "https://api.github.com/orgs/octo-org/actions/variables/USERNAME/repositories",
+        })).unwrap();
+
+        assert_eq!(variable.name, "USERNAME");
+        assert_eq!(variable.value, "octocat");
+        assert_eq!(variable.visibility, Some(ActionsVisibility::Selected));
+
+        let variable = get_variable("USERNAME", "octocat");
+
+        assert_eq!(variable.visibility, None);
+        assert!(serde_json::to_value(&variable).unwrap()
+            .get("visibility").is_none());
+    }
+
+    #[test]
+    fn test_create_payload() {
+        let client = Client::new().unwrap();
+
+        let repository = ActionsScope::Repository {
+            repository: HandleRepository {
+                owner: Account::User(HandleUser {
+                    client: client.clone(),
+                    name: "octocat".to_owned(),
+                }),
+                name: "hello-world".to_owned(),
+            },
+        };
+
+        let organization = ActionsScope::Organization {
+            organization: HandleOrganization {
+                client: client.clone(),
+                name: "octo-org".to_owned(),
+            },
+        };
+
+        let payload = get_create_payload(&repository, "USERNAME", "octocat", None)
+            .unwrap();
+
+        assert_eq!(payload, serde_json::json!({ "name": "USERNAME", "value": "octocat" }));
+
+        let result = get_create_payload(&repository, "USERNAME", "octocat", Some(ActionsVisibility::All));
+
+        assert!(matches!(result, Err(VariableError::Organization { .. })));
+
+        let payload = get_create_payload(&organization, "USERNAME", "octocat", None)
+            .unwrap();
+
+        assert_eq!(payload["visibility"], "private");
+
+        let payload = get_create_payload(&organization, "USERNAME", "octocat", Some(ActionsVisibility::Selected))
+            .unwrap();
+
+        assert_eq!(payload["visibility"], "selected");
+    }
+
+    #[test]
+    fn test_ensure_action() {
+        let existing = vec![
+            get_variable("USERNAME", "octocat"),
+            get_variable("REGION", "eu"),
+        ];
+
+        assert_eq!(get_ensure_action(&existing, "username", "octocat"), EnsureAction::Keep {
+            name: "USERNAME".to_owned(),
+        });
+
+        assert_eq!(get_ensure_action(&existing, "Region", "us"), EnsureAction::Update {
+            name: "REGION".to_owned(),
+        });
+
+        assert_eq!(get_ensure_action(&existing, "TOKEN_URL", "https://example.com"), EnsureAction::Create {
+            name: "TOKEN_URL".to_owned(),
+        });
+    }
+}
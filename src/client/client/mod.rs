@@ -83,7 +83,27 @@ use crate::client::{
         Account,
     },
 
-    models::common::user::{User},
+    search::{
+
+        SearchError,
+        SearchKind,
+        try_search,
+    },
+
+    models::common::{
+
+        search::{
+
+            CommitResult,
+            SearchRepository,
+            SearchResults,
+            SearchQuery,
+            CodeResult,
+        },
+
+        issue::{Issue},
+        user::{User},
+    },
 
     GitHubResult, 
     GitHubError,
@@ -109,6 +129,8 @@ pub enum ClientResponseError {
     Validation { code: u16, message: String },
     #[error("{code} Nothing found, reason: '{message}'")]
     Nothing { code: u16, message: String },
+    #[error("{code} Rate limited, reason: '{message}'")]
+    RateLimited { code: u16, message: String, reset: Option<u64> },
     #[error("{code} Unhandled, reason: '{message}'")]
     Unhandled { code: u16, message: String },
     #[error("Malformed response, reason: '{reason}'")]
@@ -188,6 +210,35 @@ impl Client {
         }
     }
 
+    /// Gets the user the token belongs to. Installation tokens such as the
+    /// `GITHUB_TOKEN` of a workflow cannot read it and get a 403 error.
+    pub fn try_get_authenticated_user(&self) -> GitHubResult<User, ClientError> {
+        self.get("user")?
+            .send()?.json()
+    }
+
+    /// Searches file contents, `limit` caps the number of results collected.
+    pub fn try_search_code(&self, query: &SearchQuery, limit: usize) -> GitHubResult<SearchResults<CodeResult>, SearchError> {
+        try_search(self, SearchKind::Code, query, limit)
+    }
+
+    /// Searches issues and pull requests, use `is:issue` or `is:pr` to get only one of them.
+    pub fn try_search_issues(&self, query: &SearchQuery, limit: usize) -> GitHubResult<SearchResults<Issue>, SearchError> {
+        try_search(self, SearchKind::Issues, query, limit)
+    }
+
+    pub fn try_search_commits(&self, query: &SearchQuery, limit: usize) -> GitHubResult<SearchResults<CommitResult>, SearchError> {
+        try_search(self, SearchKind::Commits, query, limit)
+    }
+
+    pub fn try_search_repositories(&self, query: &SearchQuery, limit: usize) -> GitHubResult<SearchResults<SearchRepository>, SearchError> {
+        try_search(self, SearchKind::Repositories, query, limit)
+    }
+
+    pub fn try_search_users(&self, query: &SearchQuery, limit: usize) -> GitHubResult<SearchResults<User>, SearchError> {
+        try_search(self, SearchKind::Users, query, limit)
+    }
+
     pub fn try_get_account(&self, name: impl AsRef<str>) -> GitHubResult<Account, GitHubError> {
         let name = name.as_ref();
 
@@ -485,10 +536,20 @@ impl GitHubRequestBuilder {
             }
 
             let code = response.code();
+            let reset = response.get_rate_limit_reset();
+
+            let limited = response.get_rate_limit_remaining() == Some(0)
+                || response.get_header("retry-after").is_some();
+
             let Capsule { message } = response.json()
                 .unwrap_or_default();
 
             match code {
+                403 | 429 if limited => Err(ClientError::Response({
+                    ClientResponseError::RateLimited {
+                        code, message, reset
+                    }
+                })),
                 401 | 403 => Err(ClientError::Response({
                     ClientResponseError::Unauthorized { 
                         code, message 
@@ -534,6 +595,30 @@ impl GitHubResponse {
             .as_u16()
     }
 
+    pub fn get_header(&self, name: impl AsRef<str>) -> Option<String> {
+        self.inner.headers()
+            .get(name.as_ref())
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_owned())
+    }
+
+    /// Requests left in the rate limit bucket the request was counted against.
+    pub fn get_rate_limit_remaining(&self) -> Option<u64> {
+        self.get_header("x-ratelimit-remaining")
+            .and_then(|remaining| remaining.parse().ok())
+    }
+
+    /// When the rate limit resets in seconds since the epoch, secondary rate
+    /// limits give a `retry-after` delay instead.
+    pub fn get_rate_limit_reset(&self) -> Option<u64> {
+        if let Some(delay) = self.get_header("retry-after").and_then(|delay| delay.parse::<u64>().ok()) {
+            return Some(chrono::Utc::now().timestamp().max(0) as u64 + delay)
+        }
+
+        self.get_header("x-ratelimit-reset")
+            .and_then(|reset| reset.parse().ok())
+    }
+
     pub fn bytes(self) -> GitHubResult<Bytes, ClientError> {
         let bytes = {
             self.inner.bytes().map_err(|_| {
@@ -0,0 +1,309 @@
+use std::{
+
+    collections::{BTreeMap},
+};
+
+use thiserror::{Error};
+
+use crate::client::{
+
+    account::organization::team::{
+
+        HandleTeamError,
+        HandleTeam,
+    },
+
+    repository::{
+
+        issue::{
+
+            comment::{
+
+                IssueCommentError,
+                HandleIssueComment,
+            },
+
+            IssueError,
+            HandleIssue,
+        },
+
+        HandleRepositoryError,
+        HandleRepository,
+    },
+
+    models::{
+
+        common::{
+
+            reaction::{ReactionContent},
+            permission::{Permission},
+            user::{User},
+        },
+
+        events::{EventIssueComment},
+    },
+
+    ClientError,
+    GitHubResult,
+};
+
+pub mod parser;
+pub use parser::{
+
+    Command,
+    parse,
+};
+
+#[derive(Error, Debug)]
+pub enum CommandError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Issue error!")]
+    Issue(#[from] IssueError),
+    #[error("Issue comment error!")]
+    Comment(#[from] IssueCommentError),
+    #[error("Team error!")]
+    Team(#[from] HandleTeamError),
+    #[error("Repository error!")]
+    Repository(#[from] HandleRepositoryError),
+    #[error("Invalid arguments for /{command}: {reason}")]
+    Argument { command: String, reason: String },
+    #[error("{message}")]
+    Failed { message: String },
+}
+
+/// Who may run a command.
+#[derive(Clone, Debug)]
+pub enum Authorization {
+    Anyone,
+    /// Members of any of the teams.
+    Teams(Vec<HandleTeam>),
+    /// Users with at least this permission on the repository.
+    Permission(Permission),
+}
+
+impl Authorization {
+    pub(crate) fn try_allows(&self, repository: &HandleRepository, user: &User) -> GitHubResult<bool, CommandError> {
+        match self {
+            Authorization::Anyone => Ok(true),
+            Authorization::Teams(teams) => {
+                for team in teams {
+                    if team.try_has_team_member(user.clone())? {
+                        return Ok(true)
+                    }
+                }
+
+                Ok(false)
+            },
+            Authorization::Permission(required) => {
+                Ok(repository.try_user_can(user, *required)?)
+            },
+        }
+    }
+}
+
+/// What to answer a command with, a reaction on the comment and/or a new comment.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Reply {
+    pub(crate) reaction: Option<ReactionContent>,
+    pub(crate) comment: Option<String>,
+}
+
+impl Reply {
+    pub fn new() -> Reply {
+        Reply::default()
+    }
+
+    pub fn reaction(reaction: ReactionContent) -> Reply {
+        Reply::new().with_reaction(reaction)
+    }
+
+    pub fn comment(body: impl AsRef<str>) -> Reply {
+        Reply::new().with_comment(body)
+    }
+
+    pub fn with_reaction(mut self, reaction: ReactionContent) -> Self {
+        self.reaction = Some(reaction);
+        self
+    }
+
+    pub fn with_comment(mut self, body: impl AsRef<str>) -> Self {
+        self.comment = Some(body.as_ref().to_owned());
+        self
+    }
+}
+
+/// Where a command was given and by whom.
+#[derive(Clone, Debug)]
+pub struct CommandContext {
+    pub(crate) repository: HandleRepository,
+    pub(crate) issue: HandleIssue,
+    pub(crate) comment: HandleIssueComment,
+    pub(crate) author: User,
+}
+
+impl CommandContext {
+    pub fn get_repository(&self) -> &HandleRepository {
+        &(self.repository)
+    }
+
+    /// The issue or pull request the comment was made on.
+    pub fn get_issue(&self) -> &HandleIssue {
+        &(self.issue)
+    }
+
+    pub fn get_comment(&self) -> &HandleIssueComment {
+        &(self.comment)
+    }
+
+    pub fn get_author(&self) -> &User {
+        &(self.author)
+    }
+
+    pub fn try_reply(&self, reply: &Reply) -> GitHubResult<(), CommandError> {
+        if let Some(reaction) = reply.reaction {
+            self.comment.try_add_reaction(reaction)?;
+        }
+
+        if let Some(ref body) = reply.comment {
+            self.issue.try_create_comment(body)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Arguments of a command as a typed value.
+pub trait FromCommand: Sized {
+    fn try_from_command(command: &Command) -> GitHubResult<Self, CommandError>;
+}
+
+impl FromCommand for Command {
+    fn try_from_command(command: &Command) -> GitHubResult<Self, CommandError> {
+        Ok(command.clone())
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum CommandOutcome {
+    Handled { command: Command },
+    Denied { command: Command },
+    Failed { command: Command, reason: String },
+    /// No handler is registered, the command is left for other bots.
+    Unknown { command: Command },
+}
+
+type Handler<'a> = Box<dyn Fn(&CommandContext, &Command) -> GitHubResult<Reply, CommandError> + 'a>;
+
+struct Route<'a> {
+    authorization: Authorization,
+    handler: Handler<'a>,
+}
+
+/// Runs the handlers of the commands found in issue comments. Recognized
+/// commands are acknowledged with a reaction before their handler runs,
+/// denied and failed commands are answered with a comment.
+pub struct CommandDispatcher<'a> {
+    routes: BTreeMap<String, Route<'a>>,
+    acknowledge: Option<ReactionContent>,
+}
+
+impl<'a> CommandDispatcher<'a> {
+    pub fn new() -> CommandDispatcher<'a> {
+        CommandDispatcher {
+            routes: BTreeMap::new(),
+            acknowledge: Some(ReactionContent::Eyes),
+        }
+    }
+
+    pub fn with_acknowledge(mut self, acknowledge: Option<ReactionContent>) -> Self {
+        self.acknowledge = acknowledge;
+        self
+    }
+
+    /// Registers a handler, its arguments are parsed into `T` first.
+    pub fn with_command<T, F>(mut self, name: impl AsRef<str>, authorization: Authorization, handler: F) -> Self
+    where T: FromCommand, F: Fn(&CommandContext, T) -> GitHubResult<Reply, CommandError> + 'a {
+        let handler = move |context: &CommandContext, command: &Command| {
+            handler(context, T::try_from_command(command)?)
+        };
+
+        self.routes.insert(name.as_ref().to_lowercase(), Route {
+            authorization,
+            handler: Box::new(handler),
+        });
+
+        self
+    }
+
+    /// Runs the commands of a newly created comment, edits, deletions and
+    /// comments by bots are ignored.
+    pub fn try_dispatch(&self, repository: &HandleRepository, event: &EventIssueComment) -> GitHubResult<Vec<CommandOutcome>, CommandError> {
+        let EventIssueComment::Created { issue, comment } = event else {
+            return Ok(Vec::new())
+        };
+
+        let author = comment.get_author();
+
+        if author.is_bot() {
+            return Ok(Vec::new())
+        }
+
+        let issue = HandleIssue::new(repository, issue.get_number());
+
+        let context = CommandContext {
+            repository: repository.clone(),
+            comment: HandleIssueComment::new(&issue, comment.get_number()),
+            issue,
+            author,
+        };
+
+        self.try_dispatch_body(&context, comment.get_body())
+    }
+
+    pub fn try_dispatch_body(&self, context: &CommandContext, body: impl AsRef<str>) -> GitHubResult<Vec<CommandOutcome>, CommandError> {
+        let mut outcomes = Vec::new();
+
+        for command in parse(body) {
+            let Some(route) = self.routes.get(command.name.as_str()) else {
+                outcomes.push(CommandOutcome::Unknown { command });
+                continue
+            };
+
+            if !(route.authorization.try_allows(&(context.repository), &(context.author))?) {
+                context.try_reply(&Reply::reaction(ReactionContent::ThumbsDown).with_comment({
+                    format!("@{author} is not allowed to run `{command}`.", author = context.author)
+                }))?;
+
+                outcomes.push(CommandOutcome::Denied { command });
+                continue
+            }
+
+            if let Some(reaction) = self.acknowledge {
+                context.try_reply(&Reply::reaction(reaction))?;
+            }
+
+            match (route.handler)(context, &command) {
+                Ok(reply) => {
+                    context.try_reply(&reply)?;
+                    outcomes.push(CommandOutcome::Handled { command });
+                },
+                Err(error) => {
+                    context.try_reply(&Reply::reaction(ReactionContent::Confused).with_comment({
+                        format!("`{command}` failed: {error}")
+                    }))?;
+
+                    outcomes.push(CommandOutcome::Failed { command, reason: error.to_string() });
+                },
+            }
+        }
+
+        Ok(outcomes)
+    }
+}
+
+impl<'a> Default for CommandDispatcher<'a> {
+    fn default() -> Self {
+        CommandDispatcher::new()
+    }
+}
@@ -0,0 +1,233 @@
+use std::{
+
+    collections::{BTreeMap},
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+};
+
+/// A `/command` line of a comment, `--flag` without a value maps to `None`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Command {
+    pub(crate) name: String,
+    pub(crate) arguments: Vec<String>,
+    pub(crate) flags: BTreeMap<String, Option<String>>,
+    pub(crate) line: usize,
+}
+
+impl Command {
+    /// Name of the command in lowercase, without the slash.
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_arguments(&self) -> &[String] {
+        self.arguments.as_slice()
+    }
+
+    pub fn get_argument(&self, index: usize) -> Option<String> {
+        self.arguments.get(index)
+            .cloned()
+    }
+
+    pub fn get_flags(&self) -> &BTreeMap<String, Option<String>> {
+        &(self.flags)
+    }
+
+    pub fn get_flag(&self, name: impl AsRef<str>) -> Option<String> {
+        self.flags.get(name.as_ref())
+            .cloned()
+            .flatten()
+    }
+
+    pub fn has_flag(&self, name: impl AsRef<str>) -> bool {
+        self.flags.contains_key(name.as_ref())
+    }
+
+    /// Line of the comment body the command was found on, starting at 1.
+    pub fn get_line(&self) -> usize {
+        self.line
+    }
+}
+
+impl FmtDisplay for Command {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        write!(fmt, "/{name}", name = self.name)
+    }
+}
+
+/// Finds the commands of a comment body, one per line starting with a slash.
+/// Lines in fenced or indented code blocks and quotes are ignored, so quoting
+/// an earlier command does not run it again.
+pub fn parse(body: impl AsRef<str>) -> Vec<Command> {
+    let mut commands = Vec::new();
+    let mut fence: Option<&str> = None;
+
+    for (index, line) in body.as_ref().lines().enumerate() {
+        let trimmed = line.trim_start();
+
+        let marker = ["```", "~~~"].into_iter()
+            .find(|marker| trimmed.starts_with(marker));
+
+        match (fence, marker) {
+            (Some(open), Some(marker)) if open == marker => {
+                fence = None;
+                continue
+            },
+            (None, Some(marker)) => {
+                fence = Some(marker);
+                continue
+            },
+            (Some(_), _) => continue,
+            _ => {},
+        }
+
+        if line.starts_with('\t') || line.starts_with("    ") {
+            continue
+        }
+
+        if let Some(command) = parse_line(trimmed, index + 1) {
+            commands.push(command);
+        }
+    }
+
+    commands
+}
+
+fn parse_line(line: &str, number: usize) -> Option<Command> {
+    let line = line.strip_prefix('/')?;
+
+    if !(line.starts_with(|character: char| character.is_ascii_alphabetic())) {
+        return None
+    }
+
+    let mut tokens = split(line).into_iter();
+
+    let name = tokens.next()?
+        .to_lowercase();
+
+    let valid = name.chars().all(|character| {
+        character.is_ascii_alphanumeric() || character == '-' || character == '_'
+    });
+
+    if !(valid) {
+        return None
+    }
+
+    let mut arguments = Vec::new();
+    let mut flags = BTreeMap::new();
+    let mut positional = false;
+
+    for token in tokens {
+        if positional {
+            arguments.push(token);
+            continue
+        }
+
+        if token == "--" {
+            positional = true;
+            continue
+        }
+
+        match token.strip_prefix("--") {
+            Some(flag) => match flag.split_once('=') {
+                Some((flag, value)) => flags.insert(flag.to_owned(), Some(value.to_owned())),
+                None => flags.insert(flag.to_owned(), None),
+            },
+            None => {
+                arguments.push(token);
+                continue
+            },
+        };
+    }
+
+    Some(Command {
+        name,
+        arguments,
+        flags,
+        line: number,
+    })
+}
+
+/// Splits a line on whitespace like a shell, single quotes are taken
+/// literally while double quotes allow escaping with a backslash. An
+/// unterminated quote runs to the end of the line.
+fn split(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+
+    let mut quote: Option<char> = None;
+    let mut pending = false;
+
+    let mut characters = line.chars();
+
+    while let Some(character) = characters.next() {
+        match (quote, character) {
+            (Some('\''), '\'') | (Some('"'), '"') => quote = None,
+            (Some('"'), '\\') => match characters.next() {
+                Some(escaped) => token.push(escaped),
+                None => token.push('\\'),
+            },
+            (Some(_), character) => token.push(character),
+            (None, '\'' | '"') => {
+                quote = Some(character);
+                pending = true;
+            },
+            (None, character) if character.is_whitespace() => {
+                if pending || !(token.is_empty()) {
+                    tokens.push(std::mem::take(&mut token));
+                }
+
+                pending = false;
+            },
+            (None, character) => token.push(character),
+        }
+    }
+
+    if pending || !(token.is_empty()) {
+        tokens.push(token);
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{parse};
+
+    #[test]
+    fn test_parse() {
+        let commands = parse(concat!(
+            "Thanks! Let's ship it.\n",
+            "/deploy staging --force --ref=\"release 1.2\"\n",
+            "> /deploy production\n",
+            "```\n",
+            "/deploy production\n",
+            "```\n",
+            "    /deploy production\n",
+            "/Label 'needs review' \"say \\\"hi\\\"\" -- --literal\n",
+            "/ not a command\n",
+        ));
+
+        assert_eq!(commands.len(), 2);
+
+        let deploy = &commands[0];
+
+        assert_eq!(deploy.get_name(), "deploy");
+        assert_eq!(deploy.get_arguments(), ["staging"]);
+        assert_eq!(deploy.get_flag("ref"), Some("release 1.2".to_owned()));
+        assert!(deploy.has_flag("force"));
+        assert_eq!(deploy.get_line(), 2);
+
+        let label = &commands[1];
+
+        assert_eq!(label.get_name(), "label");
+        assert_eq!(label.get_arguments(), ["needs review", "say \"hi\"", "--literal"]);
+        assert!(label.get_flags().is_empty());
+    }
+}
@@ -6,4 +6,34 @@ use chrono::{
 };
 
 pub type Duration = ChronoDuration;
-pub type Date = ChronoDateTime<ChronoUtc>;
\ No newline at end of file
+pub type Date = ChronoDateTime<ChronoUtc>;
+
+/// Percent encodes a value for use as a single path segment of an endpoint,
+/// names of labels and other user defined values may contain spaces or slashes.
+pub(crate) fn encode_segment(segment: impl AsRef<str>) -> String {
+    let mut encoded = String::new();
+
+    for byte in segment.as_ref().bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            },
+            _ => encoded.push_str(format!("%{byte:02X}").as_str()),
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{encode_segment};
+
+    #[test]
+    fn test_encode_segment() {
+        assert_eq!(encode_segment("good first issue"), "good%20first%20issue");
+        assert_eq!(encode_segment("type: bug/ui"), "type%3A%20bug%2Fui");
+        assert_eq!(encode_segment("🚀"), "%F0%9F%9A%80");
+    }
+}
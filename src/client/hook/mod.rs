@@ -0,0 +1,361 @@
+use std::{
+
+    borrow::{Cow},
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+};
+
+use thiserror::{Error};
+
+use crate::client::{
+
+    account::organization::{HandleOrganization},
+    repository::{HandleRepository},
+
+    client::{
+
+        ClientResponseError,
+        ClientError,
+        Client,
+    },
+
+    models::common::hook::{
+
+        HookDelivery,
+        Hook,
+    },
+
+    GitHubProperties,
+    GitHubEndpoint,
+    GitHubResult,
+    Number,
+};
+
+#[derive(Error, Debug)]
+pub enum HookError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Hook not found: {number}")]
+    Nothing { number: Number },
+    #[error("Hook delivery not found: {number}")]
+    Delivery { number: Number },
+    #[error("Only repository hooks can be tested, ping organization hooks instead!")]
+    Test,
+}
+
+/// Where a hook is defined, organization hooks receive the events of all its repositories.
+#[derive(Clone, Debug)]
+pub enum HookScope {
+    Organization { organization: HandleOrganization },
+    Repository { repository: HandleRepository },
+}
+
+impl HookScope {
+    pub(crate) fn get_client(&self) -> &Client {
+        match self {
+            HookScope::Organization { organization } => organization.get_client(),
+            HookScope::Repository { repository } => repository.get_client(),
+        }
+    }
+
+    pub fn is_organization(&self) -> bool {
+        matches!(self, HookScope::Organization { .. })
+    }
+
+    pub fn is_repository(&self) -> bool {
+        matches!(self, HookScope::Repository { .. })
+    }
+
+    pub fn try_get_hook(&self, number: impl Into<Number>) -> GitHubResult<HandleHook, HookError> {
+        HandleHook::try_fetch(self, number)
+    }
+
+    pub fn try_has_hook(&self, number: impl Into<Number>) -> GitHubResult<bool, HookError> {
+        match HandleHook::try_fetch(self, number) {
+            Err(HookError::Nothing { .. }) => Ok(false),
+            Err(error) => Err(error),
+            Ok(_) => Ok(true),
+        }
+    }
+
+    pub fn try_get_all_hooks(&self) -> GitHubResult<Vec<HandleHook>, HookError> {
+        HandleHook::try_fetch_all(self)
+    }
+
+    pub fn try_get_all_hook_content(&self) -> GitHubResult<Vec<Hook>, HookError> {
+        HandleHook::try_fetch_all_content(self)
+    }
+
+    pub fn try_create_hook(&self, hook: &Hook) -> GitHubResult<HandleHook, HookError> {
+        HandleHook::try_create(self, hook)
+    }
+
+    pub fn try_delete_hook(&self, number: impl Into<Number>) -> GitHubResult<(), HookError> {
+        HandleHook::try_fetch(self, number)?
+            .try_delete()
+    }
+}
+
+impl<'a> GitHubEndpoint<'a> for HookScope {
+    fn get_endpoint(&'a self) -> Cow<'a, str> {
+        match self {
+            HookScope::Organization { organization } => format!("orgs/{organization}/hooks").into(),
+            HookScope::Repository { repository } => format!("repos/{repository}/hooks").into(),
+        }
+    }
+}
+
+impl FmtDisplay for HookScope {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        match self {
+            HookScope::Organization { organization } => write!(fmt, "{organization}"),
+            HookScope::Repository { repository } => write!(fmt, "{repository}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleHook {
+    scope: HookScope,
+    number: Number,
+}
+
+impl HandleHook {
+    pub(crate) fn try_fetch(scope: &HookScope, number: impl Into<Number>) -> GitHubResult<HandleHook, HookError> {
+        let endpoint = scope.get_endpoint();
+        let number = number.into();
+
+        let Hook { number, .. } = {
+
+            let result = {
+
+                scope.get_client()
+                    .get(format!("{endpoint}/{number}"))?
+                    .send()
+            };
+
+            match result {
+                Err(ClientError::Response(ClientResponseError::Nothing { .. })) => {
+                    return Err(HookError::Nothing { number })
+                },
+                Err(error) => return Err(error.into()),
+                Ok(response) => response.json()?,
+            }
+        };
+
+        Ok(HandleHook {
+            scope: scope.clone(),
+            number,
+        })
+    }
+
+    pub(crate) fn try_fetch_all_content(scope: &HookScope) -> GitHubResult<Vec<Hook>, HookError> {
+        let endpoint = scope.get_endpoint();
+
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let hooks: Vec<Hook> = {
+
+                scope.get_client()
+                    .get(endpoint.as_ref())?
+                    .query(&[("per_page", 100), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = hooks.len();
+
+            collection.extend(hooks);
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub(crate) fn try_fetch_all(scope: &HookScope) -> GitHubResult<Vec<HandleHook>, HookError> {
+        Ok(HandleHook::try_fetch_all_content(scope)?
+            .into_iter()
+            .map(|Hook { number, .. }| HandleHook {
+                scope: scope.clone(),
+                number,
+            })
+            .collect())
+    }
+
+    pub(crate) fn try_create(scope: &HookScope, hook: &Hook) -> GitHubResult<HandleHook, HookError> {
+        let endpoint = scope.get_endpoint();
+
+        let Hook { number, .. } = {
+
+            scope.get_client()
+                .post(endpoint.as_ref())?
+                .json(hook)
+                .send()?.json()?
+        };
+
+        Ok(HandleHook {
+            scope: scope.clone(),
+            number,
+        })
+    }
+
+    pub fn try_get_hook(&self) -> GitHubResult<Hook, HookError> {
+        Ok(self.get_client()
+            .get(self.get_endpoint())?
+            .send()?.json()?)
+    }
+
+    /// Replaces the configuration, events and active flag of the hook. Hooks read
+    /// with `try_get_hook` carry no secret, the current one is kept for them.
+    pub fn try_update(&self, hook: &Hook) -> GitHubResult<(), HookError> {
+        let payload = serde_json::json!({
+            "active": hook.active,
+            "events": hook.events,
+        });
+
+        self.get_client()
+            .patch(self.get_endpoint())?
+            .json(&payload)
+            .send()?;
+
+        // Unlike the hook endpoint, the configuration one keeps a secret left out.
+        self.get_client()
+            .patch(format!("{endpoint}/config", endpoint = self.get_endpoint()))?
+            .json(&(hook.config))
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_delete(&self) -> GitHubResult<(), HookError> {
+        self.get_client()
+            .delete(self.get_endpoint())?
+            .send()?;
+
+        Ok(())
+    }
+
+    /// Sends a `ping` event to the hook.
+    pub fn try_ping(&self) -> GitHubResult<(), HookError> {
+        self.get_client()
+            .post(format!("{endpoint}/pings", endpoint = self.get_endpoint()))?
+            .send()?;
+
+        Ok(())
+    }
+
+    /// Delivers the latest push to the repository again, if the hook is subscribed to `push`.
+    pub fn try_test(&self) -> GitHubResult<(), HookError> {
+        if self.scope.is_organization() {
+            return Err(HookError::Test)
+        }
+
+        self.get_client()
+            .post(format!("{endpoint}/tests", endpoint = self.get_endpoint()))?
+            .send()?;
+
+        Ok(())
+    }
+
+    /// Lists the most recent deliveries, newest first, without their requests and responses.
+    pub fn try_get_deliveries(&self) -> GitHubResult<Vec<HookDelivery>, HookError> {
+        Ok(self.get_client()
+            .get(format!("{endpoint}/deliveries", endpoint = self.get_endpoint()))?
+            .query(&[("per_page", 100)])
+            .send()?.json()?)
+    }
+
+    pub fn try_get_delivery(&self, number: impl Into<Number>) -> GitHubResult<HookDelivery, HookError> {
+        let endpoint = self.get_endpoint();
+        let number = number.into();
+
+        let result = {
+
+            self.get_client()
+                .get(format!("{endpoint}/deliveries/{number}"))?
+                .send()
+        };
+
+        match result {
+            Err(ClientError::Response(ClientResponseError::Nothing { .. })) => {
+                Err(HookError::Delivery { number })
+            },
+            Err(error) => Err(error.into()),
+            Ok(response) => Ok(response.json()?),
+        }
+    }
+
+    pub fn try_redeliver(&self, number: impl Into<Number>) -> GitHubResult<(), HookError> {
+        let endpoint = self.get_endpoint();
+        let number = number.into();
+
+        let result = {
+
+            self.get_client()
+                .post(format!("{endpoint}/deliveries/{number}/attempts"))?
+                .send()
+        };
+
+        match result {
+            Err(ClientError::Response(ClientResponseError::Nothing { .. })) => {
+                Err(HookError::Delivery { number })
+            },
+            Err(error) => Err(error.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+}
+
+impl From<&HandleHook> for Number {
+    fn from(hook: &HandleHook) -> Number {
+        hook.number
+    }
+}
+
+impl From<HandleHook> for Number {
+    fn from(hook: HandleHook) -> Number {
+        hook.number
+    }
+}
+
+impl<'a> GitHubEndpoint<'a> for HandleHook {
+    fn get_endpoint(&'a self) -> Cow<'a, str> {
+        let HandleHook { scope, number } = { self };
+        format!("{endpoint}/{number}", endpoint = scope.get_endpoint()).into()
+    }
+}
+
+impl<'a> GitHubProperties<'a> for HandleHook {
+    type Content = Hook;
+    type Parent = HookScope;
+
+    fn get_client(&'a self) -> &'a Client {
+        self.scope.get_client()
+    }
+
+    fn get_parent(&'a self) -> &'a Self::Parent {
+        &(self.scope)
+    }
+}
+
+impl FmtDisplay for HandleHook {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        write!(fmt, "{number}", number = self.number)
+    }
+}
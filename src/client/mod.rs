@@ -6,6 +6,12 @@ use std::fmt::{Debug as FmtDebug};
 
 pub mod repository;
 pub mod account;
+pub mod actions;
+pub mod ruleset;
+pub mod hook;
+pub mod commands;
+pub mod search;
+pub mod webhook;
 pub mod client;
 pub mod common;
 pub mod models;
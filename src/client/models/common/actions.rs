@@ -0,0 +1,123 @@
+use std::fmt::{
+
+    Formatter as FmtFormatter,
+    Display as FmtDisplay,
+    Result as FmtResult,
+};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use crate::client::common::{Date};
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum ActionsVisibility {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "private")]
+    Private,
+    #[serde(rename = "selected")]
+    Selected,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct PublicKey {
+    pub(crate) key_id: String,
+    pub(crate) key: String,
+}
+
+impl PublicKey {
+    pub fn get_key_id(&self) -> String {
+        self.key_id.clone()
+    }
+
+    pub fn get_key(&self) -> String {
+        self.key.clone()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct SecretMetadata {
+    pub(crate) name: String,
+    #[serde(rename = "created_at")]
+    pub(crate) date_created: Date,
+    #[serde(rename = "updated_at")]
+    pub(crate) date_updated: Date,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub(crate) visibility: Option<ActionsVisibility>,
+}
+
+impl SecretMetadata {
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_date_created(&self) -> Date {
+        self.date_created
+    }
+
+    pub fn get_date_updated(&self) -> Date {
+        self.date_updated
+    }
+
+    /// Visibility of an organization secret, `None` for other scopes.
+    pub fn get_visibility(&self) -> Option<ActionsVisibility> {
+        self.visibility.clone()
+    }
+}
+
+impl FmtDisplay for SecretMetadata {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{name}", name = self.name)
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct Variable {
+    pub(crate) name: String,
+    pub(crate) value: String,
+    #[serde(rename = "created_at")]
+    pub(crate) date_created: Date,
+    #[serde(rename = "updated_at")]
+    pub(crate) date_updated: Date,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub(crate) visibility: Option<ActionsVisibility>,
+}
+
+impl Variable {
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_value(&self) -> String {
+        self.value.clone()
+    }
+
+    pub fn get_date_created(&self) -> Date {
+        self.date_created
+    }
+
+    pub fn get_date_updated(&self) -> Date {
+        self.date_updated
+    }
+
+    /// Visibility of an organization variable, `None` for other scopes.
+    pub fn get_visibility(&self) -> Option<ActionsVisibility> {
+        self.visibility.clone()
+    }
+}
+
+impl FmtDisplay for Variable {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{name}", name = self.name)
+    }
+}
@@ -0,0 +1,185 @@
+use std::collections::{BTreeMap};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use crate::client::{
+
+    common::{Date},
+    Number,
+};
+
+use super::{
+
+    permission::{
+
+        resolve_permission,
+        parse_permission,
+        Permission,
+    },
+
+    user::{User},
+};
+
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum CollaboratorAffiliation {
+    /// Collaborators who are not members of the organization owning the repository.
+    #[serde(rename = "outside")] Outside,
+    /// Collaborators added to the repository itself, rather than through a team or the organization.
+    #[serde(rename = "direct")] Direct,
+    #[serde(rename = "all")] #[default] All,
+}
+
+impl CollaboratorAffiliation {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            CollaboratorAffiliation::Outside => "outside",
+            CollaboratorAffiliation::Direct => "direct",
+            CollaboratorAffiliation::All => "all",
+        }
+    }
+}
+
+/// A user with access to a repository.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct Collaborator {
+    #[serde(flatten)]
+    pub(crate) user: User,
+    #[serde(default)]
+    pub(crate) role_name: Option<String>,
+    #[serde(default)]
+    pub(crate) permissions: BTreeMap<String, bool>,
+}
+
+impl Collaborator {
+    pub fn get_user(&self) -> User {
+        self.user.clone()
+    }
+
+    /// Name of the role, which differs from the permission for custom roles.
+    pub fn get_role_name(&self) -> Option<String> {
+        self.role_name.clone()
+    }
+
+    /// The highest permission of the collaborator, custom roles give the one they are based on.
+    pub fn get_permission(&self) -> Option<Permission> {
+        resolve_permission(self.role_name.as_deref(), &(self.permissions))
+    }
+}
+
+/// The effective permission of a user on a repository, from collaboration,
+/// teams and the organization alike.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct CollaboratorPermission {
+    pub(crate) permission: String,
+    #[serde(default)]
+    pub(crate) role_name: Option<String>,
+    #[serde(default)]
+    pub(crate) user: Option<User>,
+}
+
+impl CollaboratorPermission {
+    /// The permission, `None` for users without access.
+    pub fn get_permission(&self) -> Option<Permission> {
+        self.role_name.as_ref()
+            .and_then(parse_permission)
+            .or_else(|| parse_permission(self.permission.as_str()))
+    }
+
+    /// Name of the role, such as `write` or the name of a custom role.
+    pub fn get_role_name(&self) -> Option<String> {
+        self.role_name.clone()
+    }
+
+    pub fn get_user(&self) -> Option<User> {
+        self.user.clone()
+    }
+
+    /// Whether the permission grants at least the required one.
+    pub fn includes(&self, required: Permission) -> bool {
+        self.get_permission()
+            .is_some_and(|permission| permission.includes(required))
+    }
+}
+
+/// An invitation to collaborate on a repository.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct RepositoryInvitation {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    #[serde(default)]
+    pub(crate) invitee: Option<User>,
+    #[serde(default)]
+    pub(crate) inviter: Option<User>,
+    pub(crate) permissions: Permission,
+    #[serde(default)]
+    pub(crate) expired: bool,
+    #[serde(rename = "created_at")]
+    pub(crate) date_created: Date,
+}
+
+impl RepositoryInvitation {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_invitee(&self) -> Option<User> {
+        self.invitee.clone()
+    }
+
+    pub fn get_inviter(&self) -> Option<User> {
+        self.inviter.clone()
+    }
+
+    pub fn get_permission(&self) -> Permission {
+        self.permissions
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expired
+    }
+
+    pub fn get_date_created(&self) -> Date {
+        self.date_created
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::client::models::common::permission::{Permission};
+
+    use super::{
+
+        CollaboratorPermission,
+        Collaborator,
+    };
+
+    #[test]
+    fn test_deserialize() {
+        let collaborator: Collaborator = serde_json::from_str(r#"{
+            "type": "User",
+            "login": "octocat",
+            "id": 1,
+            "role_name": "write",
+            "permissions": { "admin": false, "maintain": false, "push": true, "triage": true, "pull": true }
+        }"#).unwrap();
+
+        assert_eq!(collaborator.get_user().get_name(), "octocat");
+        assert_eq!(collaborator.get_permission(), Some(Permission::Push));
+
+        let permission: CollaboratorPermission = serde_json::from_str(r#"{
+            "permission": "none",
+            "role_name": "none"
+        }"#).unwrap();
+
+        assert!(!(permission.includes(Permission::Pull)));
+    }
+}
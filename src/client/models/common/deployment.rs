@@ -0,0 +1,541 @@
+use std::fmt::{
+
+    Formatter as FmtFormatter,
+    Display as FmtDisplay,
+    Result as FmtResult,
+};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use serde_json::{Value};
+
+use crate::client::{
+
+    common::{Date},
+    Number,
+};
+
+use super::{
+
+    environment::{DeploymentReviewer},
+    user::{User},
+};
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum DeploymentState {
+    #[serde(rename = "error")] Error,
+    #[serde(rename = "failure")] Failure,
+    #[serde(rename = "inactive")] Inactive,
+    #[serde(rename = "in_progress")] InProgress,
+    #[serde(rename = "queued")] Queued,
+    #[serde(rename = "pending")] Pending,
+    #[serde(rename = "success")] Success,
+}
+
+impl DeploymentState {
+    /// Whether the deployment has reached a final state.
+    pub fn is_finished(&self) -> bool {
+        matches!(self, DeploymentState::Error
+            | DeploymentState::Failure
+            | DeploymentState::Inactive
+            | DeploymentState::Success)
+    }
+
+    pub fn is_inactive(&self) -> bool {
+        matches!(self, DeploymentState::Inactive)
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct Deployment {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    pub(crate) sha: String,
+    #[serde(rename = "ref")]
+    pub(crate) reference: String,
+    pub(crate) task: String,
+    pub(crate) environment: String,
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+    #[serde(default)]
+    pub(crate) payload: Value,
+    #[serde(default)]
+    pub(crate) creator: Option<User>,
+    #[serde(default)]
+    pub(crate) transient_environment: bool,
+    #[serde(default)]
+    pub(crate) production_environment: bool,
+    #[serde(rename = "created_at")]
+    pub(crate) date_created: Date,
+    #[serde(rename = "updated_at")]
+    pub(crate) date_updated: Date,
+}
+
+impl Deployment {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_sha(&self) -> String {
+        self.sha.clone()
+    }
+
+    pub fn get_reference(&self) -> String {
+        self.reference.clone()
+    }
+
+    pub fn get_task(&self) -> String {
+        self.task.clone()
+    }
+
+    pub fn get_environment(&self) -> String {
+        self.environment.clone()
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    pub fn get_payload(&self) -> Value {
+        self.payload.clone()
+    }
+
+    pub fn get_creator(&self) -> Option<User> {
+        self.creator.clone()
+    }
+
+    pub fn is_transient(&self) -> bool {
+        self.transient_environment
+    }
+
+    pub fn is_production(&self) -> bool {
+        self.production_environment
+    }
+
+    pub fn get_date_created(&self) -> Date {
+        self.date_created
+    }
+
+    pub fn get_date_updated(&self) -> Date {
+        self.date_updated
+    }
+}
+
+impl FmtDisplay for Deployment {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{number}", number = self.number)
+    }
+}
+
+impl From<Deployment> for Number {
+    fn from(deployment: Deployment) -> Number {
+        deployment.number
+    }
+}
+
+/// Options for creating a deployment, by default GitHub merges the default
+/// branch into the ref and requires all commit statuses to pass.
+#[derive(Clone, Debug)]
+#[derive(Serialize)]
+pub struct DeploymentContent {
+    pub(crate) environment: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) task: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) payload: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) auto_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) required_contexts: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) transient_environment: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) production_environment: Option<bool>,
+}
+
+impl DeploymentContent {
+    pub fn new(environment: impl AsRef<str>) -> DeploymentContent {
+        DeploymentContent {
+            environment: environment.as_ref().to_owned(),
+            task: None,
+            description: None,
+            payload: None,
+            auto_merge: None,
+            required_contexts: None,
+            transient_environment: None,
+            production_environment: None,
+        }
+    }
+
+    pub fn get_environment(&self) -> String {
+        self.environment.clone()
+    }
+
+    pub fn with_task(mut self, task: impl AsRef<str>) -> Self {
+        self.task = Some(task.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_description(mut self, description: impl AsRef<str>) -> Self {
+        self.description = Some(description.as_ref().to_owned());
+        self
+    }
+
+    /// Attaches extra information for the deployment tooling, any serializable value works.
+    pub fn with_payload(mut self, payload: impl Serialize) -> Self {
+        self.payload = serde_json::to_value(payload).ok();
+        self
+    }
+
+    pub fn with_auto_merge(mut self, auto_merge: bool) -> Self {
+        self.auto_merge = Some(auto_merge);
+        self
+    }
+
+    /// Commit status contexts which must pass, an empty list skips the checks.
+    pub fn with_required_contexts<T: AsRef<str>>(mut self, contexts: impl AsRef<[T]>) -> Self {
+        self.required_contexts = Some(contexts.as_ref().iter()
+            .map(|context| context.as_ref().to_owned())
+            .collect());
+
+        self
+    }
+
+    pub fn with_transient_environment(mut self, transient: bool) -> Self {
+        self.transient_environment = Some(transient);
+        self
+    }
+
+    pub fn with_production_environment(mut self, production: bool) -> Self {
+        self.production_environment = Some(production);
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct DeploymentStatus {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    pub(crate) state: DeploymentState,
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+    #[serde(default)]
+    pub(crate) environment: Option<String>,
+    #[serde(default)]
+    pub(crate) log_url: Option<String>,
+    #[serde(default)]
+    pub(crate) environment_url: Option<String>,
+    #[serde(default)]
+    pub(crate) creator: Option<User>,
+    #[serde(rename = "created_at")]
+    pub(crate) date_created: Date,
+    #[serde(rename = "updated_at")]
+    pub(crate) date_updated: Date,
+}
+
+impl DeploymentStatus {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_state(&self) -> DeploymentState {
+        self.state.clone()
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    pub fn get_environment(&self) -> Option<String> {
+        self.environment.clone()
+    }
+
+    pub fn get_log_url(&self) -> Option<String> {
+        self.log_url.clone()
+    }
+
+    pub fn get_environment_url(&self) -> Option<String> {
+        self.environment_url.clone()
+    }
+
+    pub fn get_creator(&self) -> Option<User> {
+        self.creator.clone()
+    }
+
+    pub fn get_date_created(&self) -> Date {
+        self.date_created
+    }
+
+    pub fn get_date_updated(&self) -> Date {
+        self.date_updated
+    }
+}
+
+impl FmtDisplay for DeploymentStatus {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{number}", number = self.number)
+    }
+}
+
+/// A new status for a deployment, `log_url` usually points at the workflow run.
+#[derive(Clone, Debug)]
+#[derive(Serialize)]
+pub struct DeploymentStatusContent {
+    pub(crate) state: DeploymentState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) environment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) log_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) environment_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) auto_inactive: Option<bool>,
+}
+
+impl DeploymentStatusContent {
+    pub fn new(state: DeploymentState) -> DeploymentStatusContent {
+        DeploymentStatusContent {
+            state,
+            description: None,
+            environment: None,
+            log_url: None,
+            environment_url: None,
+            auto_inactive: None,
+        }
+    }
+
+    pub fn get_state(&self) -> DeploymentState {
+        self.state.clone()
+    }
+
+    pub fn with_description(mut self, description: impl AsRef<str>) -> Self {
+        self.description = Some(description.as_ref().to_owned());
+        self
+    }
+
+    /// Moves the deployment to another environment.
+    pub fn with_environment(mut self, environment: impl AsRef<str>) -> Self {
+        self.environment = Some(environment.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_log_url(mut self, url: impl AsRef<str>) -> Self {
+        self.log_url = Some(url.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_environment_url(mut self, url: impl AsRef<str>) -> Self {
+        self.environment_url = Some(url.as_ref().to_owned());
+        self
+    }
+
+    /// Whether earlier non-transient deployments of the environment become inactive on success.
+    pub fn with_auto_inactive(mut self, auto_inactive: bool) -> Self {
+        self.auto_inactive = Some(auto_inactive);
+        self
+    }
+}
+
+impl From<DeploymentState> for DeploymentStatusContent {
+    fn from(state: DeploymentState) -> DeploymentStatusContent {
+        DeploymentStatusContent::new(state)
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum ReviewState {
+    #[serde(rename = "approved")] Approved,
+    #[serde(rename = "rejected")] Rejected,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct PendingEnvironment {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    pub(crate) name: String,
+}
+
+impl PendingEnvironment {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl From<&PendingEnvironment> for Number {
+    fn from(environment: &PendingEnvironment) -> Number {
+        environment.number
+    }
+}
+
+impl From<PendingEnvironment> for Number {
+    fn from(environment: PendingEnvironment) -> Number {
+        environment.number
+    }
+}
+
+impl FmtDisplay for PendingEnvironment {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{name}", name = self.name)
+    }
+}
+
+/// A deployment of a workflow run waiting on the protection rules of its environment.
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct PendingDeployment {
+    pub(crate) environment: PendingEnvironment,
+    #[serde(default)]
+    pub(crate) wait_timer: u32,
+    #[serde(rename = "wait_timer_started_at")]
+    #[serde(default)]
+    pub(crate) date_wait_timer_started: Option<Date>,
+    #[serde(default)]
+    pub(crate) current_user_can_approve: bool,
+    #[serde(default)]
+    pub(crate) reviewers: Vec<DeploymentReviewer>,
+}
+
+impl PendingDeployment {
+    pub fn get_environment(&self) -> PendingEnvironment {
+        self.environment.clone()
+    }
+
+    /// Minutes the deployment has to wait, zero without a wait timer.
+    pub fn get_wait_timer(&self) -> u32 {
+        self.wait_timer
+    }
+
+    pub fn get_date_wait_timer_started(&self) -> Option<Date> {
+        self.date_wait_timer_started
+    }
+
+    /// Whether the authenticated user is one of the required reviewers.
+    pub fn can_approve(&self) -> bool {
+        self.current_user_can_approve
+    }
+
+    pub fn get_reviewers(&self) -> Vec<DeploymentReviewer> {
+        self.reviewers.clone()
+    }
+}
+
+impl FmtDisplay for PendingDeployment {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{environment}", environment = self.environment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{
+
+        DeploymentStatusContent,
+        DeploymentContent,
+        DeploymentStatus,
+        DeploymentState,
+        Deployment,
+    };
+
+    #[test]
+    fn test_serialize_content() {
+        let content = DeploymentContent::new("production")
+            .with_task("deploy:migrations")
+            .with_payload(serde_json::json!({ "version": "1.2.3" }))
+            .with_auto_merge(false)
+            .with_required_contexts(Vec::<String>::new())
+            .with_production_environment(true);
+
+        let value = serde_json::to_value(&content)
+            .unwrap();
+
+        assert_eq!(value, serde_json::json!({
+            "environment": "production",
+            "task": "deploy:migrations",
+            "payload": { "version": "1.2.3" },
+            "auto_merge": false,
+            "required_contexts": [],
+            "production_environment": true,
+        }));
+
+        let value = serde_json::to_value(DeploymentContent::new("staging"))
+            .unwrap();
+
+        assert_eq!(value, serde_json::json!({ "environment": "staging" }));
+    }
+
+    #[test]
+    fn test_serialize_status_content() {
+        let content = DeploymentStatusContent::new(DeploymentState::InProgress)
+            .with_log_url("https://github.com/octo-org/octo-repo/actions/runs/42")
+            .with_environment_url("https://staging.example.com")
+            .with_auto_inactive(false);
+
+        let value = serde_json::to_value(&content)
+            .unwrap();
+
+        assert_eq!(value, serde_json::json!({
+            "state": "in_progress",
+            "log_url": "https://github.com/octo-org/octo-repo/actions/runs/42",
+            "environment_url": "https://staging.example.com",
+            "auto_inactive": false,
+        }));
+
+        let value = serde_json::to_value(DeploymentStatusContent::from(DeploymentState::Success))
+            .unwrap();
+
+        assert_eq!(value, serde_json::json!({ "state": "success" }));
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let deployment: Deployment = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "sha": "a84d88e7554fc1fa21bcbc4efae3c782a70d2b9d",
+            "ref": "topic-branch",
+            "task": "deploy",
+            "payload": {},
+            "environment": "production",
+            "description": "Deploy request from hubot",
+            "created_at": "2012-07-20T01:19:13Z",
+            "updated_at": "2012-07-20T01:19:13Z",
+            "transient_environment": false,
+            "production_environment": true,
+        })).unwrap();
+
+        assert_eq!(deployment.get_reference(), "topic-branch");
+        assert_eq!(deployment.get_creator(), None);
+        assert!(deployment.is_production());
+
+        let status: DeploymentStatus = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "state": "inactive",
+            "description": "Deployment finished successfully.",
+            "environment": "production",
+            "log_url": "https://example.com/deployment/42/output",
+            "created_at": "2012-07-20T01:19:13Z",
+            "updated_at": "2012-07-20T01:19:13Z",
+        })).unwrap();
+
+        assert!(status.get_state().is_finished());
+        assert!(status.get_state().is_inactive());
+        assert_eq!(status.get_environment_url(), None);
+    }
+}
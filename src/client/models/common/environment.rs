@@ -0,0 +1,422 @@
+use std::fmt::{
+
+    Formatter as FmtFormatter,
+    Display as FmtDisplay,
+    Result as FmtResult,
+};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use crate::client::{
+
+    common::{Date},
+    Number,
+};
+
+use super::{
+
+    user::{User},
+    team::{Team},
+};
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct DeploymentBranchPolicy {
+    pub(crate) protected_branches: bool,
+    pub(crate) custom_branch_policies: bool,
+}
+
+impl DeploymentBranchPolicy {
+    /// Only branches with branch protection rules can deploy.
+    pub fn protected() -> DeploymentBranchPolicy {
+        DeploymentBranchPolicy {
+            protected_branches: true,
+            custom_branch_policies: false,
+        }
+    }
+
+    /// Only refs matching the environment's branch policies can deploy.
+    pub fn custom() -> DeploymentBranchPolicy {
+        DeploymentBranchPolicy {
+            protected_branches: false,
+            custom_branch_policies: true,
+        }
+    }
+
+    pub fn is_protected(&self) -> bool {
+        self.protected_branches
+    }
+
+    pub fn is_custom(&self) -> bool {
+        self.custom_branch_policies
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum BranchPolicyKind {
+    #[serde(rename = "branch")] Branch,
+    #[serde(rename = "tag")] Tag,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct BranchPolicy {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    pub(crate) name: String,
+    #[serde(rename = "type")]
+    #[serde(default = "BranchPolicy::default_kind")]
+    pub(crate) kind: BranchPolicyKind,
+}
+
+impl BranchPolicy {
+    fn default_kind() -> BranchPolicyKind {
+        BranchPolicyKind::Branch
+    }
+
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    /// Name pattern of the policy, for example `release/*`.
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_kind(&self) -> BranchPolicyKind {
+        self.kind.clone()
+    }
+}
+
+impl FmtDisplay for BranchPolicy {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{name}", name = self.name)
+    }
+}
+
+impl From<BranchPolicy> for Number {
+    fn from(policy: BranchPolicy) -> Number {
+        policy.number
+    }
+}
+
+/// Someone allowed to approve deployments to an environment.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum EnvironmentReviewer {
+    User {
+        #[serde(rename = "id")]
+        number: Number,
+    },
+    Team {
+        #[serde(rename = "id")]
+        number: Number,
+    },
+}
+
+impl EnvironmentReviewer {
+    pub fn get_number(&self) -> Number {
+        match self {
+            EnvironmentReviewer::User { number } |
+            EnvironmentReviewer::Team { number } => *number,
+        }
+    }
+}
+
+impl From<&User> for EnvironmentReviewer {
+    fn from(user: &User) -> EnvironmentReviewer {
+        EnvironmentReviewer::User { number: user.get_number() }
+    }
+}
+
+impl From<&Team> for EnvironmentReviewer {
+    fn from(team: &Team) -> EnvironmentReviewer {
+        EnvironmentReviewer::Team { number: team.get_number() }
+    }
+}
+
+/// A user or team that can review deployments, as reported by GitHub.
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", content = "reviewer")]
+pub enum DeploymentReviewer {
+    User(User),
+    Team(Team),
+}
+
+impl DeploymentReviewer {
+    pub fn get_name(&self) -> String {
+        match self {
+            DeploymentReviewer::User(user) => user.get_name(),
+            DeploymentReviewer::Team(team) => team.get_slug(),
+        }
+    }
+
+    pub fn is_user(&self) -> bool {
+        matches!(self, DeploymentReviewer::User(_))
+    }
+
+    pub fn is_team(&self) -> bool {
+        matches!(self, DeploymentReviewer::Team(_))
+    }
+}
+
+impl From<&DeploymentReviewer> for EnvironmentReviewer {
+    fn from(reviewer: &DeploymentReviewer) -> EnvironmentReviewer {
+        match reviewer {
+            DeploymentReviewer::User(user) => user.into(),
+            DeploymentReviewer::Team(team) => team.into(),
+        }
+    }
+}
+
+impl FmtDisplay for DeploymentReviewer {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{name}", name = self.get_name())
+    }
+}
+
+/// A rule deployments to an environment have to pass, custom deployment
+/// protection rules of GitHub Apps are reported as `Other`.
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ProtectionRule {
+    #[serde(rename = "required_reviewers")]
+    RequiredReviewers {
+        #[serde(rename = "id")]
+        number: Number,
+        #[serde(default)]
+        prevent_self_review: bool,
+        #[serde(default)]
+        reviewers: Vec<DeploymentReviewer>,
+    },
+    #[serde(rename = "wait_timer")]
+    WaitTimer {
+        #[serde(rename = "id")]
+        number: Number,
+        wait_timer: u32,
+    },
+    #[serde(rename = "branch_policy")]
+    BranchPolicy {
+        #[serde(rename = "id")]
+        number: Number,
+    },
+    #[serde(other)]
+    Other,
+}
+
+impl ProtectionRule {
+    pub fn is_required_reviewers(&self) -> bool {
+        matches!(self, ProtectionRule::RequiredReviewers { .. })
+    }
+
+    pub fn is_wait_timer(&self) -> bool {
+        matches!(self, ProtectionRule::WaitTimer { .. })
+    }
+
+    pub fn is_branch_policy(&self) -> bool {
+        matches!(self, ProtectionRule::BranchPolicy { .. })
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct Environment {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) protection_rules: Vec<ProtectionRule>,
+    #[serde(default)]
+    pub(crate) deployment_branch_policy: Option<DeploymentBranchPolicy>,
+    #[serde(rename = "created_at")]
+    pub(crate) date_created: Date,
+    #[serde(rename = "updated_at")]
+    pub(crate) date_updated: Date,
+}
+
+impl Environment {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_protection_rules(&self) -> Vec<ProtectionRule> {
+        self.protection_rules.clone()
+    }
+
+    /// Reviewers required by the environment, empty when no review is needed.
+    pub fn get_reviewers(&self) -> Vec<DeploymentReviewer> {
+        self.protection_rules.iter().flat_map(|rule| match rule {
+            ProtectionRule::RequiredReviewers { reviewers, .. } => reviewers.clone(),
+            _ => Vec::new(),
+        }).collect()
+    }
+
+    /// Minutes jobs wait before they may start, zero without a wait timer.
+    pub fn get_wait_timer(&self) -> u32 {
+        self.protection_rules.iter().find_map(|rule| match rule {
+            ProtectionRule::WaitTimer { wait_timer, .. } => Some(*wait_timer),
+            _ => None,
+        }).unwrap_or_default()
+    }
+
+    /// Which refs can deploy, `None` when every ref is allowed.
+    pub fn get_deployment_branch_policy(&self) -> Option<DeploymentBranchPolicy> {
+        self.deployment_branch_policy.clone()
+    }
+
+    pub fn get_date_created(&self) -> Date {
+        self.date_created
+    }
+
+    pub fn get_date_updated(&self) -> Date {
+        self.date_updated
+    }
+}
+
+impl From<Environment> for Number {
+    fn from(environment: Environment) -> Number {
+        environment.number
+    }
+}
+
+impl FmtDisplay for Environment {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{name}", name = self.name)
+    }
+}
+
+/// Protection settings of an environment, creating or updating an environment
+/// replaces all of them so unset values fall back to GitHub's defaults.
+#[derive(Clone, Debug, Default)]
+#[derive(Serialize)]
+pub struct EnvironmentContent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) wait_timer: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) prevent_self_review: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) reviewers: Option<Vec<EnvironmentReviewer>>,
+    pub(crate) deployment_branch_policy: Option<DeploymentBranchPolicy>,
+}
+
+impl EnvironmentContent {
+    pub fn new() -> EnvironmentContent {
+        Default::default()
+    }
+
+    /// Minutes to wait before jobs referencing the environment may start, at most 43200.
+    pub fn with_wait_timer(mut self, minutes: u32) -> Self {
+        self.wait_timer = Some(minutes);
+        self
+    }
+
+    pub fn with_prevent_self_review(mut self, prevent: bool) -> Self {
+        self.prevent_self_review = Some(prevent);
+        self
+    }
+
+    /// Up to six users or teams, one of which has to approve each deployment.
+    pub fn with_reviewers(mut self, reviewers: impl AsRef<[EnvironmentReviewer]>) -> Self {
+        self.reviewers = Some(reviewers.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_reviewer(mut self, reviewer: impl Into<EnvironmentReviewer>) -> Self {
+        self.reviewers.get_or_insert_with(Vec::new)
+            .push(reviewer.into());
+
+        self
+    }
+
+    pub fn with_deployment_branch_policy(mut self, policy: DeploymentBranchPolicy) -> Self {
+        self.deployment_branch_policy = Some(policy);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{
+
+        DeploymentBranchPolicy,
+        EnvironmentReviewer,
+        EnvironmentContent,
+        ProtectionRule,
+        Environment,
+    };
+
+    #[test]
+    fn test_serialize_content() {
+        let content = EnvironmentContent::new()
+            .with_wait_timer(30)
+            .with_reviewer(EnvironmentReviewer::Team { number: 42 })
+            .with_deployment_branch_policy(DeploymentBranchPolicy::custom());
+
+        let value = serde_json::to_value(&content)
+            .unwrap();
+
+        assert_eq!(value, serde_json::json!({
+            "wait_timer": 30,
+            "reviewers": [{ "type": "Team", "id": 42 }],
+            "deployment_branch_policy": {
+                "protected_branches": false,
+                "custom_branch_policies": true,
+            },
+        }));
+
+        let value = serde_json::to_value(EnvironmentContent::new())
+            .unwrap();
+
+        assert_eq!(value, serde_json::json!({ "deployment_branch_policy": null }));
+    }
+
+    #[test]
+    fn test_deserialize_protection_rules() {
+        let environment: Environment = serde_json::from_value(serde_json::json!({
+            "id": 161088068,
+            "name": "production",
+            "created_at": "2020-11-23T22:00:40Z",
+            "updated_at": "2020-11-23T22:00:40Z",
+            "protection_rules": [
+                { "id": 3736, "type": "wait_timer", "wait_timer": 30 },
+                {
+                    "id": 3755,
+                    "type": "required_reviewers",
+                    "prevent_self_review": true,
+                    "reviewers": [
+                        { "type": "User", "reviewer": { "login": "octocat", "id": 1, "type": "User" } },
+                        { "type": "Team", "reviewer": { "name": "Justice League", "id": 1, "slug": "justice-league", "description": null } },
+                    ],
+                },
+                { "id": 3756, "type": "branch_policy" },
+                { "id": 3757, "type": "custom_rule" },
+            ],
+        })).unwrap();
+
+        assert_eq!(environment.get_wait_timer(), 30);
+
+        let reviewers: Vec<String> = environment.get_reviewers().iter()
+            .map(|reviewer| reviewer.get_name())
+            .collect();
+
+        assert_eq!(reviewers, ["octocat", "justice-league"]);
+
+        let rules = environment.get_protection_rules();
+
+        assert!(rules[2].is_branch_policy());
+        assert!(matches!(rules[3], ProtectionRule::Other));
+    }
+}
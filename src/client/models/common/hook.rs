@@ -0,0 +1,337 @@
+use std::collections::{BTreeMap};
+
+use serde::{
+
+    Deserializer,
+    Deserialize,
+    Serializer,
+    Serialize,
+};
+
+use serde_json::{Value};
+
+use crate::client::{
+
+    common::{Date},
+    Number,
+};
+
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum HookContentType {
+    #[serde(rename = "json")] Json,
+    /// The payload is sent as the `payload` field of a form.
+    #[serde(rename = "form")] #[default] Form,
+}
+
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct HookConfig {
+    pub(crate) url: String,
+    #[serde(default)]
+    pub(crate) content_type: HookContentType,
+    /// Only a mask of the secret is ever returned, so it is not read back.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_deserializing)]
+    pub(crate) secret: Option<String>,
+    #[serde(serialize_with = "serialize_insecure_ssl")]
+    #[serde(deserialize_with = "deserialize_insecure_ssl")]
+    #[serde(default)]
+    pub(crate) insecure_ssl: bool,
+}
+
+fn serialize_insecure_ssl<S>(insecure_ssl: &bool, serializer: S) -> Result<S::Ok, S::Error>
+where S: Serializer {
+    serializer.serialize_str(if *insecure_ssl { "1" } else { "0" })
+}
+
+/// The flag is given either as a string or as a number.
+fn deserialize_insecure_ssl<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where D: Deserializer<'de> {
+    match Value::deserialize(deserializer)? {
+        Value::String(value) => Ok(value == "1"),
+        Value::Number(value) => Ok(value.as_u64() == Some(1)),
+        Value::Bool(value) => Ok(value),
+        _ => Ok(false),
+    }
+}
+
+/// A webhook of a repository or an organization.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct Hook {
+    #[serde(rename = "id")]
+    #[serde(skip_serializing)]
+    #[serde(default)]
+    pub(crate) number: Number,
+    #[serde(default = "Hook::default_name")]
+    pub(crate) name: String,
+    pub(crate) active: bool,
+    pub(crate) events: Vec<String>,
+    pub(crate) config: HookConfig,
+    #[serde(rename = "created_at")]
+    #[serde(skip_serializing)]
+    #[serde(default)]
+    pub(crate) date_created: Option<Date>,
+    #[serde(rename = "updated_at")]
+    #[serde(skip_serializing)]
+    #[serde(default)]
+    pub(crate) date_updated: Option<Date>,
+}
+
+impl Hook {
+    fn default_name() -> String {
+        "web".to_owned()
+    }
+
+    /// Creates an active hook for `push` events, sent as a form.
+    pub fn new(url: impl AsRef<str>) -> Hook {
+        Hook {
+            number: Default::default(),
+            name: Hook::default_name(),
+            active: true,
+            events: vec!["push".to_owned()],
+            config: HookConfig {
+                url: url.as_ref().to_owned(),
+                ..Default::default()
+            },
+            date_created: None,
+            date_updated: None,
+        }
+    }
+
+    pub fn with_content_type(mut self, content_type: HookContentType) -> Self {
+        self.config.content_type = content_type;
+        self
+    }
+
+    pub fn with_secret(mut self, secret: impl AsRef<str>) -> Self {
+        self.config.secret = Some(secret.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_insecure_ssl(mut self, insecure_ssl: bool) -> Self {
+        self.config.insecure_ssl = insecure_ssl;
+        self
+    }
+
+    /// Replaces the events, `*` subscribes to all of them.
+    pub fn with_events<T>(mut self, events: impl IntoIterator<Item = T>) -> Self
+    where T: AsRef<str> {
+        self.events = events.into_iter()
+            .map(|event| event.as_ref().to_owned())
+            .collect();
+        self
+    }
+
+    pub fn with_active(mut self, active: bool) -> Self {
+        self.active = active;
+        self
+    }
+
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_url(&self) -> String {
+        self.config.url.clone()
+    }
+
+    pub fn set_url(&mut self, url: impl AsRef<str>) {
+        self.config.url = url.as_ref().to_owned();
+    }
+
+    pub fn get_content_type(&self) -> HookContentType {
+        self.config.content_type
+    }
+
+    pub fn set_content_type(&mut self, content_type: HookContentType) {
+        self.config.content_type = content_type;
+    }
+
+    /// Sets the secret, hooks read from GitHub carry none and keep theirs when updated.
+    pub fn set_secret(&mut self, secret: Option<&str>) {
+        self.config.secret = secret.map(|secret| secret.to_owned());
+    }
+
+    pub fn is_insecure_ssl(&self) -> bool {
+        self.config.insecure_ssl
+    }
+
+    pub fn set_insecure_ssl(&mut self, insecure_ssl: bool) {
+        self.config.insecure_ssl = insecure_ssl;
+    }
+
+    pub fn get_events(&self) -> Vec<String> {
+        self.events.clone()
+    }
+
+    pub fn set_events<T>(&mut self, events: impl IntoIterator<Item = T>)
+    where T: AsRef<str> {
+        self.events = events.into_iter()
+            .map(|event| event.as_ref().to_owned())
+            .collect();
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+    }
+
+    pub fn get_date_created(&self) -> Option<Date> {
+        self.date_created
+    }
+
+    pub fn get_date_updated(&self) -> Option<Date> {
+        self.date_updated
+    }
+}
+
+/// Headers and payload of a delivery request or of its response.
+#[derive(Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize)]
+pub struct HookMessage {
+    #[serde(default)]
+    pub(crate) headers: BTreeMap<String, String>,
+    #[serde(default)]
+    pub(crate) payload: Value,
+}
+
+impl HookMessage {
+    pub fn get_headers(&self) -> &BTreeMap<String, String> {
+        &(self.headers)
+    }
+
+    pub fn get_payload(&self) -> &Value {
+        &(self.payload)
+    }
+}
+
+/// An attempt to deliver an event to a hook, the request and the response
+/// are only given when getting a single delivery.
+#[derive(Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize)]
+pub struct HookDelivery {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    /// Shared by redeliveries, sent as `X-GitHub-Delivery`.
+    pub(crate) guid: String,
+    #[serde(rename = "delivered_at")]
+    pub(crate) date_delivered: Date,
+    #[serde(default)]
+    pub(crate) redelivery: bool,
+    /// Seconds the delivery took.
+    #[serde(default)]
+    pub(crate) duration: f64,
+    pub(crate) status: String,
+    pub(crate) status_code: u16,
+    pub(crate) event: String,
+    #[serde(default)]
+    pub(crate) action: Option<String>,
+    #[serde(default)]
+    pub(crate) request: Option<HookMessage>,
+    #[serde(default)]
+    pub(crate) response: Option<HookMessage>,
+}
+
+impl HookDelivery {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_guid(&self) -> String {
+        self.guid.clone()
+    }
+
+    pub fn get_date_delivered(&self) -> Date {
+        self.date_delivered
+    }
+
+    pub fn is_redelivery(&self) -> bool {
+        self.redelivery
+    }
+
+    pub fn get_duration(&self) -> f64 {
+        self.duration
+    }
+
+    pub fn get_status(&self) -> String {
+        self.status.clone()
+    }
+
+    pub fn get_status_code(&self) -> u16 {
+        self.status_code
+    }
+
+    /// Whether the hook answered with a 2xx status.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&(self.status_code))
+    }
+
+    pub fn get_event(&self) -> String {
+        self.event.clone()
+    }
+
+    pub fn get_action(&self) -> Option<String> {
+        self.action.clone()
+    }
+
+    pub fn get_request(&self) -> Option<&HookMessage> {
+        self.request.as_ref()
+    }
+
+    pub fn get_response(&self) -> Option<&HookMessage> {
+        self.response.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{
+
+        HookContentType,
+        Hook,
+    };
+
+    #[test]
+    fn test_serialize() {
+        let hook = Hook::new("https://example.com/webhook")
+            .with_content_type(HookContentType::Json)
+            .with_secret("secret")
+            .with_events(["issues", "issue_comment"]);
+
+        assert_eq!(serde_json::to_value(&hook).unwrap(), serde_json::json!({
+            "name": "web",
+            "active": true,
+            "events": ["issues", "issue_comment"],
+            "config": {
+                "url": "https://example.com/webhook",
+                "content_type": "json",
+                "secret": "secret",
+                "insecure_ssl": "0",
+            },
+        }));
+
+        let hook: Hook = serde_json::from_str(r#"{
+            "id": 12345678,
+            "name": "web",
+            "active": true,
+            "events": ["push"],
+            "config": {
+                "url": "https://example.com/webhook",
+                "content_type": "json",
+                "secret": "********",
+                "insecure_ssl": 1
+            },
+            "created_at": "2019-06-03T00:57:16Z",
+            "updated_at": "2019-06-03T00:57:16Z"
+        }"#).unwrap();
+
+        assert!(hook.is_insecure_ssl());
+        assert_eq!(hook.config.secret, None);
+    }
+}
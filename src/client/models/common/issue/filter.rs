@@ -0,0 +1,222 @@
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use crate::client::{
+
+    common::{Date},
+    Number,
+};
+
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum IssueStateFilter {
+    #[serde(rename = "open")] #[default] Open,
+    #[serde(rename = "closed")] Closed,
+    #[serde(rename = "all")] All,
+}
+
+impl IssueStateFilter {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            IssueStateFilter::Open => "open",
+            IssueStateFilter::Closed => "closed",
+            IssueStateFilter::All => "all",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum IssueSort {
+    #[serde(rename = "created")] #[default] Created,
+    #[serde(rename = "updated")] Updated,
+    #[serde(rename = "comments")] Comments,
+}
+
+impl IssueSort {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            IssueSort::Created => "created",
+            IssueSort::Updated => "updated",
+            IssueSort::Comments => "comments",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum SortDirection {
+    #[serde(rename = "asc")] Ascending,
+    #[serde(rename = "desc")] #[default] Descending,
+}
+
+impl SortDirection {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "asc",
+            SortDirection::Descending => "desc",
+        }
+    }
+}
+
+/// Which issues of a repository to list, unset fields are left to GitHub's defaults.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IssueFilter {
+    pub(crate) state: Option<IssueStateFilter>,
+    pub(crate) labels: Vec<String>,
+    pub(crate) assignee: Option<String>,
+    pub(crate) creator: Option<String>,
+    pub(crate) mentioned: Option<String>,
+    pub(crate) milestone: Option<String>,
+    pub(crate) since: Option<Date>,
+    pub(crate) sort: Option<IssueSort>,
+    pub(crate) direction: Option<SortDirection>,
+}
+
+impl IssueFilter {
+    pub fn new() -> IssueFilter {
+        IssueFilter::default()
+    }
+
+    pub fn with_state(mut self, state: IssueStateFilter) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Only issues having all of the labels.
+    pub fn with_label(mut self, label: impl AsRef<str>) -> Self {
+        self.labels.push(label.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_assignee(mut self, assignee: impl AsRef<str>) -> Self {
+        self.assignee = Some(assignee.as_ref().to_owned());
+        self
+    }
+
+    pub fn without_assignee(mut self) -> Self {
+        self.assignee = Some("none".to_owned());
+        self
+    }
+
+    pub fn with_any_assignee(mut self) -> Self {
+        self.assignee = Some("*".to_owned());
+        self
+    }
+
+    pub fn with_creator(mut self, creator: impl AsRef<str>) -> Self {
+        self.creator = Some(creator.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_mentioned(mut self, mentioned: impl AsRef<str>) -> Self {
+        self.mentioned = Some(mentioned.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_milestone(mut self, milestone: impl Into<Number>) -> Self {
+        self.milestone = Some(milestone.into().to_string());
+        self
+    }
+
+    pub fn without_milestone(mut self) -> Self {
+        self.milestone = Some("none".to_owned());
+        self
+    }
+
+    pub fn with_any_milestone(mut self) -> Self {
+        self.milestone = Some("*".to_owned());
+        self
+    }
+
+    /// Only issues updated at or after the given time.
+    pub fn with_since(mut self, since: Date) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn with_sort(mut self, sort: IssueSort, direction: SortDirection) -> Self {
+        self.sort = Some(sort);
+        self.direction = Some(direction);
+        self
+    }
+
+    pub(crate) fn get_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+
+        if let Some(state) = self.state {
+            query.push(("state", state.as_str().to_owned()));
+        }
+
+        if !(self.labels.is_empty()) {
+            query.push(("labels", self.labels.join(",")));
+        }
+
+        if let Some(ref assignee) = self.assignee {
+            query.push(("assignee", assignee.clone()));
+        }
+
+        if let Some(ref creator) = self.creator {
+            query.push(("creator", creator.clone()));
+        }
+
+        if let Some(ref mentioned) = self.mentioned {
+            query.push(("mentioned", mentioned.clone()));
+        }
+
+        if let Some(ref milestone) = self.milestone {
+            query.push(("milestone", milestone.clone()));
+        }
+
+        if let Some(since) = self.since {
+            query.push(("since", since.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)));
+        }
+
+        if let Some(sort) = self.sort {
+            query.push(("sort", sort.as_str().to_owned()));
+        }
+
+        if let Some(direction) = self.direction {
+            query.push(("direction", direction.as_str().to_owned()));
+        }
+
+        query
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use chrono::{TimeZone, Utc};
+
+    use super::{
+
+        IssueStateFilter,
+        SortDirection,
+        IssueFilter,
+        IssueSort,
+    };
+
+    #[test]
+    fn test_query() {
+        let filter = IssueFilter::new()
+            .with_state(IssueStateFilter::All)
+            .with_label("bug")
+            .with_label("help wanted")
+            .without_milestone()
+            .with_since(Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap())
+            .with_sort(IssueSort::Updated, SortDirection::Ascending);
+
+        assert_eq!(filter.get_query(), vec![
+            ("state", "all".to_owned()),
+            ("labels", "bug,help wanted".to_owned()),
+            ("milestone", "none".to_owned()),
+            ("since", "2024-01-02T03:04:05Z".to_owned()),
+            ("sort", "updated".to_owned()),
+            ("direction", "asc".to_owned()),
+        ]);
+    }
+}
@@ -0,0 +1,523 @@
+use std::{
+
+    collections::{
+
+        BTreeMap,
+        BTreeSet,
+    },
+    path::{Path},
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use thiserror::{Error};
+
+/// Placeholder GitHub renders for optional fields left empty.
+const NO_RESPONSE: &str = "_No response_";
+
+#[derive(Error, Debug)]
+pub enum FormError {
+    #[error("Failed to read issue form!")]
+    File(#[from] std::io::Error),
+    #[error("Failed to parse issue form!")]
+    Template(#[from] serde_yaml::Error),
+    #[error("Issue does not match its form: {}", violations.iter().map(|violation| violation.to_string()).collect::<Vec<_>>().join(", "))]
+    Invalid { violations: Vec<FormViolation> },
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum FormElementKind {
+    #[serde(rename = "markdown")] Markdown,
+    #[serde(rename = "input")] Input,
+    #[serde(rename = "textarea")] Textarea,
+    #[serde(rename = "dropdown")] Dropdown,
+    #[serde(rename = "checkboxes")] Checkboxes,
+}
+
+/// Dropdown options are plain labels, checkbox options can be required on their own.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FormOption {
+    Label(String),
+    Checkbox {
+        label: String,
+        #[serde(default)]
+        required: bool,
+    },
+}
+
+impl FormOption {
+    pub fn get_label(&self) -> String {
+        match self {
+            FormOption::Label(label) |
+            FormOption::Checkbox { label, .. } => label.clone(),
+        }
+    }
+
+    pub fn is_required(&self) -> bool {
+        matches!(self, FormOption::Checkbox { required: true, .. })
+    }
+}
+
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct FormAttributes {
+    #[serde(default)]
+    pub(crate) label: String,
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+    #[serde(default)]
+    pub(crate) options: Vec<FormOption>,
+    #[serde(default)]
+    pub(crate) multiple: bool,
+    #[serde(default)]
+    pub(crate) render: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct FormValidations {
+    #[serde(default)]
+    pub(crate) required: bool,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct FormElement {
+    #[serde(rename = "type")]
+    pub(crate) kind: FormElementKind,
+    #[serde(default)]
+    pub(crate) id: Option<String>,
+    #[serde(default)]
+    pub(crate) attributes: FormAttributes,
+    #[serde(default)]
+    pub(crate) validations: FormValidations,
+}
+
+impl FormElement {
+    pub fn get_kind(&self) -> FormElementKind {
+        self.kind
+    }
+
+    /// The id of the element, or its label when it has none.
+    pub fn get_key(&self) -> String {
+        self.id.clone()
+            .unwrap_or_else(|| self.attributes.label.clone())
+    }
+
+    pub fn get_label(&self) -> String {
+        self.attributes.label.clone()
+    }
+
+    pub fn get_options(&self) -> &[FormOption] {
+        self.attributes.options.as_slice()
+    }
+
+    pub fn is_required(&self) -> bool {
+        self.validations.required
+    }
+}
+
+/// An issue form template from `.github/ISSUE_TEMPLATE`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct IssueForm {
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+    #[serde(default)]
+    pub(crate) title: Option<String>,
+    #[serde(default)]
+    pub(crate) body: Vec<FormElement>,
+}
+
+impl IssueForm {
+    pub fn try_read(path: impl AsRef<Path>) -> Result<IssueForm, FormError> {
+        let content = std::fs::read_to_string(path)?;
+        IssueForm::try_from_str(content)
+    }
+
+    pub fn try_from_str(content: impl AsRef<str>) -> Result<IssueForm, FormError> {
+        Ok(serde_yaml::from_str(content.as_ref())?)
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_elements(&self) -> &[FormElement] {
+        self.body.as_slice()
+    }
+
+    /// Reads the field values from an issue body created with this form,
+    /// sections without a matching element are ignored.
+    pub fn parse(&self, body: impl AsRef<str>) -> FormResponse {
+        let labels = self.body.iter()
+            .map(|element| element.attributes.label.trim())
+            .filter(|label| !(label.is_empty()))
+            .collect();
+
+        let sections = split_sections(body.as_ref(), labels);
+        let mut values = BTreeMap::new();
+
+        for element in self.body.iter() {
+            let label = element.attributes.label.trim();
+
+            let Some(content) = sections.get(label) else {
+                continue
+            };
+
+            let value = match element.kind {
+                FormElementKind::Markdown => continue,
+                FormElementKind::Input => parse_text(content, None),
+                FormElementKind::Textarea => parse_text(content, element.attributes.render.as_deref()),
+                FormElementKind::Dropdown => parse_dropdown(content, {
+                    element.attributes.options.iter()
+                        .map(|option| option.get_label())
+                        .collect::<Vec<_>>()
+                        .as_slice()
+                }),
+                FormElementKind::Checkboxes => parse_checkboxes(content),
+            };
+
+            if let Some(value) = value {
+                values.insert(element.get_key(), value);
+            }
+        }
+
+        FormResponse { values }
+    }
+
+    /// Checks required fields and that dropdown values are among their options.
+    pub fn validate(&self, response: &FormResponse) -> Vec<FormViolation> {
+        let mut violations = Vec::new();
+
+        for element in self.body.iter() {
+            let key = element.get_key();
+            let value = response.values.get(key.as_str());
+
+            match (element.kind, value) {
+                (FormElementKind::Markdown, _) => {},
+                (FormElementKind::Checkboxes, value) => {
+                    for option in element.attributes.options.iter().filter(|option| option.is_required()) {
+                        let label = option.get_label();
+
+                        let checked = match value {
+                            Some(FormValue::Checkboxes(options)) => options.get(label.as_str()).copied().unwrap_or_default(),
+                            _ => false,
+                        };
+
+                        if !(checked) {
+                            violations.push(FormViolation::Unchecked { key: key.clone(), option: label });
+                        }
+                    }
+                },
+                (_, None) if element.is_required() => {
+                    violations.push(FormViolation::Missing { key });
+                },
+                (FormElementKind::Dropdown, Some(FormValue::Dropdown(selected))) => {
+                    for choice in selected {
+                        let known = element.attributes.options.iter()
+                            .any(|option| option.get_label().eq(choice));
+
+                        if !(known) {
+                            violations.push(FormViolation::Unknown { key: key.clone(), option: choice.clone() });
+                        }
+                    }
+                },
+                _ => {},
+            }
+        }
+
+        violations
+    }
+
+    /// Parses and validates an issue body in one go.
+    pub fn try_parse(&self, body: impl AsRef<str>) -> Result<FormResponse, FormError> {
+        let response = self.parse(body);
+        let violations = self.validate(&response);
+
+        match violations.is_empty() {
+            true => Ok(response),
+            _ => Err(FormError::Invalid { violations }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum FormValue {
+    Text(String),
+    Dropdown(Vec<String>),
+    /// Every option of the element and whether it was checked.
+    Checkboxes(BTreeMap<String, bool>),
+}
+
+/// Field values of an issue keyed by element id, fields left empty are absent.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct FormResponse {
+    pub(crate) values: BTreeMap<String, FormValue>,
+}
+
+impl FormResponse {
+    pub fn get(&self, key: impl AsRef<str>) -> Option<&FormValue> {
+        self.values.get(key.as_ref())
+    }
+
+    pub fn get_text(&self, key: impl AsRef<str>) -> Option<String> {
+        match self.get(key) {
+            Some(FormValue::Text(text)) => Some(text.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn get_selected(&self, key: impl AsRef<str>) -> Vec<String> {
+        match self.get(key) {
+            Some(FormValue::Dropdown(selected)) => selected.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn is_checked(&self, key: impl AsRef<str>, option: impl AsRef<str>) -> bool {
+        match self.get(key) {
+            Some(FormValue::Checkboxes(options)) => options.get(option.as_ref()).copied().unwrap_or_default(),
+            _ => false,
+        }
+    }
+
+    pub fn get_values(&self) -> &BTreeMap<String, FormValue> {
+        &(self.values)
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum FormViolation {
+    Missing { key: String },
+    Unchecked { key: String, option: String },
+    Unknown { key: String, option: String },
+}
+
+impl FmtDisplay for FormViolation {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        match self {
+            FormViolation::Missing { key } => write!(fmt, "'{key}' is required"),
+            FormViolation::Unchecked { key, option } => write!(fmt, "'{option}' of '{key}' must be checked"),
+            FormViolation::Unknown { key, option } => write!(fmt, "'{option}' is not an option of '{key}'"),
+        }
+    }
+}
+
+/// Splits a body into its `### Label` sections. Only the first heading of
+/// each label starts a section, other headings belong to the answers.
+fn split_sections(body: &str, mut labels: BTreeSet<&str>) -> BTreeMap<String, String> {
+    let mut sections = BTreeMap::new();
+    let mut current: Option<(String, Vec<&str>)> = None;
+
+    for line in body.lines() {
+        let label = line.strip_prefix("### ")
+            .map(|label| label.trim())
+            .filter(|label| labels.remove(*label));
+
+        if let Some(label) = label {
+            if let Some((label, lines)) = current.take() {
+                sections.insert(label, lines.join("\n"));
+            }
+
+            current = Some((label.to_owned(), Vec::new()));
+            continue
+        }
+
+        if let Some((_, ref mut lines)) = current {
+            lines.push(line);
+        }
+    }
+
+    if let Some((label, lines)) = current {
+        sections.insert(label, lines.join("\n"));
+    }
+
+    sections
+}
+
+fn parse_text(content: &str, render: Option<&str>) -> Option<FormValue> {
+    let mut content = content.trim();
+
+    if content.is_empty() || content == NO_RESPONSE {
+        return None
+    }
+
+    // Textareas with `render` are wrapped in a code block of that language.
+    if let Some(render) = render {
+        let fenced = content.strip_prefix("```")
+            .and_then(|content| content.strip_prefix(render))
+            .and_then(|content| content.strip_suffix("```"));
+
+        if let Some(fenced) = fenced {
+            content = fenced.trim_matches('\n');
+        }
+    }
+
+    Some(FormValue::Text(content.to_owned()))
+}
+
+/// Reads the selected choices, which are joined with `, `. Labels of the options
+/// may contain that separator too, so known ones are matched first.
+fn parse_dropdown(content: &str, options: &[String]) -> Option<FormValue> {
+    let content = content.trim();
+
+    if content.is_empty() || content == NO_RESPONSE {
+        return None
+    }
+
+    let mut choices = Vec::new();
+    let mut rest = content;
+
+    while !(rest.is_empty()) {
+        let known = options.iter()
+            .filter(|option| {
+                rest.strip_prefix(option.as_str())
+                    .is_some_and(|tail| tail.is_empty() || tail.starts_with(", "))
+            })
+            .max_by_key(|option| option.len());
+
+        let (choice, tail) = match known {
+            Some(option) => rest.split_at(option.len()),
+            None => rest.find(", ")
+                .map(|index| rest.split_at(index))
+                .unwrap_or((rest, "")),
+        };
+
+        choices.push(choice.trim().to_owned());
+        rest = tail.strip_prefix(", ").unwrap_or(tail);
+    }
+
+    Some(FormValue::Dropdown(choices))
+}
+
+fn parse_checkboxes(content: &str) -> Option<FormValue> {
+    let options: BTreeMap<String, bool> = content.lines()
+        .filter_map(|line| {
+            let line = line.trim().strip_prefix("- [")?;
+
+            let (checked, label) = line.split_once("] ")?;
+            Some((label.trim().to_owned(), checked.eq_ignore_ascii_case("x")))
+        })
+        .collect();
+
+    match options.is_empty() {
+        true => None,
+        _ => Some(FormValue::Checkboxes(options)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{
+
+        FormViolation,
+        IssueForm,
+        FormValue,
+    };
+
+    const FORM: &str = r#"
+name: Bug report
+description: File a bug report
+body:
+  - type: markdown
+    attributes:
+      value: Thanks for taking the time!
+  - type: input
+    id: version
+    attributes:
+      label: Version
+    validations:
+      required: true
+  - type: dropdown
+    id: browsers
+    attributes:
+      label: Browsers
+      multiple: true
+      options: [Firefox, Chrome, Safari, "Edge, legacy"]
+  - type: textarea
+    id: logs
+    attributes:
+      label: Relevant log output
+      render: shell
+  - type: textarea
+    id: context
+    attributes:
+      label: Additional context
+  - type: checkboxes
+    id: terms
+    attributes:
+      label: Code of Conduct
+      options:
+        - label: I agree to follow the Code of Conduct
+          required: true
+        - label: I searched existing issues
+"#;
+
+    #[test]
+    fn test_parse() {
+        let form = IssueForm::try_from_str(FORM).unwrap();
+
+        let body = concat!(
+            "### Version\n\n1.2.3\n\n",
+            "### Browsers\n\nFirefox, Edge, legacy, Chrome\n\n",
+            "### Relevant log output\n\n```shell\npanicked at 'oops'\n```\n\n",
+            "### Additional context\n\n_No response_\n\n",
+            "### Code of Conduct\n\n- [X] I agree to follow the Code of Conduct\n- [ ] I searched existing issues\n",
+        );
+
+        let response = form.try_parse(body).unwrap();
+
+        assert_eq!(response.get_text("version"), Some("1.2.3".to_owned()));
+        assert_eq!(response.get_selected("browsers"), ["Firefox", "Edge, legacy", "Chrome"]);
+        assert!(form.validate(&response).is_empty());
+        assert_eq!(response.get_text("logs"), Some("panicked at 'oops'".to_owned()));
+        assert_eq!(response.get("context"), None);
+        assert!(response.is_checked("terms", "I agree to follow the Code of Conduct"));
+        assert!(!response.is_checked("terms", "I searched existing issues"));
+
+        let body = concat!(
+            "### Version\n\n1.2.3\n\n",
+            "### Additional context\n\nSeen after upgrading.\n\n### Steps\n\n1. Run it\n\n",
+            "### Code of Conduct\n\n- [X] I agree to follow the Code of Conduct\n",
+        );
+
+        let response = form.try_parse(body).unwrap();
+
+        assert_eq!(response.get_text("context"), Some("Seen after upgrading.\n\n### Steps\n\n1. Run it".to_owned()));
+        assert!(response.is_checked("terms", "I agree to follow the Code of Conduct"));
+
+        let body = concat!(
+            "### Version\n\n_No response_\n\n",
+            "### Browsers\n\nLynx\n\n",
+            "### Code of Conduct\n\n- [ ] I agree to follow the Code of Conduct\n",
+        );
+
+        let response = form.parse(body);
+
+        assert_eq!(response.get("browsers"), Some(&FormValue::Dropdown(vec!["Lynx".to_owned()])));
+        assert_eq!(form.validate(&response), [
+            FormViolation::Missing { key: "version".to_owned() },
+            FormViolation::Unknown { key: "browsers".to_owned(), option: "Lynx".to_owned() },
+            FormViolation::Unchecked { key: "terms".to_owned(), option: "I agree to follow the Code of Conduct".to_owned() },
+        ]);
+    }
+}
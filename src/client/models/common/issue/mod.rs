@@ -24,9 +24,26 @@ use serde::{
 
 use crate::client::{Number};
 
-use super::user::{User};
+use self::{
+
+    form::{
+
+        FormResponse,
+        IssueForm,
+    },
+};
+
+use super::{
+
+    milestone::{Milestone},
+    label::{Label},
+    user::{User},
+};
 
 pub mod comment;
+pub mod filter;
+pub mod form;
+pub mod timeline;
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 #[derive(Serialize, Deserialize)]
@@ -51,6 +68,27 @@ impl IssueState {
     }
 }
 
+/// Why an issue was closed or reopened.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum IssueStateReason {
+    #[serde(rename = "completed")] Completed,
+    #[serde(rename = "not_planned")] NotPlanned,
+    #[serde(rename = "duplicate")] Duplicate,
+    #[serde(rename = "reopened")] Reopened,
+    /// Reasons this crate does not know, written back as they were read.
+    #[serde(untagged)] Other(String),
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum LockReason {
+    #[serde(rename = "off-topic")] OffTopic,
+    #[serde(rename = "too heated")] TooHeated,
+    #[serde(rename = "resolved")] Resolved,
+    #[serde(rename = "spam")] Spam,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 #[derive(Serialize)]
 #[serde(untagged)]
@@ -163,13 +201,38 @@ impl DerefMut for Issue {
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 #[derive(Serialize, Deserialize)]
 pub struct IssueContent {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) assignees: Option<Vec<User>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub(crate) labels: Option<Vec<Label>>,
     pub(crate) number: Number,
     #[serde(rename = "user")]
     pub(crate) author: User,
     pub(crate) title: String,
+    #[serde(deserialize_with = "deserialize_body")]
+    #[serde(default)]
     pub(crate) body: String,
-    pub(crate) state: IssueState 
+    pub(crate) state: IssueState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub(crate) state_reason: Option<IssueStateReason>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    #[serde(default)]
+    pub(crate) locked: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub(crate) active_lock_reason: Option<LockReason>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub(crate) milestone: Option<Milestone>,
+}
+
+/// Issues without a description report their body as `null`.
+fn deserialize_body<'de, D>(deserializer: D) -> Result<String, D::Error>
+where D: Deserializer<'de> {
+    Ok(Option::<String>::deserialize(deserializer)?
+        .unwrap_or_default())
 }
 
 impl IssueContent {
@@ -199,6 +262,11 @@ impl IssueContent {
         self.body.clone()
     }
 
+    /// Reads the fields of an issue created from the issue form, see [`IssueForm::try_parse`].
+    pub fn get_form_response(&self, form: &IssueForm) -> FormResponse {
+        form.parse(self.body.as_str())
+    }
+
     pub fn set_body(&mut self, body: impl AsRef<str>) {
         self.body = body.as_ref()
             .to_owned();
@@ -239,6 +307,30 @@ impl IssueContent {
         self
     }
 
+    pub fn get_labels(&self) -> Vec<Label> {
+        self.labels.clone()
+            .unwrap_or_default()
+    }
+
+    pub fn set_labels(&mut self, labels: impl AsRef<[Label]>) {
+        self.labels = Some({
+            labels.as_ref()
+                .to_owned()
+        });
+    }
+
+    pub fn with_labels(mut self, labels: impl AsRef<[Label]>) -> Self {
+        self.set_labels(labels);
+        self
+    }
+
+    pub fn has_label(&self, name: impl AsRef<str>) -> bool {
+        let name = name.as_ref();
+
+        self.labels.iter().flatten()
+            .any(|label| label.name.eq_ignore_ascii_case(name))
+    }
+
     pub fn is_closed(&self) -> bool {
         match self.state {
             IssueState::Closed => true,
@@ -246,8 +338,10 @@ impl IssueContent {
         }
     }
 
+    /// Closes the issue, GitHub records it as completed.
     pub fn close(&mut self) {
         self.state = IssueState::Closed;
+        self.state_reason = None;
     }
 
     pub fn as_closed(mut self) -> Self {
@@ -255,6 +349,32 @@ impl IssueContent {
         self
     }
 
+    pub fn close_as(&mut self, reason: IssueStateReason) {
+        self.state = IssueState::Closed;
+        self.state_reason = Some(reason);
+    }
+
+    pub fn as_closed_as(mut self, reason: IssueStateReason) -> Self {
+        self.close_as(reason);
+        self
+    }
+
+    pub fn get_state_reason(&self) -> Option<IssueStateReason> {
+        self.state_reason.clone()
+    }
+
+    pub fn get_milestone(&self) -> Option<Milestone> {
+        self.milestone.clone()
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    pub fn get_lock_reason(&self) -> Option<LockReason> {
+        self.active_lock_reason.clone()
+    }
+
     pub fn is_open(&self) -> bool {
         match self.state {
             IssueState::Open => true,
@@ -262,8 +382,10 @@ impl IssueContent {
         }
     }
 
+    /// Reopens the issue, dropping the reason it was closed for.
     pub fn open(&mut self) {
         self.state = IssueState::Open;
+        self.state_reason = None;
     }
 
     pub fn as_open(mut self) -> Self {
@@ -272,6 +394,61 @@ impl IssueContent {
     }
 }
 
+/// A new issue, assignees and labels are given by name.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct IssueDraft {
+    pub(crate) title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) body: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub(crate) assignees: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub(crate) labels: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) milestone: Option<Number>,
+}
+
+impl IssueDraft {
+    pub fn new(title: impl AsRef<str>) -> IssueDraft {
+        IssueDraft {
+            title: title.as_ref().to_owned(),
+            body: None,
+            assignees: Vec::new(),
+            labels: Vec::new(),
+            milestone: None,
+        }
+    }
+
+    pub fn with_body(mut self, body: impl AsRef<str>) -> Self {
+        self.body = Some(body.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_assignee(mut self, assignee: impl AsRef<str>) -> Self {
+        self.assignees.push(assignee.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_label(mut self, label: impl AsRef<str>) -> Self {
+        self.labels.push(label.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_milestone(mut self, milestone: impl Into<Number>) -> Self {
+        self.milestone = Some(milestone.into());
+        self
+    }
+}
+
+impl<T: AsRef<str>> From<T> for IssueDraft {
+    fn from(title: T) -> IssueDraft {
+        IssueDraft::new(title)
+    }
+}
+
 impl FmtDisplay for IssueContent {
     fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
         write!(fmt, "{number}", number = {
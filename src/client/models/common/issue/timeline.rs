@@ -0,0 +1,268 @@
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use crate::client::{
+
+    models::common::{
+
+        label::{Label},
+        user::{User},
+    },
+
+    common::{Date},
+    Number,
+};
+
+use super::{
+
+    IssueStateReason,
+    Issue,
+};
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct Rename {
+    pub(crate) from: String,
+    pub(crate) to: String,
+}
+
+impl Rename {
+    pub fn get_from(&self) -> String {
+        self.from.clone()
+    }
+
+    pub fn get_to(&self) -> String {
+        self.to.clone()
+    }
+}
+
+/// The issue or pull request which mentioned the issue.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct ReferenceSource {
+    #[serde(default)]
+    pub(crate) issue: Option<Box<Issue>>,
+}
+
+impl ReferenceSource {
+    pub fn get_issue(&self) -> Option<Issue> {
+        self.issue.as_deref()
+            .cloned()
+    }
+}
+
+/// Something that happened to an issue, actors are `None` for deleted accounts.
+/// Event types without a variant are read as `Other`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "event")]
+pub enum TimelineEvent {
+    #[serde(rename = "labeled")]
+    Labeled {
+        actor: Option<User>,
+        #[serde(rename = "created_at")]
+        date: Date,
+        label: Label,
+    },
+    #[serde(rename = "unlabeled")]
+    Unlabeled {
+        actor: Option<User>,
+        #[serde(rename = "created_at")]
+        date: Date,
+        label: Label,
+    },
+    #[serde(rename = "assigned")]
+    Assigned {
+        actor: Option<User>,
+        #[serde(rename = "created_at")]
+        date: Date,
+        assignee: Option<User>,
+    },
+    #[serde(rename = "unassigned")]
+    Unassigned {
+        actor: Option<User>,
+        #[serde(rename = "created_at")]
+        date: Date,
+        assignee: Option<User>,
+    },
+    #[serde(rename = "closed")]
+    Closed {
+        actor: Option<User>,
+        #[serde(rename = "created_at")]
+        date: Date,
+        #[serde(default)]
+        commit_id: Option<String>,
+        #[serde(default)]
+        state_reason: Option<IssueStateReason>,
+    },
+    #[serde(rename = "reopened")]
+    Reopened {
+        actor: Option<User>,
+        #[serde(rename = "created_at")]
+        date: Date,
+    },
+    /// The issue was mentioned in a commit message.
+    #[serde(rename = "referenced")]
+    Referenced {
+        actor: Option<User>,
+        #[serde(rename = "created_at")]
+        date: Date,
+        #[serde(default)]
+        commit_id: Option<String>,
+    },
+    /// The issue was mentioned in another issue or pull request.
+    #[serde(rename = "cross-referenced")]
+    CrossReferenced {
+        actor: Option<User>,
+        #[serde(rename = "created_at")]
+        date: Date,
+        source: ReferenceSource,
+    },
+    #[serde(rename = "commented")]
+    Commented {
+        actor: Option<User>,
+        #[serde(rename = "created_at")]
+        date: Date,
+        #[serde(rename = "id")]
+        number: Number,
+        #[serde(default)]
+        body: Option<String>,
+    },
+    #[serde(rename = "renamed")]
+    Renamed {
+        actor: Option<User>,
+        #[serde(rename = "created_at")]
+        date: Date,
+        rename: Rename,
+    },
+    #[serde(other)]
+    Other,
+}
+
+impl TimelineEvent {
+    pub fn get_actor(&self) -> Option<User> {
+        match self {
+            TimelineEvent::Labeled { actor, .. } |
+            TimelineEvent::Unlabeled { actor, .. } |
+            TimelineEvent::Assigned { actor, .. } |
+            TimelineEvent::Unassigned { actor, .. } |
+            TimelineEvent::Closed { actor, .. } |
+            TimelineEvent::Reopened { actor, .. } |
+            TimelineEvent::Referenced { actor, .. } |
+            TimelineEvent::CrossReferenced { actor, .. } |
+            TimelineEvent::Commented { actor, .. } |
+            TimelineEvent::Renamed { actor, .. } => actor.clone(),
+            TimelineEvent::Other => None,
+        }
+    }
+
+    pub fn get_date(&self) -> Option<Date> {
+        match self {
+            TimelineEvent::Labeled { date, .. } |
+            TimelineEvent::Unlabeled { date, .. } |
+            TimelineEvent::Assigned { date, .. } |
+            TimelineEvent::Unassigned { date, .. } |
+            TimelineEvent::Closed { date, .. } |
+            TimelineEvent::Reopened { date, .. } |
+            TimelineEvent::Referenced { date, .. } |
+            TimelineEvent::CrossReferenced { date, .. } |
+            TimelineEvent::Commented { date, .. } |
+            TimelineEvent::Renamed { date, .. } => Some(*date),
+            TimelineEvent::Other => None,
+        }
+    }
+
+    pub fn is_comment(&self) -> bool {
+        matches!(self, TimelineEvent::Commented { .. })
+    }
+
+    pub fn is_other(&self) -> bool {
+        matches!(self, TimelineEvent::Other)
+    }
+}
+
+/// An event from the issue events endpoint, which also names the issue.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct IssueEvent {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    #[serde(default)]
+    pub(crate) issue: Option<Issue>,
+    #[serde(flatten)]
+    pub(crate) event: TimelineEvent,
+}
+
+impl IssueEvent {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    /// The issue of the event, only set when listing events of a repository.
+    pub fn get_issue(&self) -> Option<Issue> {
+        self.issue.clone()
+    }
+
+    pub fn get_event(&self) -> &TimelineEvent {
+        &(self.event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{
+
+        TimelineEvent,
+        IssueEvent,
+    };
+
+    #[test]
+    fn test_deserialize() {
+        let events: Vec<TimelineEvent> = serde_json::from_str(r#"[
+            {
+                "event": "labeled",
+                "actor": { "type": "User", "login": "octocat", "id": 1 },
+                "created_at": "2011-04-14T16:00:49Z",
+                "label": { "name": "bug", "color": "d73a4a" }
+            },
+            {
+                "event": "commented",
+                "id": 1,
+                "actor": { "type": "User", "login": "octocat", "id": 1 },
+                "created_at": "2011-04-14T16:00:49Z",
+                "body": "Me too"
+            },
+            {
+                "event": "renamed",
+                "actor": null,
+                "created_at": "2011-04-14T16:00:49Z",
+                "rename": { "from": "Bug", "to": "Crash on start" }
+            },
+            {
+                "event": "subscribed",
+                "actor": { "type": "User", "login": "octocat", "id": 1 },
+                "created_at": "2011-04-14T16:00:49Z"
+            }
+        ]"#).unwrap();
+
+        assert!(events[0].get_actor().is_some());
+        assert!(events[1].is_comment());
+        assert!(events[2].get_actor().is_none());
+        assert!(events[3].is_other());
+
+        let event: IssueEvent = serde_json::from_str(r#"{
+            "id": 6430295168,
+            "event": "closed",
+            "actor": { "type": "User", "login": "octocat", "id": 1 },
+            "created_at": "2011-04-14T16:00:49Z",
+            "commit_id": null,
+            "state_reason": "not_planned"
+        }"#).unwrap();
+
+        assert!(matches!(event.get_event(), TimelineEvent::Closed { .. }));
+    }
+}
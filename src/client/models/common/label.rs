@@ -0,0 +1,167 @@
+use std::fmt::{
+
+    Formatter as FmtFormatter,
+    Display as FmtDisplay,
+    Result as FmtResult,
+};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use crate::client::{Number};
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct Label {
+    #[serde(rename = "id")]
+    #[serde(default)]
+    pub(crate) number: Number,
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) color: String,
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+}
+
+impl Label {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Hexadecimal color without the leading `#`.
+    pub fn get_color(&self) -> String {
+        self.color.clone()
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+}
+
+impl FmtDisplay for Label {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{name}", name = self.name)
+    }
+}
+
+impl AsRef<str> for Label {
+    fn as_ref(&self) -> &str {
+        self.name.as_ref()
+    }
+}
+
+/// Desired state of a label, `aliases` are former names which are renamed to
+/// `name` so issues keep their labels.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct LabelDefinition {
+    pub(crate) name: String,
+    pub(crate) color: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub(crate) aliases: Vec<String>,
+}
+
+impl LabelDefinition {
+    pub fn new(name: impl AsRef<str>, color: impl AsRef<str>) -> LabelDefinition {
+        LabelDefinition {
+            name: name.as_ref().to_owned(),
+            color: color.as_ref().to_owned(),
+            description: None,
+            aliases: Vec::new(),
+        }
+    }
+
+    pub fn with_description(mut self, description: impl AsRef<str>) -> Self {
+        self.description = Some(description.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_alias(mut self, alias: impl AsRef<str>) -> Self {
+        self.aliases.push(alias.as_ref().to_owned());
+        self
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Color normalized to lowercase hexadecimal without the leading `#`.
+    pub fn get_color(&self) -> String {
+        self.color.trim_start_matches('#')
+            .to_lowercase()
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    pub fn get_aliases(&self) -> Vec<String> {
+        self.aliases.clone()
+    }
+
+    /// Whether the label already matches the definition, ignoring its name.
+    pub fn is_satisfied_by(&self, label: &Label) -> bool {
+        let description = self.description.as_deref()
+            .unwrap_or_default();
+
+        label.color.eq_ignore_ascii_case(self.get_color().as_str()) && {
+            label.description.as_deref()
+                .unwrap_or_default()
+                .eq(description)
+        }
+    }
+}
+
+impl FmtDisplay for LabelDefinition {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{name}", name = self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{
+
+        LabelDefinition,
+        Label,
+    };
+
+    #[test]
+    fn test_definitions() {
+        let definitions: Vec<LabelDefinition> = serde_yaml::from_str(r##"
+            - name: bug
+              color: "#D73A4A"
+              description: Something isn't working
+              aliases: [defect, "type: bug"]
+            - name: documentation
+              color: 0075ca
+        "##).unwrap();
+
+        assert_eq!(definitions[0], LabelDefinition::new("bug", "#D73A4A")
+            .with_description("Something isn't working")
+            .with_alias("defect")
+            .with_alias("type: bug"));
+
+        let label = Label {
+            number: 208045946,
+            name: "defect".to_owned(),
+            color: "d73a4a".to_owned(),
+            description: Some("Something isn't working".to_owned()),
+        };
+
+        assert!(definitions[0].is_satisfied_by(&label));
+        assert!(!definitions[1].is_satisfied_by(&label));
+    }
+}
@@ -0,0 +1,250 @@
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use crate::client::{
+
+    common::{Date},
+    Number,
+};
+
+use super::user::{User};
+
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum OrganizationRole {
+    #[serde(rename = "admin")] Admin,
+    #[serde(rename = "member")] #[default] Member,
+    /// Only reported, billing managers are added through invitations.
+    #[serde(rename = "billing_manager")] BillingManager,
+}
+
+impl OrganizationRole {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            OrganizationRole::Admin => "admin",
+            OrganizationRole::Member => "member",
+            OrganizationRole::BillingManager => "billing_manager",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum MembershipState {
+    #[serde(rename = "active")] Active,
+    /// The user was invited and has not accepted yet.
+    #[serde(rename = "pending")] Pending,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct Membership {
+    pub(crate) state: MembershipState,
+    pub(crate) role: OrganizationRole,
+    #[serde(default)]
+    pub(crate) user: Option<User>,
+}
+
+impl Membership {
+    pub fn get_state(&self) -> MembershipState {
+        self.state
+    }
+
+    pub fn is_active(&self) -> bool {
+        matches!(self.state, MembershipState::Active)
+    }
+
+    pub fn is_pending(&self) -> bool {
+        matches!(self.state, MembershipState::Pending)
+    }
+
+    pub fn get_role(&self) -> OrganizationRole {
+        self.role
+    }
+
+    pub fn get_user(&self) -> Option<User> {
+        self.user.clone()
+    }
+}
+
+/// Which members or outside collaborators to list, by default all of them.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct MemberFilter {
+    pub(crate) role: Option<OrganizationRole>,
+    pub(crate) two_factor_disabled: bool,
+}
+
+impl MemberFilter {
+    pub fn new() -> MemberFilter {
+        MemberFilter::default()
+    }
+
+    /// Only members with the role, ignored when listing outside collaborators.
+    pub fn with_role(mut self, role: OrganizationRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// Only users without two-factor authentication, which only owners can see.
+    pub fn with_two_factor_disabled(mut self) -> Self {
+        self.two_factor_disabled = true;
+        self
+    }
+
+    pub(crate) fn get_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+
+        if let Some(role) = self.role {
+            query.push(("role", role.as_str().to_owned()));
+        }
+
+        if self.two_factor_disabled {
+            query.push(("filter", "2fa_disabled".to_owned()));
+        }
+
+        query
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum InvitationRole {
+    #[serde(rename = "admin")] Admin,
+    #[serde(rename = "direct_member")] #[default] DirectMember,
+    #[serde(rename = "billing_manager")] BillingManager,
+    /// Restores the former membership of a user who left.
+    #[serde(rename = "reinstate")] Reinstate,
+    #[serde(other)] Other,
+}
+
+/// A pending invitation to the organization, either of a GitHub user or of an email address.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct Invitation {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    #[serde(default)]
+    pub(crate) login: Option<String>,
+    #[serde(default)]
+    pub(crate) email: Option<String>,
+    pub(crate) role: InvitationRole,
+    #[serde(default)]
+    pub(crate) inviter: Option<User>,
+    #[serde(default)]
+    pub(crate) team_count: usize,
+    #[serde(rename = "created_at")]
+    pub(crate) date_created: Date,
+}
+
+impl Invitation {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_login(&self) -> Option<String> {
+        self.login.clone()
+    }
+
+    pub fn get_email(&self) -> Option<String> {
+        self.email.clone()
+    }
+
+    pub fn get_role(&self) -> InvitationRole {
+        self.role
+    }
+
+    pub fn get_inviter(&self) -> Option<User> {
+        self.inviter.clone()
+    }
+
+    pub fn get_team_count(&self) -> usize {
+        self.team_count
+    }
+
+    pub fn get_date_created(&self) -> Date {
+        self.date_created
+    }
+}
+
+/// An invitation to send, users are invited by id and others by email.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct InvitationDraft {
+    #[serde(rename = "invitee_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) user: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) email: Option<String>,
+    pub(crate) role: InvitationRole,
+    #[serde(rename = "team_ids")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) teams: Vec<Number>,
+}
+
+impl InvitationDraft {
+    pub fn user(user: impl Into<Number>) -> InvitationDraft {
+        InvitationDraft {
+            user: Some(user.into()),
+            email: None,
+            role: InvitationRole::default(),
+            teams: Vec::new(),
+        }
+    }
+
+    pub fn email(email: impl AsRef<str>) -> InvitationDraft {
+        InvitationDraft {
+            user: None,
+            email: Some(email.as_ref().to_owned()),
+            role: InvitationRole::default(),
+            teams: Vec::new(),
+        }
+    }
+
+    pub fn with_role(mut self, role: InvitationRole) -> Self {
+        self.role = role;
+        self
+    }
+
+    /// Adds the user to the team once they accept.
+    pub fn with_team(mut self, team: impl Into<Number>) -> Self {
+        self.teams.push(team.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{
+
+        InvitationDraft,
+        InvitationRole,
+        OrganizationRole,
+        MemberFilter,
+    };
+
+    #[test]
+    fn test_serialize() {
+        let filter = MemberFilter::new()
+            .with_role(OrganizationRole::Admin)
+            .with_two_factor_disabled();
+
+        assert_eq!(filter.get_query(), vec![
+            ("role", "admin".to_owned()),
+            ("filter", "2fa_disabled".to_owned()),
+        ]);
+
+        let draft = InvitationDraft::email("octocat@github.com")
+            .with_role(InvitationRole::Admin)
+            .with_team(12usize);
+
+        assert_eq!(serde_json::to_value(&draft).unwrap(), serde_json::json!({
+            "email": "octocat@github.com",
+            "role": "admin",
+            "team_ids": [12],
+        }));
+    }
+}
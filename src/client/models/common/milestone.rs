@@ -0,0 +1,225 @@
+use std::fmt::{
+
+    Formatter as FmtFormatter,
+    Display as FmtDisplay,
+    Result as FmtResult,
+};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use crate::client::{
+
+    common::{Date},
+    Number,
+};
+
+use super::{
+
+    issue::{IssueState},
+    user::{User},
+};
+
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum MilestoneSort {
+    #[serde(rename = "due_on")] #[default] DueOn,
+    #[serde(rename = "completeness")] Completeness,
+}
+
+impl MilestoneSort {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            MilestoneSort::DueOn => "due_on",
+            MilestoneSort::Completeness => "completeness",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct Milestone {
+    pub(crate) number: Number,
+    pub(crate) title: String,
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+    pub(crate) state: IssueState,
+    #[serde(default)]
+    pub(crate) creator: Option<User>,
+    #[serde(default)]
+    pub(crate) open_issues: usize,
+    #[serde(default)]
+    pub(crate) closed_issues: usize,
+    #[serde(default)]
+    pub(crate) due_on: Option<Date>,
+    #[serde(rename = "created_at")]
+    pub(crate) date_created: Date,
+    #[serde(rename = "closed_at")]
+    #[serde(default)]
+    pub(crate) date_closed: Option<Date>,
+}
+
+impl Milestone {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_title(&self) -> String {
+        self.title.clone()
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    pub fn get_state(&self) -> IssueState {
+        self.state.clone()
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.state.is_open()
+    }
+
+    pub fn get_creator(&self) -> Option<User> {
+        self.creator.clone()
+    }
+
+    pub fn get_open_issues(&self) -> usize {
+        self.open_issues
+    }
+
+    pub fn get_closed_issues(&self) -> usize {
+        self.closed_issues
+    }
+
+    /// Share of closed issues between 0 and 1, an empty milestone counts as done.
+    pub fn get_progress(&self) -> f64 {
+        match self.open_issues + self.closed_issues {
+            0 => 1.0,
+            total => self.closed_issues as f64 / total as f64,
+        }
+    }
+
+    pub fn get_due_on(&self) -> Option<Date> {
+        self.due_on
+    }
+
+    pub fn get_date_created(&self) -> Date {
+        self.date_created
+    }
+
+    pub fn get_date_closed(&self) -> Option<Date> {
+        self.date_closed
+    }
+}
+
+impl FmtDisplay for Milestone {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{title}", title = self.title)
+    }
+}
+
+impl From<Milestone> for Number {
+    fn from(milestone: Milestone) -> Number {
+        milestone.number
+    }
+}
+
+/// Fields of a milestone to create or update, unset fields are left alone.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct MilestoneContent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) state: Option<IssueState>,
+    /// Set to `Some(None)` to remove the due date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) due_on: Option<Option<Date>>,
+}
+
+impl MilestoneContent {
+    pub fn new(title: impl AsRef<str>) -> MilestoneContent {
+        MilestoneContent::default()
+            .with_title(title)
+    }
+
+    pub fn with_title(mut self, title: impl AsRef<str>) -> Self {
+        self.title = Some(title.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_description(mut self, description: impl AsRef<str>) -> Self {
+        self.description = Some(description.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_state(mut self, state: IssueState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    pub fn with_due_on(mut self, due_on: Date) -> Self {
+        self.due_on = Some(Some(due_on));
+        self
+    }
+
+    pub fn without_due_on(mut self) -> Self {
+        self.due_on = Some(None);
+        self
+    }
+}
+
+impl<T: AsRef<str>> From<T> for MilestoneContent {
+    fn from(title: T) -> MilestoneContent {
+        MilestoneContent::new(title)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{
+
+        MilestoneContent,
+        Milestone,
+    };
+
+    #[test]
+    fn test_deserialize() {
+        let milestone: Milestone = serde_json::from_str(r#"{
+            "number": 1,
+            "state": "open",
+            "title": "v1.0",
+            "description": null,
+            "open_issues": 4,
+            "closed_issues": 8,
+            "created_at": "2011-04-10T20:09:31Z",
+            "closed_at": null,
+            "due_on": "2012-10-09T23:39:01Z"
+        }"#).unwrap();
+
+        assert!(milestone.is_open());
+        assert!(milestone.get_due_on().is_some());
+        assert_eq!(milestone.get_number(), 1);
+        assert_eq!(milestone.get_progress(), 8.0 / 12.0);
+    }
+
+    #[test]
+    fn test_serialize_content() {
+        let value = serde_json::to_value(MilestoneContent::new("v1.0"))
+            .unwrap();
+
+        assert_eq!(value, serde_json::json!({ "title": "v1.0" }));
+
+        let value = serde_json::to_value(MilestoneContent::default().without_due_on())
+            .unwrap();
+
+        assert_eq!(value, serde_json::json!({ "due_on": null }));
+    }
+}
@@ -1,5 +1,18 @@
 pub mod repository;
+pub mod actions;
+pub mod deployment;
+pub mod environment;
+pub mod protection;
+pub mod ruleset;
+pub mod hook;
 pub mod commit;
+pub mod collaborator;
 pub mod issue;
+pub mod label;
+pub mod milestone;
+pub mod membership;
+pub mod permission;
+pub mod reaction;
+pub mod search;
 pub mod team;
 pub mod user;
\ No newline at end of file
@@ -0,0 +1,77 @@
+use std::{
+
+    collections::{BTreeMap},
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+/// Access to a repository, ordered from least to most privileged.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Serialize, Deserialize)]
+pub enum Permission {
+    #[serde(rename = "pull", alias = "read")] Pull,
+    #[serde(rename = "triage")] Triage,
+    #[serde(rename = "push", alias = "write")] Push,
+    #[serde(rename = "maintain")] Maintain,
+    #[serde(rename = "admin")] Admin,
+}
+
+impl Permission {
+    /// Whether this permission grants at least the other one.
+    pub fn includes(&self, other: Permission) -> bool {
+        *self >= other
+    }
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Permission::Pull => "pull",
+            Permission::Triage => "triage",
+            Permission::Push => "push",
+            Permission::Maintain => "maintain",
+            Permission::Admin => "admin",
+        }
+    }
+}
+
+impl FmtDisplay for Permission {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{permission}", permission = self.as_str())
+    }
+}
+
+/// Reads a permission, custom role names and `none` give `None`.
+pub(crate) fn parse_permission(name: impl AsRef<str>) -> Option<Permission> {
+    serde_json::from_value(serde_json::Value::String(name.as_ref().to_owned())).ok()
+}
+
+/// The permission of a role, custom roles give the highest of the granted
+/// permissions, which is the one they are based on.
+pub(crate) fn resolve_permission(role_name: Option<&str>, permissions: &BTreeMap<String, bool>) -> Option<Permission> {
+    let role = role_name.and_then(parse_permission);
+
+    role.or_else(|| {
+
+        [
+            Permission::Admin,
+            Permission::Maintain,
+            Permission::Push,
+            Permission::Triage,
+            Permission::Pull,
+        ].into_iter().find(|permission| {
+            permissions.get(permission.as_str())
+                .copied()
+                .unwrap_or_default()
+        })
+    })
+}
@@ -0,0 +1,661 @@
+use serde::{
+
+    Deserializer,
+    Deserialize,
+    Serializer,
+    Serialize,
+};
+
+use crate::client::{Number};
+
+/// A status check that must pass, `app` restricts which GitHub App may report it.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct StatusCheck {
+    pub(crate) context: String,
+    #[serde(rename = "app_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub(crate) app: Option<Number>,
+}
+
+impl StatusCheck {
+    pub fn new(context: impl AsRef<str>) -> StatusCheck {
+        StatusCheck {
+            context: context.as_ref().to_owned(),
+            app: None,
+        }
+    }
+
+    pub fn with_app(mut self, app: Number) -> Self {
+        self.app = Some(app);
+        self
+    }
+
+    pub fn get_context(&self) -> String {
+        self.context.clone()
+    }
+
+    pub fn get_app(&self) -> Option<Number> {
+        self.app
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StatusChecks {
+    pub(crate) strict: bool,
+    pub(crate) checks: Vec<StatusCheck>,
+}
+
+impl StatusChecks {
+    pub fn new() -> StatusChecks {
+        Default::default()
+    }
+
+    /// Requires branches to be up to date with the base branch before merging.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    pub fn with_check(mut self, check: StatusCheck) -> Self {
+        self.checks.push(check);
+        self
+    }
+
+    pub fn with_context(self, context: impl AsRef<str>) -> Self {
+        self.with_check(StatusCheck::new(context))
+    }
+
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    pub fn get_checks(&self) -> Vec<StatusCheck> {
+        self.checks.clone()
+    }
+
+    pub fn get_contexts(&self) -> Vec<String> {
+        self.checks.iter()
+            .map(|check| check.get_context())
+            .collect()
+    }
+}
+
+/// Users, teams and apps are full objects when reading them.
+#[derive(Debug)]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ActorName {
+    Plain(String),
+    Login { login: String },
+    Slug { slug: String },
+}
+
+impl From<ActorName> for String {
+    fn from(name: ActorName) -> String {
+        match name {
+            ActorName::Plain(name) => name,
+            ActorName::Login { login } => login,
+            ActorName::Slug { slug } => slug,
+        }
+    }
+}
+
+fn deserialize_names<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where D: Deserializer<'de> {
+    Ok(Vec::<ActorName>::deserialize(deserializer)?.into_iter()
+        .map(String::from)
+        .collect())
+}
+
+/// Who may dismiss or bypass reviews, users by login and teams and apps by slug.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct ReviewActors {
+    #[serde(deserialize_with = "deserialize_names")]
+    #[serde(default)]
+    pub(crate) users: Vec<String>,
+    #[serde(deserialize_with = "deserialize_names")]
+    #[serde(default)]
+    pub(crate) teams: Vec<String>,
+    #[serde(deserialize_with = "deserialize_names")]
+    #[serde(default)]
+    pub(crate) apps: Vec<String>,
+}
+
+impl ReviewActors {
+    pub fn new() -> ReviewActors {
+        Default::default()
+    }
+
+    pub fn with_user(mut self, user: impl AsRef<str>) -> Self {
+        self.users.push(user.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_team(mut self, team: impl AsRef<str>) -> Self {
+        self.teams.push(team.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_app(mut self, app: impl AsRef<str>) -> Self {
+        self.apps.push(app.as_ref().to_owned());
+        self
+    }
+
+    pub fn get_users(&self) -> Vec<String> {
+        self.users.clone()
+    }
+
+    pub fn get_teams(&self) -> Vec<String> {
+        self.teams.clone()
+    }
+
+    pub fn get_apps(&self) -> Vec<String> {
+        self.apps.clone()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct ReviewRules {
+    #[serde(default = "ReviewRules::default_count")]
+    pub(crate) required_approving_review_count: u8,
+    #[serde(default)]
+    pub(crate) require_code_owner_reviews: bool,
+    #[serde(default)]
+    pub(crate) dismiss_stale_reviews: bool,
+    #[serde(default)]
+    pub(crate) require_last_push_approval: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub(crate) dismissal_restrictions: Option<ReviewActors>,
+    #[serde(rename = "bypass_pull_request_allowances")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub(crate) bypass_allowances: Option<ReviewActors>,
+}
+
+impl Default for ReviewRules {
+    fn default() -> ReviewRules {
+        ReviewRules {
+            required_approving_review_count: ReviewRules::default_count(),
+            require_code_owner_reviews: false,
+            dismiss_stale_reviews: false,
+            require_last_push_approval: false,
+            dismissal_restrictions: None,
+            bypass_allowances: None,
+        }
+    }
+}
+
+impl ReviewRules {
+    fn default_count() -> u8 {
+        1
+    }
+
+    pub fn new() -> ReviewRules {
+        Default::default()
+    }
+
+    /// Number of approvals needed, between 0 and 6.
+    pub fn with_required_approvals(mut self, count: u8) -> Self {
+        self.required_approving_review_count = count;
+        self
+    }
+
+    pub fn with_code_owner_reviews(mut self, required: bool) -> Self {
+        self.require_code_owner_reviews = required;
+        self
+    }
+
+    pub fn with_dismiss_stale_reviews(mut self, dismiss: bool) -> Self {
+        self.dismiss_stale_reviews = dismiss;
+        self
+    }
+
+    pub fn with_last_push_approval(mut self, required: bool) -> Self {
+        self.require_last_push_approval = required;
+        self
+    }
+
+    /// Only these may dismiss reviews, organization repositories only.
+    pub fn with_dismissal_restrictions(mut self, actors: ReviewActors) -> Self {
+        self.dismissal_restrictions = Some(actors);
+        self
+    }
+
+    /// These may merge without the required reviews.
+    pub fn with_bypass_allowances(mut self, actors: ReviewActors) -> Self {
+        self.bypass_allowances = Some(actors);
+        self
+    }
+
+    pub fn get_required_approvals(&self) -> u8 {
+        self.required_approving_review_count
+    }
+
+    pub fn requires_code_owner_reviews(&self) -> bool {
+        self.require_code_owner_reviews
+    }
+
+    pub fn dismisses_stale_reviews(&self) -> bool {
+        self.dismiss_stale_reviews
+    }
+
+    pub fn requires_last_push_approval(&self) -> bool {
+        self.require_last_push_approval
+    }
+
+    pub fn get_dismissal_restrictions(&self) -> Option<ReviewActors> {
+        self.dismissal_restrictions.clone()
+    }
+
+    pub fn get_bypass_allowances(&self) -> Option<ReviewActors> {
+        self.bypass_allowances.clone()
+    }
+}
+
+/// Who may push to the branch, users by login and teams and apps by slug.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PushRestrictions {
+    pub(crate) users: Vec<String>,
+    pub(crate) teams: Vec<String>,
+    pub(crate) apps: Vec<String>,
+}
+
+impl PushRestrictions {
+    pub fn new() -> PushRestrictions {
+        Default::default()
+    }
+
+    pub fn with_user(mut self, user: impl AsRef<str>) -> Self {
+        self.users.push(user.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_team(mut self, team: impl AsRef<str>) -> Self {
+        self.teams.push(team.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_app(mut self, app: impl AsRef<str>) -> Self {
+        self.apps.push(app.as_ref().to_owned());
+        self
+    }
+
+    pub fn get_users(&self) -> Vec<String> {
+        self.users.clone()
+    }
+
+    pub fn get_teams(&self) -> Vec<String> {
+        self.teams.clone()
+    }
+
+    pub fn get_apps(&self) -> Vec<String> {
+        self.apps.clone()
+    }
+}
+
+/// Protection of a branch, it deserializes from both the verbose shape GitHub
+/// returns and the flat shape it serializes to, so it can be read, modified and
+/// written back.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BranchProtection {
+    pub(crate) required_status_checks: Option<StatusChecks>,
+    pub(crate) required_reviews: Option<ReviewRules>,
+    pub(crate) restrictions: Option<PushRestrictions>,
+    pub(crate) enforce_admins: bool,
+    pub(crate) required_linear_history: bool,
+    pub(crate) allow_force_pushes: bool,
+    pub(crate) allow_deletions: bool,
+    pub(crate) required_signatures: bool,
+    pub(crate) required_conversation_resolution: bool,
+}
+
+impl BranchProtection {
+    pub fn new() -> BranchProtection {
+        Default::default()
+    }
+
+    pub fn get_status_checks(&self) -> Option<StatusChecks> {
+        self.required_status_checks.clone()
+    }
+
+    pub fn set_status_checks(&mut self, checks: Option<StatusChecks>) {
+        self.required_status_checks = checks;
+    }
+
+    pub fn with_status_checks(mut self, checks: StatusChecks) -> Self {
+        self.set_status_checks(Some(checks));
+        self
+    }
+
+    pub fn get_reviews(&self) -> Option<ReviewRules> {
+        self.required_reviews.clone()
+    }
+
+    pub fn set_reviews(&mut self, reviews: Option<ReviewRules>) {
+        self.required_reviews = reviews;
+    }
+
+    pub fn with_reviews(mut self, reviews: ReviewRules) -> Self {
+        self.set_reviews(Some(reviews));
+        self
+    }
+
+    /// Push restrictions, only available for organization owned repositories.
+    pub fn get_restrictions(&self) -> Option<PushRestrictions> {
+        self.restrictions.clone()
+    }
+
+    pub fn set_restrictions(&mut self, restrictions: Option<PushRestrictions>) {
+        self.restrictions = restrictions;
+    }
+
+    pub fn with_restrictions(mut self, restrictions: PushRestrictions) -> Self {
+        self.set_restrictions(Some(restrictions));
+        self
+    }
+
+    pub fn enforces_admins(&self) -> bool {
+        self.enforce_admins
+    }
+
+    pub fn with_enforce_admins(mut self, enforce: bool) -> Self {
+        self.enforce_admins = enforce;
+        self
+    }
+
+    pub fn requires_linear_history(&self) -> bool {
+        self.required_linear_history
+    }
+
+    pub fn with_linear_history(mut self, required: bool) -> Self {
+        self.required_linear_history = required;
+        self
+    }
+
+    pub fn allows_force_pushes(&self) -> bool {
+        self.allow_force_pushes
+    }
+
+    pub fn with_force_pushes(mut self, allow: bool) -> Self {
+        self.allow_force_pushes = allow;
+        self
+    }
+
+    pub fn allows_deletions(&self) -> bool {
+        self.allow_deletions
+    }
+
+    pub fn with_deletions(mut self, allow: bool) -> Self {
+        self.allow_deletions = allow;
+        self
+    }
+
+    pub fn requires_signatures(&self) -> bool {
+        self.required_signatures
+    }
+
+    pub fn with_signatures(mut self, required: bool) -> Self {
+        self.required_signatures = required;
+        self
+    }
+
+    pub fn requires_conversation_resolution(&self) -> bool {
+        self.required_conversation_resolution
+    }
+
+    pub fn with_conversation_resolution(mut self, required: bool) -> Self {
+        self.required_conversation_resolution = required;
+        self
+    }
+}
+
+impl Serialize for BranchProtection {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+
+        #[derive(Serialize)]
+        struct CapsuleChecks<'a> {
+            strict: bool,
+            checks: &'a [StatusCheck],
+        }
+
+        #[derive(Serialize)]
+        struct CapsuleRestrictions<'a> {
+            users: &'a [String],
+            teams: &'a [String],
+            apps: &'a [String],
+        }
+
+        #[derive(Serialize)]
+        struct Capsule<'a> {
+            required_status_checks: Option<CapsuleChecks<'a>>,
+            required_pull_request_reviews: Option<&'a ReviewRules>,
+            restrictions: Option<CapsuleRestrictions<'a>>,
+            enforce_admins: bool,
+            required_linear_history: bool,
+            allow_force_pushes: bool,
+            allow_deletions: bool,
+            required_signatures: bool,
+            required_conversation_resolution: bool,
+        }
+
+        Capsule {
+            required_status_checks: self.required_status_checks.as_ref()
+                .map(|StatusChecks { strict, checks }| CapsuleChecks {
+                    checks: checks.as_slice(),
+                    strict: *strict,
+                }),
+            required_pull_request_reviews: self.required_reviews.as_ref(),
+            restrictions: self.restrictions.as_ref()
+                .map(|PushRestrictions { users, teams, apps }| CapsuleRestrictions {
+                    users: users.as_slice(),
+                    teams: teams.as_slice(),
+                    apps: apps.as_slice(),
+                }),
+            enforce_admins: self.enforce_admins,
+            required_linear_history: self.required_linear_history,
+            allow_force_pushes: self.allow_force_pushes,
+            allow_deletions: self.allow_deletions,
+            required_signatures: self.required_signatures,
+            required_conversation_resolution: self.required_conversation_resolution,
+        }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for BranchProtection {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de> {
+
+        // GitHub wraps settings as `{ "enabled": bool }` when reading them.
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum CapsuleToggle {
+            Wrapped { enabled: bool },
+            Plain(bool),
+        }
+
+        impl CapsuleToggle {
+            fn is_enabled(toggle: Option<CapsuleToggle>) -> bool {
+                match toggle {
+                    Some(CapsuleToggle::Wrapped { enabled }) => enabled,
+                    Some(CapsuleToggle::Plain(enabled)) => enabled,
+                    None => false,
+                }
+            }
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleChecks {
+            #[serde(default)]
+            strict: bool,
+            #[serde(default)]
+            contexts: Vec<String>,
+            #[serde(default)]
+            checks: Vec<StatusCheck>,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleRestrictions {
+            #[serde(deserialize_with = "deserialize_names")]
+            #[serde(default)]
+            users: Vec<String>,
+            #[serde(deserialize_with = "deserialize_names")]
+            #[serde(default)]
+            teams: Vec<String>,
+            #[serde(deserialize_with = "deserialize_names")]
+            #[serde(default)]
+            apps: Vec<String>,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            #[serde(default)]
+            required_status_checks: Option<CapsuleChecks>,
+            #[serde(default)]
+            required_pull_request_reviews: Option<ReviewRules>,
+            #[serde(default)]
+            restrictions: Option<CapsuleRestrictions>,
+            #[serde(default)]
+            enforce_admins: Option<CapsuleToggle>,
+            #[serde(default)]
+            required_linear_history: Option<CapsuleToggle>,
+            #[serde(default)]
+            allow_force_pushes: Option<CapsuleToggle>,
+            #[serde(default)]
+            allow_deletions: Option<CapsuleToggle>,
+            #[serde(default)]
+            required_signatures: Option<CapsuleToggle>,
+            #[serde(default)]
+            required_conversation_resolution: Option<CapsuleToggle>,
+        }
+
+        let capsule = Capsule::deserialize(deserializer)?;
+
+        let required_status_checks = capsule.required_status_checks
+            .map(|CapsuleChecks { strict, contexts, checks }| StatusChecks {
+                checks: if checks.is_empty() {
+                    contexts.into_iter()
+                        .map(StatusCheck::new)
+                        .collect()
+                } else {
+                    checks
+                },
+                strict,
+            });
+
+        let restrictions = capsule.restrictions
+            .map(|CapsuleRestrictions { users, teams, apps }| PushRestrictions { users, teams, apps });
+
+        Ok(BranchProtection {
+            required_status_checks,
+            required_reviews: capsule.required_pull_request_reviews,
+            restrictions,
+            enforce_admins: CapsuleToggle::is_enabled(capsule.enforce_admins),
+            required_linear_history: CapsuleToggle::is_enabled(capsule.required_linear_history),
+            allow_force_pushes: CapsuleToggle::is_enabled(capsule.allow_force_pushes),
+            allow_deletions: CapsuleToggle::is_enabled(capsule.allow_deletions),
+            required_signatures: CapsuleToggle::is_enabled(capsule.required_signatures),
+            required_conversation_resolution: CapsuleToggle::is_enabled(capsule.required_conversation_resolution),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{
+
+        BranchProtection,
+        PushRestrictions,
+        ReviewActors,
+        StatusChecks,
+        StatusCheck,
+        ReviewRules,
+    };
+
+    #[test]
+    fn test_deserialize_verbose() {
+        let protection: BranchProtection = serde_json::from_value(serde_json::json!({
+            "url": "https://api.github.com/repos/octocat/Hello-World/branches/master/protection",
+            "required_status_checks": {
+                "strict": true,
+                "contexts": ["continuous-integration/travis-ci"],
+                "checks": [{ "context": "continuous-integration/travis-ci", "app_id": null }],
+            },
+            "enforce_admins": { "enabled": true },
+            "required_pull_request_reviews": {
+                "dismiss_stale_reviews": true,
+                "require_code_owner_reviews": true,
+                "required_approving_review_count": 2,
+                "dismissal_restrictions": {
+                    "url": "https://api.github.com/repos/octocat/Hello-World/branches/master/protection/dismissal_restrictions",
+                    "users": [{ "login": "octocat", "id": 1, "type": "User" }],
+                    "teams": [],
+                    "apps": [],
+                },
+                "bypass_pull_request_allowances": {
+                    "users": [],
+                    "teams": [{ "name": "Justice League", "slug": "justice-league", "id": 1 }],
+                    "apps": [{ "slug": "octoapp", "id": 1, "owner": { "login": "github" } }],
+                },
+            },
+            "restrictions": {
+                "users": [{ "login": "octocat", "id": 1, "type": "User" }],
+                "teams": [{ "name": "Justice League", "slug": "justice-league", "id": 1 }],
+                "apps": [{ "slug": "octoapp", "id": 1, "owner": { "login": "github" } }],
+            },
+            "required_linear_history": { "enabled": true },
+            "allow_force_pushes": { "enabled": false },
+            "allow_deletions": { "enabled": false },
+            "required_signatures": { "enabled": true },
+        })).unwrap();
+
+        let expected = BranchProtection::new()
+            .with_status_checks(StatusChecks::new()
+                .with_strict(true)
+                .with_check(StatusCheck::new("continuous-integration/travis-ci")))
+            .with_reviews(ReviewRules::new()
+                .with_required_approvals(2)
+                .with_code_owner_reviews(true)
+                .with_dismiss_stale_reviews(true)
+                .with_dismissal_restrictions(ReviewActors::new()
+                    .with_user("octocat"))
+                .with_bypass_allowances(ReviewActors::new()
+                    .with_team("justice-league")
+                    .with_app("octoapp")))
+            .with_restrictions(PushRestrictions::new()
+                .with_user("octocat")
+                .with_team("justice-league")
+                .with_app("octoapp"))
+            .with_enforce_admins(true)
+            .with_linear_history(true)
+            .with_signatures(true);
+
+        assert_eq!(protection, expected);
+
+        let value = serde_json::to_value(&protection)
+            .unwrap();
+
+        assert_eq!(value["required_status_checks"]["checks"], serde_json::json!([
+            { "context": "continuous-integration/travis-ci" },
+        ]));
+
+        assert_eq!(value["required_pull_request_reviews"]["bypass_pull_request_allowances"], serde_json::json!({
+            "users": [], "teams": ["justice-league"], "apps": ["octoapp"],
+        }));
+
+        let roundtrip: BranchProtection = serde_json::from_value(value)
+            .unwrap();
+
+        assert_eq!(roundtrip, protection);
+    }
+}
@@ -0,0 +1,102 @@
+use std::fmt::{
+
+    Formatter as FmtFormatter,
+    Display as FmtDisplay,
+    Result as FmtResult,
+};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use crate::client::{
+
+    common::{Date},
+    Number,
+};
+
+use super::user::{User};
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum ReactionContent {
+    #[serde(rename = "+1")] ThumbsUp,
+    #[serde(rename = "-1")] ThumbsDown,
+    #[serde(rename = "laugh")] Laugh,
+    #[serde(rename = "confused")] Confused,
+    #[serde(rename = "heart")] Heart,
+    #[serde(rename = "hooray")] Hooray,
+    #[serde(rename = "rocket")] Rocket,
+    #[serde(rename = "eyes")] Eyes,
+}
+
+impl ReactionContent {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            ReactionContent::ThumbsUp => "+1",
+            ReactionContent::ThumbsDown => "-1",
+            ReactionContent::Laugh => "laugh",
+            ReactionContent::Confused => "confused",
+            ReactionContent::Heart => "heart",
+            ReactionContent::Hooray => "hooray",
+            ReactionContent::Rocket => "rocket",
+            ReactionContent::Eyes => "eyes",
+        }
+    }
+}
+
+impl FmtDisplay for ReactionContent {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{content}", content = self.as_str())
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct Reaction {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    #[serde(default)]
+    pub(crate) user: Option<User>,
+    pub(crate) content: ReactionContent,
+    #[serde(rename = "created_at")]
+    pub(crate) date_created: Date,
+}
+
+impl Reaction {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_user(&self) -> Option<User> {
+        self.user.clone()
+    }
+
+    pub fn get_content(&self) -> ReactionContent {
+        self.content
+    }
+
+    pub fn get_date_created(&self) -> Date {
+        self.date_created
+    }
+}
+
+impl FmtDisplay for Reaction {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{number}", number = self.number)
+    }
+}
+
+impl From<Reaction> for Number {
+    fn from(reaction: Reaction) -> Number {
+        reaction.number
+    }
+}
+
+impl From<&Reaction> for Number {
+    fn from(reaction: &Reaction) -> Number {
+        reaction.number
+    }
+}
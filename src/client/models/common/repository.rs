@@ -346,4 +346,195 @@ impl FmtDisplay for Repository {
     fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
         write!(fmt, "{name}", name = self.name)
     }
-}
\ No newline at end of file
+}
+
+/// A repository to create, settings left unset get the defaults of GitHub.
+#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize)]
+pub struct RepositoryDraft {
+    pub(crate) name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) homepage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) visibility: Option<Visibility>,
+    #[serde(rename = "has_issues")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) issues: Option<bool>,
+    #[serde(rename = "has_projects")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) projects: Option<bool>,
+    #[serde(rename = "has_wiki")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) wiki: Option<bool>,
+    #[serde(rename = "is_template")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) template: Option<bool>,
+    /// Creates an initial commit with a README, needed to push through the API.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    #[serde(default)]
+    pub(crate) auto_init: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) gitignore_template: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) license_template: Option<String>,
+    /// Team given access to the repository, only for organizations.
+    #[serde(rename = "team_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) team: Option<usize>,
+}
+
+impl RepositoryDraft {
+    pub fn new(name: impl AsRef<str>) -> RepositoryDraft {
+        RepositoryDraft {
+            name: name.as_ref().to_owned(),
+            description: None,
+            homepage: None,
+            visibility: None,
+            issues: None,
+            projects: None,
+            wiki: None,
+            template: None,
+            auto_init: false,
+            gitignore_template: None,
+            license_template: None,
+            team: None,
+        }
+    }
+
+    pub fn with_description(mut self, description: impl AsRef<str>) -> Self {
+        self.description = Some(description.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_homepage(mut self, homepage: impl AsRef<str>) -> Self {
+        self.homepage = Some(homepage.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+
+    pub fn with_issues(mut self, issues: bool) -> Self {
+        self.issues = Some(issues);
+        self
+    }
+
+    pub fn with_projects(mut self, projects: bool) -> Self {
+        self.projects = Some(projects);
+        self
+    }
+
+    pub fn with_wiki(mut self, wiki: bool) -> Self {
+        self.wiki = Some(wiki);
+        self
+    }
+
+    pub fn with_template(mut self, template: bool) -> Self {
+        self.template = Some(template);
+        self
+    }
+
+    pub fn with_auto_init(mut self, auto_init: bool) -> Self {
+        self.auto_init = auto_init;
+        self
+    }
+
+    /// Name of a `.gitignore` template such as `Rust`, implies `auto_init`.
+    pub fn with_gitignore_template(mut self, template: impl AsRef<str>) -> Self {
+        self.gitignore_template = Some(template.as_ref().to_owned());
+        self
+    }
+
+    /// License keyword such as `mit`, implies `auto_init`.
+    pub fn with_license_template(mut self, template: impl AsRef<str>) -> Self {
+        self.license_template = Some(template.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_team(mut self, team: impl Into<usize>) -> Self {
+        self.team = Some(team.into());
+        self
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl<T> From<T> for RepositoryDraft
+where T: AsRef<str> {
+    fn from(name: T) -> RepositoryDraft {
+        RepositoryDraft::new(name)
+    }
+}
+
+/// A repository to generate from a template, in the account of `owner`.
+#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize)]
+pub struct TemplateDraft {
+    pub(crate) owner: String,
+    pub(crate) name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+    /// Copies all branches instead of only the default branch.
+    #[serde(default)]
+    pub(crate) include_all_branches: bool,
+    #[serde(default)]
+    pub(crate) private: bool,
+}
+
+impl TemplateDraft {
+    pub fn new(owner: impl FmtDisplay, name: impl AsRef<str>) -> TemplateDraft {
+        TemplateDraft {
+            owner: owner.to_string(),
+            name: name.as_ref().to_owned(),
+            description: None,
+            include_all_branches: false,
+            private: false,
+        }
+    }
+
+    pub fn with_description(mut self, description: impl AsRef<str>) -> Self {
+        self.description = Some(description.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_all_branches(mut self, include_all_branches: bool) -> Self {
+        self.include_all_branches = include_all_branches;
+        self
+    }
+
+    pub fn with_private(mut self, private: bool) -> Self {
+        self.private = private;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{
+
+        RepositoryDraft,
+        Visibility,
+    };
+
+    #[test]
+    fn test_serialize() {
+        let draft = RepositoryDraft::new("hello-world")
+            .with_visibility(Visibility::Private)
+            .with_wiki(false)
+            .with_auto_init(true);
+
+        assert_eq!(serde_json::to_value(&draft).unwrap(), serde_json::json!({
+            "name": "hello-world",
+            "visibility": "private",
+            "has_wiki": false,
+            "auto_init": true,
+        }));
+    }
+}
@@ -0,0 +1,683 @@
+use std::fmt::{
+
+    Formatter as FmtFormatter,
+    Display as FmtDisplay,
+    Result as FmtResult,
+};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use serde_json::{
+
+    Value,
+    Map,
+};
+
+use crate::client::{Number};
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum RulesetTarget {
+    #[serde(rename = "branch")] Branch,
+    #[serde(rename = "tag")] Tag,
+    #[serde(rename = "push")] Push,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum RulesetEnforcement {
+    #[serde(rename = "disabled")] Disabled,
+    #[serde(rename = "active")] Active,
+    /// Only reports violations, available for organizations on GitHub Enterprise.
+    #[serde(rename = "evaluate")] Evaluate,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum BypassActorKind {
+    Integration,
+    OrganizationAdmin,
+    RepositoryRole,
+    Team,
+    DeployKey,
+    /// Kinds this crate does not know, written back as they were read.
+    #[serde(untagged)]
+    Other(String),
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum BypassMode {
+    /// Can always bypass the rules.
+    #[serde(rename = "always")] Always,
+    /// Can only bypass the rules when merging pull requests.
+    #[serde(rename = "pull_request")] PullRequest,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct BypassActor {
+    #[serde(rename = "actor_id")]
+    #[serde(default)]
+    pub(crate) actor: Option<Number>,
+    #[serde(rename = "actor_type")]
+    pub(crate) kind: BypassActorKind,
+    #[serde(rename = "bypass_mode")]
+    pub(crate) mode: BypassMode,
+}
+
+impl BypassActor {
+    /// Creates a bypass actor, `actor` is the id of the team, app or role and
+    /// is not needed for organization admins and deploy keys.
+    pub fn new(kind: BypassActorKind, actor: Option<Number>, mode: BypassMode) -> BypassActor {
+        BypassActor { actor, kind, mode }
+    }
+
+    pub fn get_actor(&self) -> Option<Number> {
+        self.actor
+    }
+
+    pub fn get_kind(&self) -> BypassActorKind {
+        self.kind.clone()
+    }
+
+    pub fn get_mode(&self) -> BypassMode {
+        self.mode.clone()
+    }
+}
+
+/// Patterns a name has to match, `~DEFAULT_BRANCH` and `~ALL` are special
+/// patterns for ref names.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct NameCondition {
+    #[serde(default)]
+    pub(crate) include: Vec<String>,
+    #[serde(default)]
+    pub(crate) exclude: Vec<String>,
+}
+
+impl NameCondition {
+    pub fn new() -> NameCondition {
+        Default::default()
+    }
+
+    pub fn with_include(mut self, pattern: impl AsRef<str>) -> Self {
+        self.include.push(pattern.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_exclude(mut self, pattern: impl AsRef<str>) -> Self {
+        self.exclude.push(pattern.as_ref().to_owned());
+        self
+    }
+
+    pub fn get_include(&self) -> Vec<String> {
+        self.include.clone()
+    }
+
+    pub fn get_exclude(&self) -> Vec<String> {
+        self.exclude.clone()
+    }
+}
+
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct RulesetConditions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub(crate) ref_name: Option<NameCondition>,
+    /// Which repositories an organization ruleset applies to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub(crate) repository_name: Option<NameCondition>,
+}
+
+impl RulesetConditions {
+    pub fn new() -> RulesetConditions {
+        Default::default()
+    }
+
+    pub fn with_ref_name(mut self, condition: NameCondition) -> Self {
+        self.ref_name = Some(condition);
+        self
+    }
+
+    pub fn with_repository_name(mut self, condition: NameCondition) -> Self {
+        self.repository_name = Some(condition);
+        self
+    }
+
+    pub fn get_ref_name(&self) -> Option<NameCondition> {
+        self.ref_name.clone()
+    }
+
+    pub fn get_repository_name(&self) -> Option<NameCondition> {
+        self.repository_name.clone()
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct RequiredStatusCheck {
+    pub(crate) context: String,
+    #[serde(rename = "integration_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub(crate) integration: Option<Number>,
+}
+
+impl RequiredStatusCheck {
+    pub fn new(context: impl AsRef<str>) -> RequiredStatusCheck {
+        RequiredStatusCheck {
+            context: context.as_ref().to_owned(),
+            integration: None,
+        }
+    }
+
+    /// Only accepts the check when reported by the given GitHub App.
+    pub fn with_integration(mut self, integration: Number) -> Self {
+        self.integration = Some(integration);
+        self
+    }
+
+    pub fn get_context(&self) -> String {
+        self.context.clone()
+    }
+
+    pub fn get_integration(&self) -> Option<Number> {
+        self.integration
+    }
+}
+
+/// Parameters of the `update` rule, unknown parameters are kept in `extra`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct UpdateParameters {
+    #[serde(default)]
+    pub(crate) update_allows_fetch_and_merge: bool,
+    #[serde(flatten)]
+    pub(crate) extra: Map<String, Value>,
+}
+
+impl UpdateParameters {
+    pub fn new() -> UpdateParameters {
+        Default::default()
+    }
+
+    /// Still allows to fetch and merge from the upstream of forks.
+    pub fn with_fetch_and_merge(mut self, allows: bool) -> Self {
+        self.update_allows_fetch_and_merge = allows;
+        self
+    }
+
+    pub fn allows_fetch_and_merge(&self) -> bool {
+        self.update_allows_fetch_and_merge
+    }
+}
+
+/// Parameters of the `pull_request` rule, unknown parameters such as
+/// `allowed_merge_methods` are kept in `extra`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct PullRequestParameters {
+    #[serde(default)]
+    pub(crate) required_approving_review_count: u8,
+    #[serde(default)]
+    pub(crate) dismiss_stale_reviews_on_push: bool,
+    #[serde(default)]
+    pub(crate) require_code_owner_review: bool,
+    #[serde(default)]
+    pub(crate) require_last_push_approval: bool,
+    #[serde(default)]
+    pub(crate) required_review_thread_resolution: bool,
+    #[serde(flatten)]
+    pub(crate) extra: Map<String, Value>,
+}
+
+impl PullRequestParameters {
+    pub fn new() -> PullRequestParameters {
+        Default::default()
+    }
+
+    pub fn with_required_approving_review_count(mut self, count: u8) -> Self {
+        self.required_approving_review_count = count;
+        self
+    }
+
+    pub fn with_dismiss_stale_reviews_on_push(mut self, dismiss: bool) -> Self {
+        self.dismiss_stale_reviews_on_push = dismiss;
+        self
+    }
+
+    pub fn with_require_code_owner_review(mut self, require: bool) -> Self {
+        self.require_code_owner_review = require;
+        self
+    }
+
+    pub fn with_require_last_push_approval(mut self, require: bool) -> Self {
+        self.require_last_push_approval = require;
+        self
+    }
+
+    pub fn with_required_review_thread_resolution(mut self, require: bool) -> Self {
+        self.required_review_thread_resolution = require;
+        self
+    }
+
+    pub fn get_required_approving_review_count(&self) -> u8 {
+        self.required_approving_review_count
+    }
+
+    pub fn get_dismiss_stale_reviews_on_push(&self) -> bool {
+        self.dismiss_stale_reviews_on_push
+    }
+
+    pub fn get_require_code_owner_review(&self) -> bool {
+        self.require_code_owner_review
+    }
+
+    pub fn get_require_last_push_approval(&self) -> bool {
+        self.require_last_push_approval
+    }
+
+    pub fn get_required_review_thread_resolution(&self) -> bool {
+        self.required_review_thread_resolution
+    }
+
+    /// Parameters this crate does not model, written back as they were read.
+    pub fn get_extra(&self) -> &Map<String, Value> {
+        &(self.extra)
+    }
+}
+
+/// Parameters of the `required_status_checks` rule, unknown parameters are kept in `extra`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct StatusCheckParameters {
+    #[serde(default)]
+    pub(crate) required_status_checks: Vec<RequiredStatusCheck>,
+    #[serde(rename = "strict_required_status_checks_policy")]
+    #[serde(default)]
+    pub(crate) strict: bool,
+    #[serde(flatten)]
+    pub(crate) extra: Map<String, Value>,
+}
+
+impl StatusCheckParameters {
+    pub fn new() -> StatusCheckParameters {
+        Default::default()
+    }
+
+    pub fn with_check(mut self, check: RequiredStatusCheck) -> Self {
+        self.required_status_checks.push(check);
+        self
+    }
+
+    /// Requires branches to be up to date with the base before merging.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    pub fn get_checks(&self) -> Vec<RequiredStatusCheck> {
+        self.required_status_checks.clone()
+    }
+
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Parameters this crate does not model, written back as they were read.
+    pub fn get_extra(&self) -> &Map<String, Value> {
+        &(self.extra)
+    }
+}
+
+/// Parameters of the `required_deployments` rule, unknown parameters are kept in `extra`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct DeploymentParameters {
+    #[serde(default)]
+    pub(crate) required_deployment_environments: Vec<String>,
+    #[serde(flatten)]
+    pub(crate) extra: Map<String, Value>,
+}
+
+impl DeploymentParameters {
+    pub fn new() -> DeploymentParameters {
+        Default::default()
+    }
+
+    pub fn with_environment(mut self, environment: impl AsRef<str>) -> Self {
+        self.required_deployment_environments.push(environment.as_ref().to_owned());
+        self
+    }
+
+    pub fn get_environments(&self) -> Vec<String> {
+        self.required_deployment_environments.clone()
+    }
+}
+
+/// A rule of a ruleset, kinds this crate does not model are read as `Other`
+/// and written back unchanged.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", content = "parameters")]
+pub enum Rule {
+    #[serde(rename = "creation")]
+    Creation,
+    #[serde(rename = "update")]
+    Update(UpdateParameters),
+    #[serde(rename = "deletion")]
+    Deletion,
+    #[serde(rename = "required_linear_history")]
+    RequiredLinearHistory,
+    #[serde(rename = "required_signatures")]
+    RequiredSignatures,
+    #[serde(rename = "non_fast_forward")]
+    NonFastForward,
+    #[serde(rename = "pull_request")]
+    PullRequest(PullRequestParameters),
+    #[serde(rename = "required_status_checks")]
+    RequiredStatusChecks(StatusCheckParameters),
+    #[serde(rename = "required_deployments")]
+    RequiredDeployments(DeploymentParameters),
+    #[serde(untagged)]
+    Other(Value),
+}
+
+impl Rule {
+    pub fn is_pull_request(&self) -> bool {
+        matches!(self, Rule::PullRequest(_))
+    }
+
+    pub fn is_required_status_checks(&self) -> bool {
+        matches!(self, Rule::RequiredStatusChecks(_))
+    }
+
+    pub fn is_required_signatures(&self) -> bool {
+        matches!(self, Rule::RequiredSignatures)
+    }
+
+    pub fn is_non_fast_forward(&self) -> bool {
+        matches!(self, Rule::NonFastForward)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct Ruleset {
+    #[serde(rename = "id")]
+    #[serde(skip_serializing)]
+    #[serde(default)]
+    pub(crate) number: Number,
+    pub(crate) name: String,
+    #[serde(default = "Ruleset::default_target")]
+    pub(crate) target: RulesetTarget,
+    pub(crate) enforcement: RulesetEnforcement,
+    /// Repository or organization the ruleset is defined in.
+    #[serde(skip_serializing)]
+    #[serde(default)]
+    pub(crate) source: String,
+    #[serde(default)]
+    pub(crate) bypass_actors: Vec<BypassActor>,
+    #[serde(default)]
+    pub(crate) conditions: RulesetConditions,
+    #[serde(default)]
+    pub(crate) rules: Vec<Rule>,
+}
+
+impl Ruleset {
+    fn default_target() -> RulesetTarget {
+        RulesetTarget::Branch
+    }
+
+    pub fn new(name: impl AsRef<str>, target: RulesetTarget) -> Ruleset {
+        Ruleset {
+            number: Default::default(),
+            name: name.as_ref().to_owned(),
+            enforcement: RulesetEnforcement::Active,
+            source: Default::default(),
+            bypass_actors: Vec::new(),
+            conditions: Default::default(),
+            rules: Vec::new(),
+            target,
+        }
+    }
+
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn set_name(&mut self, name: impl AsRef<str>) {
+        self.name = name.as_ref().to_owned();
+    }
+
+    pub fn get_target(&self) -> RulesetTarget {
+        self.target.clone()
+    }
+
+    pub fn get_enforcement(&self) -> RulesetEnforcement {
+        self.enforcement.clone()
+    }
+
+    pub fn set_enforcement(&mut self, enforcement: RulesetEnforcement) {
+        self.enforcement = enforcement;
+    }
+
+    pub fn with_enforcement(mut self, enforcement: RulesetEnforcement) -> Self {
+        self.set_enforcement(enforcement);
+        self
+    }
+
+    pub fn get_source(&self) -> String {
+        self.source.clone()
+    }
+
+    pub fn get_bypass_actors(&self) -> Vec<BypassActor> {
+        self.bypass_actors.clone()
+    }
+
+    pub fn with_bypass_actor(mut self, actor: BypassActor) -> Self {
+        self.bypass_actors.push(actor);
+        self
+    }
+
+    pub fn get_conditions(&self) -> RulesetConditions {
+        self.conditions.clone()
+    }
+
+    pub fn set_conditions(&mut self, conditions: RulesetConditions) {
+        self.conditions = conditions;
+    }
+
+    pub fn with_conditions(mut self, conditions: RulesetConditions) -> Self {
+        self.set_conditions(conditions);
+        self
+    }
+
+    /// Rules of the ruleset, listing rulesets leaves these empty.
+    pub fn get_rules(&self) -> Vec<Rule> {
+        self.rules.clone()
+    }
+
+    pub fn set_rules(&mut self, rules: impl AsRef<[Rule]>) {
+        self.rules = rules.as_ref().to_owned();
+    }
+
+    pub fn with_rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+}
+
+impl FmtDisplay for Ruleset {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{name}", name = self.name)
+    }
+}
+
+impl From<Ruleset> for Number {
+    fn from(ruleset: Ruleset) -> Number {
+        ruleset.number
+    }
+}
+
+/// A rule that applies to a branch together with the ruleset it comes from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct BranchRule {
+    #[serde(flatten)]
+    pub(crate) rule: Rule,
+    #[serde(rename = "ruleset_id")]
+    pub(crate) ruleset: Number,
+    #[serde(rename = "ruleset_source")]
+    pub(crate) source: String,
+}
+
+impl BranchRule {
+    pub fn get_rule(&self) -> Rule {
+        self.rule.clone()
+    }
+
+    pub fn get_ruleset(&self) -> Number {
+        self.ruleset
+    }
+
+    /// Repository or organization that defines the ruleset.
+    pub fn get_source(&self) -> String {
+        self.source.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{
+
+        RulesetConditions,
+        UpdateParameters,
+        BypassActorKind,
+        NameCondition,
+        BranchRule,
+        Ruleset,
+        Rule,
+    };
+
+    #[test]
+    fn test_deserialize() {
+        let ruleset: Ruleset = serde_json::from_value(serde_json::json!({
+            "id": 42,
+            "name": "main",
+            "target": "branch",
+            "source_type": "Repository",
+            "source": "octo-org/octo-repo",
+            "enforcement": "active",
+            "bypass_actors": [
+                { "actor_id": 234, "actor_type": "Team", "bypass_mode": "always" },
+            ],
+            "conditions": {
+                "ref_name": { "include": ["~DEFAULT_BRANCH"], "exclude": ["refs/heads/dev*"] },
+            },
+            "rules": [
+                { "type": "non_fast_forward" },
+                { "type": "required_signatures" },
+                {
+                    "type": "pull_request",
+                    "parameters": {
+                        "required_approving_review_count": 2,
+                        "dismiss_stale_reviews_on_push": true,
+                        "require_code_owner_review": false,
+                        "require_last_push_approval": false,
+                        "required_review_thread_resolution": false,
+                    },
+                },
+                {
+                    "type": "required_status_checks",
+                    "parameters": {
+                        "required_status_checks": [{ "context": "ci", "integration_id": 15368 }],
+                        "strict_required_status_checks_policy": true,
+                    },
+                },
+                { "type": "workflows", "parameters": { "workflows": [] } },
+            ],
+        })).unwrap();
+
+        assert_eq!(ruleset.get_conditions(), RulesetConditions::new()
+            .with_ref_name(NameCondition::new()
+                .with_include("~DEFAULT_BRANCH")
+                .with_exclude("refs/heads/dev*")));
+
+        let rules = ruleset.get_rules();
+
+        assert!(rules[0].is_non_fast_forward());
+        assert!(rules[1].is_required_signatures());
+        assert!(matches!(&rules[2], Rule::PullRequest(parameters) if parameters.get_required_approving_review_count() == 2));
+        assert!(matches!(&rules[3], Rule::RequiredStatusChecks(parameters) if parameters.is_strict()));
+        assert!(matches!(rules[4], Rule::Other(_)));
+
+        let branch: Vec<BranchRule> = serde_json::from_value(serde_json::json!([
+            { "type": "deletion", "ruleset_source_type": "Repository", "ruleset_source": "octo-org/octo-repo", "ruleset_id": 42 },
+            { "type": "update", "parameters": { "update_allows_fetch_and_merge": true }, "ruleset_source_type": "Organization", "ruleset_source": "octo-org", "ruleset_id": 7 },
+        ])).unwrap();
+
+        assert_eq!(branch[0].get_rule(), Rule::Deletion);
+        assert_eq!(branch[1].get_rule(), Rule::Update(UpdateParameters::new()
+            .with_fetch_and_merge(true)));
+        assert_eq!(branch[1].get_source(), "octo-org");
+    }
+    #[test]
+    fn test_round_trip() {
+        let value = serde_json::json!({
+            "name": "main",
+            "target": "branch",
+            "enforcement": "active",
+            "bypass_actors": [
+                { "actor_id": 5, "actor_type": "EnterpriseOwner", "bypass_mode": "always" },
+            ],
+            "conditions": {},
+            "rules": [
+                { "type": "deletion" },
+                { "type": "workflows", "parameters": { "workflows": [{ "path": ".github/workflows/ci.yml", "repository_id": 1 }] } },
+                {
+                    "type": "pull_request",
+                    "parameters": {
+                        "required_approving_review_count": 1,
+                        "dismiss_stale_reviews_on_push": false,
+                        "require_code_owner_review": false,
+                        "require_last_push_approval": false,
+                        "required_review_thread_resolution": false,
+                        "allowed_merge_methods": ["squash"],
+                    },
+                },
+                {
+                    "type": "required_status_checks",
+                    "parameters": {
+                        "required_status_checks": [{ "context": "ci" }],
+                        "strict_required_status_checks_policy": false,
+                        "do_not_enforce_on_create": true,
+                    },
+                },
+            ],
+        });
+
+        let ruleset: Ruleset = serde_json::from_value(value.clone()).unwrap();
+
+        assert_eq!(ruleset.get_bypass_actors()[0].get_kind(), BypassActorKind::Other("EnterpriseOwner".to_owned()));
+        let rules = ruleset.get_rules();
+
+        assert!(matches!(rules[1], Rule::Other(_)));
+        assert!(matches!(&rules[2], Rule::PullRequest(parameters) if parameters.get_extra().contains_key("allowed_merge_methods")));
+        assert!(matches!(&rules[3], Rule::RequiredStatusChecks(parameters) if parameters.get_extra().contains_key("do_not_enforce_on_create")));
+        assert_eq!(serde_json::to_value(&ruleset).unwrap(), value);
+    }
+}
\ No newline at end of file
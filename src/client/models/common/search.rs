@@ -0,0 +1,422 @@
+use std::fmt::{
+
+    Formatter as FmtFormatter,
+    Display as FmtDisplay,
+    Result as FmtResult,
+};
+
+use chrono::{NaiveDate};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use crate::client::{
+
+    common::{Date},
+    Number,
+};
+
+use super::{
+
+    issue::filter::{SortDirection},
+    user::{User},
+};
+
+/// A range of days for qualifiers such as `created:`, bounds are inclusive.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum DateRange {
+    On(NaiveDate),
+    After(NaiveDate),
+    Before(NaiveDate),
+    Between(NaiveDate, NaiveDate),
+}
+
+impl FmtDisplay for DateRange {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        match self {
+            DateRange::On(date) => write!(fmt, "{date}"),
+            DateRange::After(date) => write!(fmt, ">={date}"),
+            DateRange::Before(date) => write!(fmt, "<={date}"),
+            DateRange::Between(from, to) => write!(fmt, "{from}..{to}"),
+        }
+    }
+}
+
+/// A search query made of free text and `key:value` qualifiers. Values with
+/// whitespace are quoted, excluded qualifiers are prefixed with a dash.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct SearchQuery {
+    pub(crate) terms: Vec<String>,
+    pub(crate) qualifiers: Vec<(bool, String, String)>,
+    pub(crate) sort: Option<String>,
+    pub(crate) direction: Option<SortDirection>,
+}
+
+impl SearchQuery {
+    pub fn new(terms: impl AsRef<str>) -> SearchQuery {
+        SearchQuery::default()
+            .with_terms(terms)
+    }
+
+    pub fn with_terms(mut self, terms: impl AsRef<str>) -> Self {
+        let terms = terms.as_ref().trim();
+
+        if !(terms.is_empty()) {
+            self.terms.push(terms.to_owned());
+        }
+
+        self
+    }
+
+    pub fn with_qualifier(mut self, key: impl AsRef<str>, value: impl FmtDisplay) -> Self {
+        self.qualifiers.push((false, key.as_ref().to_owned(), value.to_string()));
+        self
+    }
+
+    pub fn without_qualifier(mut self, key: impl AsRef<str>, value: impl FmtDisplay) -> Self {
+        self.qualifiers.push((true, key.as_ref().to_owned(), value.to_string()));
+        self
+    }
+
+    /// Restricts to a repository given as `owner/name` or a repository handle.
+    pub fn with_repository(self, repository: impl FmtDisplay) -> Self {
+        self.with_qualifier("repo", repository)
+    }
+
+    pub fn with_organization(self, organization: impl FmtDisplay) -> Self {
+        self.with_qualifier("org", organization)
+    }
+
+    pub fn with_user(self, user: impl FmtDisplay) -> Self {
+        self.with_qualifier("user", user)
+    }
+
+    /// Adds an `is:` qualifier such as `open`, `closed`, `issue`, `pr` or `merged`.
+    pub fn with_is(self, state: impl AsRef<str>) -> Self {
+        self.with_qualifier("is", state.as_ref())
+    }
+
+    pub fn with_label(self, label: impl AsRef<str>) -> Self {
+        self.with_qualifier("label", label.as_ref())
+    }
+
+    pub fn without_label(self, label: impl AsRef<str>) -> Self {
+        self.without_qualifier("label", label.as_ref())
+    }
+
+    pub fn with_author(self, author: impl FmtDisplay) -> Self {
+        self.with_qualifier("author", author)
+    }
+
+    pub fn with_assignee(self, assignee: impl FmtDisplay) -> Self {
+        self.with_qualifier("assignee", assignee)
+    }
+
+    pub fn with_created(self, range: DateRange) -> Self {
+        self.with_qualifier("created", range)
+    }
+
+    pub fn with_updated(self, range: DateRange) -> Self {
+        self.with_qualifier("updated", range)
+    }
+
+    pub fn with_closed(self, range: DateRange) -> Self {
+        self.with_qualifier("closed", range)
+    }
+
+    /// Where to look for the terms, such as `title`, `body`, `comments` or `file`.
+    pub fn with_in(self, field: impl AsRef<str>) -> Self {
+        self.with_qualifier("in", field.as_ref())
+    }
+
+    pub fn with_language(self, language: impl AsRef<str>) -> Self {
+        self.with_qualifier("language", language.as_ref())
+    }
+
+    /// Sorts by a field of the searched type instead of the best match.
+    pub fn with_sort(mut self, sort: impl AsRef<str>, direction: SortDirection) -> Self {
+        self.sort = Some(sort.as_ref().to_owned());
+        self.direction = Some(direction);
+        self
+    }
+
+    pub(crate) fn get_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![("q", self.to_string())];
+
+        if let Some(ref sort) = self.sort {
+            query.push(("sort", sort.clone()));
+        }
+
+        if let Some(direction) = self.direction {
+            query.push(("order", direction.as_str().to_owned()));
+        }
+
+        query
+    }
+}
+
+impl FmtDisplay for SearchQuery {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        let mut parts = self.terms.clone();
+
+        for (excluded, key, value) in self.qualifiers.iter() {
+            let prefix = if *excluded { "-" } else { "" };
+
+            match value.contains(|character: char| character.is_whitespace() || character == '"') {
+                true => parts.push(format!("{prefix}{key}:\"{value}\"", value = value.replace('"', "\\\""))),
+                _ => parts.push(format!("{prefix}{key}:{value}")),
+            }
+        }
+
+        write!(fmt, "{query}", query = parts.join(" "))
+    }
+}
+
+/// A page of search results, `total` counts all matches even beyond the 1,000 results
+/// search can return.
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct SearchResults<T> {
+    #[serde(rename = "total_count")]
+    pub(crate) total: usize,
+    #[serde(rename = "incomplete_results")]
+    pub(crate) incomplete: bool,
+    pub(crate) items: Vec<T>,
+}
+
+impl<T> SearchResults<T> {
+    pub fn get_total(&self) -> usize {
+        self.total
+    }
+
+    /// Whether the search timed out before finding all matches.
+    pub fn is_incomplete(&self) -> bool {
+        self.incomplete
+    }
+
+    pub fn get_items(&self) -> &[T] {
+        self.items.as_slice()
+    }
+
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+/// The repository a search result belongs to.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct SearchRepository {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    pub(crate) name: String,
+    pub(crate) full_name: String,
+    #[serde(default)]
+    pub(crate) owner: Option<User>,
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+    #[serde(default)]
+    pub(crate) private: bool,
+    #[serde(default)]
+    pub(crate) fork: bool,
+    #[serde(default)]
+    pub(crate) archived: bool,
+    #[serde(default)]
+    pub(crate) language: Option<String>,
+    #[serde(default)]
+    pub(crate) topics: Vec<String>,
+    #[serde(rename = "stargazers_count")]
+    #[serde(default)]
+    pub(crate) stars: usize,
+    #[serde(rename = "forks_count")]
+    #[serde(default)]
+    pub(crate) forks: usize,
+    #[serde(rename = "pushed_at")]
+    #[serde(default)]
+    pub(crate) date_pushed: Option<Date>,
+}
+
+impl SearchRepository {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Name of the repository as `owner/name`.
+    pub fn get_full_name(&self) -> String {
+        self.full_name.clone()
+    }
+
+    pub fn get_owner(&self) -> Option<User> {
+        self.owner.clone()
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    pub fn is_private(&self) -> bool {
+        self.private
+    }
+
+    pub fn is_fork(&self) -> bool {
+        self.fork
+    }
+
+    pub fn is_archived(&self) -> bool {
+        self.archived
+    }
+
+    pub fn get_language(&self) -> Option<String> {
+        self.language.clone()
+    }
+
+    pub fn get_topics(&self) -> Vec<String> {
+        self.topics.clone()
+    }
+
+    pub fn get_stars(&self) -> usize {
+        self.stars
+    }
+
+    pub fn get_forks(&self) -> usize {
+        self.forks
+    }
+
+    pub fn get_date_pushed(&self) -> Option<Date> {
+        self.date_pushed
+    }
+}
+
+impl FmtDisplay for SearchRepository {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        write!(fmt, "{name}", name = self.full_name)
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct CodeResult {
+    pub(crate) name: String,
+    pub(crate) path: String,
+    pub(crate) sha: String,
+    pub(crate) repository: SearchRepository,
+}
+
+impl CodeResult {
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_path(&self) -> String {
+        self.path.clone()
+    }
+
+    /// Sha of the blob the match was found in.
+    pub fn get_sha(&self) -> String {
+        self.sha.clone()
+    }
+
+    pub fn get_repository(&self) -> &SearchRepository {
+        &(self.repository)
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct CommitResultAuthor {
+    pub(crate) name: String,
+    pub(crate) email: String,
+    pub(crate) date: Date,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct CommitResultContent {
+    pub(crate) message: String,
+    pub(crate) author: CommitResultAuthor,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct CommitResult {
+    pub(crate) sha: String,
+    pub(crate) commit: CommitResultContent,
+    #[serde(default)]
+    pub(crate) author: Option<User>,
+    pub(crate) repository: SearchRepository,
+}
+
+impl CommitResult {
+    pub fn get_sha(&self) -> String {
+        self.sha.clone()
+    }
+
+    pub fn get_message(&self) -> String {
+        self.commit.message.clone()
+    }
+
+    /// Name of the author as given in the commit.
+    pub fn get_author_name(&self) -> String {
+        self.commit.author.name.clone()
+    }
+
+    pub fn get_author_email(&self) -> String {
+        self.commit.author.email.clone()
+    }
+
+    pub fn get_date(&self) -> Date {
+        self.commit.author.date
+    }
+
+    /// The GitHub account of the author, if the email belongs to one.
+    pub fn get_author(&self) -> Option<User> {
+        self.author.clone()
+    }
+
+    pub fn get_repository(&self) -> &SearchRepository {
+        &(self.repository)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use chrono::{NaiveDate};
+
+    use super::{
+
+        SearchQuery,
+        DateRange,
+    };
+
+    #[test]
+    fn test_query() {
+        let query = SearchQuery::new("crash in:title")
+            .with_repository("octocat/hello-world")
+            .with_is("open")
+            .with_label("help wanted")
+            .without_label("wontfix")
+            .with_created(DateRange::Between({
+                NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()
+            }, {
+                NaiveDate::from_ymd_opt(2024, 3, 31).unwrap()
+            }));
+
+        assert_eq!(query.to_string(), concat!(
+            "crash in:title repo:octocat/hello-world is:open ",
+            "label:\"help wanted\" -label:wontfix created:2024-01-01..2024-03-31",
+        ));
+
+        let query = SearchQuery::new("crash")
+            .with_label(r#"say "hi""#);
+
+        assert_eq!(query.to_string(), r#"crash label:"say \"hi\"""#);
+    }
+}
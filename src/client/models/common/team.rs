@@ -1,27 +1,111 @@
-use std::fmt::{
+use std::{
 
-    Formatter as FmtFormatter,
-    Display as FmtDisplay,
-    Result as FmtResult,
+    collections::{BTreeMap},
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
 };
 
 
 use serde::{
     
+    Deserializer,
     Deserialize,
     Serialize, 
 };
 
 use crate::client::{Number};
 
+use super::permission::{
+
+    resolve_permission,
+    Permission,
+};
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum TeamPrivacy {
+    /// Only visible to organization owners and members of the team.
+    #[serde(rename = "secret")] Secret,
+    /// Visible to all members of the organization.
+    #[serde(rename = "closed")] Closed,
+}
+
+impl TeamPrivacy {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            TeamPrivacy::Secret => "secret",
+            TeamPrivacy::Closed => "closed",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum TeamNotification {
+    #[serde(rename = "notifications_enabled")] Enabled,
+    #[serde(rename = "notifications_disabled")] Disabled,
+}
+
+impl TeamNotification {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            TeamNotification::Enabled => "notifications_enabled",
+            TeamNotification::Disabled => "notifications_disabled",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum TeamRole {
+    #[serde(rename = "member")] #[default] Member,
+    /// Can add and remove members and change the team settings.
+    #[serde(rename = "maintainer")] Maintainer,
+}
+
+impl TeamRole {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            TeamRole::Member => "member",
+            TeamRole::Maintainer => "maintainer",
+        }
+    }
+}
+
+impl FmtDisplay for TeamRole {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{role}", role = self.as_str())
+    }
+}
+
 #[derive(Clone, Debug, Eq)]
 #[derive(Serialize, Deserialize)]
 pub struct Team {
     pub(crate) name: String,
+    #[serde(deserialize_with = "deserialize_description")]
+    #[serde(default)]
     pub(crate) description: String,
     pub(crate) slug: String,
     #[serde(rename = "id")]
     pub(crate) number: Number,
+    #[serde(default)]
+    pub(crate) privacy: Option<TeamPrivacy>,
+    #[serde(default)]
+    pub(crate) notification_setting: Option<TeamNotification>,
+    #[serde(default)]
+    pub(crate) parent: Option<Box<Team>>,
+}
+
+/// Teams without a description report it as `null`.
+fn deserialize_description<'de, D>(deserializer: D) -> Result<String, D::Error>
+where D: Deserializer<'de> {
+    Ok(Option::<String>::deserialize(deserializer)?
+        .unwrap_or_default())
 }
 
 impl Team {
@@ -40,6 +124,19 @@ impl Team {
     pub fn get_number(&self) -> Number {
         self.number.clone()
     }
+
+    pub fn get_privacy(&self) -> Option<TeamPrivacy> {
+        self.privacy
+    }
+
+    pub fn get_notification(&self) -> Option<TeamNotification> {
+        self.notification_setting
+    }
+
+    pub fn get_parent(&self) -> Option<Team> {
+        self.parent.as_deref()
+            .cloned()
+    }
 }
 
 impl PartialEq for Team {
@@ -74,4 +171,284 @@ impl Into<Number> for Team {
     fn into(self) -> Number {
         self.number.clone()
     }
-}
\ No newline at end of file
+}
+
+/// Settings of a team to create or update, settings left unset are not changed.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct TeamContent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) privacy: Option<TeamPrivacy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) notification_setting: Option<TeamNotification>,
+    /// Set to `Some(None)` to move the team to the top level.
+    #[serde(rename = "parent_team_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) parent: Option<Option<Number>>,
+}
+
+impl TeamContent {
+    pub fn new() -> TeamContent {
+        TeamContent::default()
+    }
+
+    pub fn with_name(mut self, name: impl AsRef<str>) -> Self {
+        self.name = Some(name.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_description(mut self, description: impl AsRef<str>) -> Self {
+        self.description = Some(description.as_ref().to_owned());
+        self
+    }
+
+    /// Child teams must be `Closed`.
+    pub fn with_privacy(mut self, privacy: TeamPrivacy) -> Self {
+        self.privacy = Some(privacy);
+        self
+    }
+
+    pub fn with_notification(mut self, notification: TeamNotification) -> Self {
+        self.notification_setting = Some(notification);
+        self
+    }
+
+    pub fn with_parent(mut self, parent: impl Into<Number>) -> Self {
+        self.parent = Some(Some(parent.into()));
+        self
+    }
+
+    pub fn without_parent(mut self) -> Self {
+        self.parent = Some(None);
+        self
+    }
+}
+
+impl<T> From<T> for TeamContent
+where T: AsRef<str> {
+    fn from(name: T) -> TeamContent {
+        TeamContent::new().with_name(name)
+    }
+}
+
+/// A repository a team has access to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct TeamRepository {
+    pub(crate) name: String,
+    pub(crate) full_name: String,
+    #[serde(default)]
+    pub(crate) role_name: Option<String>,
+    #[serde(default)]
+    pub(crate) permissions: BTreeMap<String, bool>,
+}
+
+impl TeamRepository {
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Name of the repository as `owner/name`.
+    pub fn get_full_name(&self) -> String {
+        self.full_name.clone()
+    }
+
+    /// Name of the role, which differs from the permission for custom roles.
+    pub fn get_role_name(&self) -> Option<String> {
+        self.role_name.clone()
+    }
+
+    /// The permission of the team, custom roles give the one they are based on.
+    pub fn get_permission(&self) -> Option<Permission> {
+        resolve_permission(self.role_name.as_deref(), &(self.permissions))
+    }
+}
+
+impl FmtDisplay for TeamRepository {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{name}", name = self.full_name)
+    }
+}
+
+/// The desired state of a team. Members are given by login with their role,
+/// repositories by name within the organization or as `owner/name`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct TeamDefinition {
+    pub(crate) name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub(crate) privacy: Option<TeamPrivacy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub(crate) notification: Option<TeamNotification>,
+    /// Name or slug of the parent team, teams without one are at the top level.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub(crate) parent: Option<String>,
+    #[serde(default)]
+    pub(crate) members: BTreeMap<String, TeamRole>,
+    #[serde(default)]
+    pub(crate) repositories: BTreeMap<String, Permission>,
+}
+
+impl TeamDefinition {
+    pub fn new(name: impl AsRef<str>) -> TeamDefinition {
+        TeamDefinition {
+            name: name.as_ref().to_owned(),
+            description: None,
+            privacy: None,
+            notification: None,
+            parent: None,
+            members: BTreeMap::new(),
+            repositories: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_description(mut self, description: impl AsRef<str>) -> Self {
+        self.description = Some(description.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_privacy(mut self, privacy: TeamPrivacy) -> Self {
+        self.privacy = Some(privacy);
+        self
+    }
+
+    pub fn with_notification(mut self, notification: TeamNotification) -> Self {
+        self.notification = Some(notification);
+        self
+    }
+
+    pub fn with_parent(mut self, parent: impl AsRef<str>) -> Self {
+        self.parent = Some(parent.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_member(mut self, user: impl AsRef<str>, role: TeamRole) -> Self {
+        self.members.insert(user.as_ref().to_owned(), role);
+        self
+    }
+
+    pub fn with_repository(mut self, repository: impl AsRef<str>, permission: Permission) -> Self {
+        self.repositories.insert(repository.as_ref().to_owned(), permission);
+        self
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    pub fn get_privacy(&self) -> Option<TeamPrivacy> {
+        self.privacy
+    }
+
+    pub fn get_notification(&self) -> Option<TeamNotification> {
+        self.notification
+    }
+
+    pub fn get_parent(&self) -> Option<String> {
+        self.parent.clone()
+    }
+
+    pub fn get_members(&self) -> &BTreeMap<String, TeamRole> {
+        &(self.members)
+    }
+
+    pub fn get_repositories(&self) -> &BTreeMap<String, Permission> {
+        &(self.repositories)
+    }
+
+    /// Whether the definition names the team, by name or slug ignoring case.
+    pub fn is_defining(&self, team: &Team) -> bool {
+        self.name.eq_ignore_ascii_case(team.name.as_str())
+            || self.name.eq_ignore_ascii_case(team.slug.as_str())
+    }
+}
+
+impl FmtDisplay for TeamDefinition {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{name}", name = self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::client::models::common::permission::{Permission};
+
+    use super::{
+
+        TeamRepository,
+        TeamDefinition,
+        TeamContent,
+        TeamRole,
+        Team,
+    };
+
+    #[test]
+    fn test_serialize() {
+        let content = TeamContent::from("Platform")
+            .without_parent();
+
+        assert_eq!(serde_json::to_value(&content).unwrap(), serde_json::json!({
+            "name": "Platform",
+            "parent_team_id": null,
+        }));
+
+        let team: Team = serde_json::from_str(r#"{
+            "id": 2,
+            "name": "Infrastructure",
+            "slug": "infrastructure",
+            "description": null,
+            "privacy": "closed",
+            "notification_setting": "notifications_enabled",
+            "parent": { "id": 1, "name": "Platform", "slug": "platform", "description": null }
+        }"#).unwrap();
+
+        assert_eq!(team.get_parent().unwrap().get_slug(), "platform");
+
+        let repository: TeamRepository = serde_json::from_str(r#"{
+            "name": "hello-world",
+            "full_name": "octocat/hello-world",
+            "role_name": "security-reviewer",
+            "permissions": { "admin": false, "maintain": false, "push": true, "triage": true, "pull": true }
+        }"#).unwrap();
+
+        assert_eq!(repository.get_permission(), Some(Permission::Push));
+    }
+
+    #[test]
+    fn test_definitions() {
+        let definitions: Vec<TeamDefinition> = serde_yaml::from_str(r#"
+            - name: Platform
+              privacy: closed
+              members:
+                octocat: maintainer
+            - name: Infrastructure
+              parent: platform
+              members:
+                octocat: member
+                hubot: member
+              repositories:
+                hello-world: push
+        "#).unwrap();
+
+        assert_eq!(definitions[1], TeamDefinition::new("Infrastructure")
+            .with_parent("platform")
+            .with_member("octocat", TeamRole::Member)
+            .with_member("hubot", TeamRole::Member)
+            .with_repository("hello-world", Permission::Push));
+    }
+}
@@ -1,9 +1,17 @@
+use std::{
+
+    path::{Path},
+};
+
 use serde::{
     
     Deserialize,
     Serialize, 
 };
 
+use serde_json::{Value};
+use thiserror::{Error};
+
 pub mod payloads;
 pub use payloads::{
     
@@ -13,6 +21,29 @@ pub use payloads::{
     EventIssue, 
 };
 
+#[derive(Error, Debug)]
+pub enum EventError {
+    #[error("Failed to read event payload!")]
+    File(#[from] std::io::Error),
+    #[error("Failed to parse event payload!")]
+    Payload(#[from] serde_json::Error),
+    #[error("Missing environment variable: '{name}'")]
+    Environment { name: String },
+}
+
+/// Whether the event and its action are modeled by `Event`.
+fn is_modeled(name: &str, payload: &Value) -> bool {
+    let action = payload["action"].as_str()
+        .unwrap_or_default();
+
+    match name {
+        "issue_comment" => EventIssueComment::ACTIONS.contains(&action),
+        "issues" => EventIssue::ACTIONS.contains(&action),
+        "schedule" | "workflow_dispatch" => true,
+        _ => false,
+    }
+}
+
 #[derive(Debug, Clone)]
 #[derive(Serialize, Deserialize)]
 #[serde(tag = "event_name", content = "event")]
@@ -25,9 +56,63 @@ pub enum Event {
     Schedule(EventSchedule),
     #[serde(rename = "workflow_dispatch")]
     WorkflowDispatch(EventWorkflowDispatch),
+    /// Events or actions this crate does not model, such as `ping` or `push`.
+    #[serde(untagged)]
+    #[serde(skip_deserializing)]
+    Other {
+        #[serde(rename = "event_name")]
+        name: String,
+        #[serde(rename = "event")]
+        payload: Value,
+    },
 }
 
 impl Event {
+    /// Reads an event from its name, such as `issue_comment`, and the payload as
+    /// delivered by a webhook or written to `GITHUB_EVENT_PATH`. Events or actions
+    /// which are not modeled are read as `Event::Other`, modeled ones which fail
+    /// to parse are an error.
+    pub fn try_from_payload(name: impl AsRef<str>, payload: impl AsRef<[u8]>) -> Result<Event, EventError> {
+        let payload: Value = serde_json::from_slice(payload.as_ref())?;
+        let name = name.as_ref();
+
+        if !(is_modeled(name, &payload)) {
+            return Ok(Event::Other {
+                name: name.to_owned(),
+                payload,
+            })
+        }
+
+        Ok(serde_json::from_value(serde_json::json!({
+            "event_name": name,
+            "event": payload,
+        }))?)
+    }
+
+    pub fn try_from_path(name: impl AsRef<str>, path: impl AsRef<Path>) -> Result<Event, EventError> {
+        Event::try_from_payload(name, std::fs::read(path)?)
+    }
+
+    /// Reads the event which triggered the running workflow.
+    pub fn try_from_environment() -> Result<Event, EventError> {
+        let variable = |name: &str| std::env::var(name).map_err(|_| EventError::Environment {
+            name: name.to_owned()
+        });
+
+        Event::try_from_path(variable("GITHUB_EVENT_NAME")?, variable("GITHUB_EVENT_PATH")?)
+    }
+
+    /// Name of the event, such as `issue_comment`.
+    pub fn get_name(&self) -> &str {
+        match self {
+            Event::IssueComment(_) => "issue_comment",
+            Event::Issue(_) => "issues",
+            Event::Schedule(_) => "schedule",
+            Event::WorkflowDispatch(_) => "workflow_dispatch",
+            Event::Other { name, .. } => name.as_str(),
+        }
+    }
+
     pub fn is_bot_generated(&self) -> bool {
         match self {
             Event::IssueComment(comment) => match comment {
@@ -51,4 +136,30 @@ impl Event {
             _ => false,
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{
+
+        EventError,
+        Event,
+    };
+
+    #[test]
+    fn test_try_from_payload() {
+        let event = Event::try_from_payload("ping", r#"{"zen":"Design for failure."}"#)
+            .unwrap();
+
+        assert!(matches!(event, Event::Other { ref name, .. } if name == "ping"));
+
+        let event = Event::try_from_payload("issue_comment", r#"{"action":"pinned","issue":{}}"#)
+            .unwrap();
+
+        assert!(matches!(event, Event::Other { ref payload, .. } if payload["action"] == "pinned"));
+
+        let result = Event::try_from_payload("issue_comment", r#"{"action":"created","issue":{"number":"one"}}"#);
+        assert!(matches!(result, Err(EventError::Payload(_))));
+    }
+}
@@ -6,7 +6,17 @@ use serde::{
 
 use crate::client::models::common::{
 
-    issue::{Issue, IssueState},
+    issue::{
+
+        form::{
+
+            FormResponse,
+            IssueForm,
+        },
+
+        IssueState,
+        Issue,
+    },
     user::{User},
 };
 
@@ -27,6 +37,9 @@ pub enum EventIssue {
 }
 
 impl EventIssue {
+    /// Actions modeled by this crate, other actions are read as `Event::Other`.
+    pub(crate) const ACTIONS: &'static [&'static str] = &["opened", "closed", "edited", "reopened", "deleted"];
+
     pub fn get_state(&self) -> IssueState {
         match self {
             EventIssue::Reopened { issue, .. } |
@@ -57,6 +70,10 @@ impl EventIssue {
         }
     }
 
+    pub fn get_form_response(&self, form: &IssueForm) -> FormResponse {
+        form.parse(self.get_body())
+    }
+
     pub fn get_number(&self) -> usize {
         match self {
             EventIssue::Reopened { issue, .. } |
@@ -26,6 +26,9 @@ pub enum EventIssueComment {
 }
 
 impl EventIssueComment {
+    /// Actions modeled by this crate, other actions are read as `Event::Other`.
+    pub(crate) const ACTIONS: &'static [&'static str] = &["created", "edited", "deleted"];
+
     pub fn get_issue_number(&self) -> usize {
         match self {
             EventIssueComment::Deleted { issue, .. } |
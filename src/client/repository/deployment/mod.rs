@@ -0,0 +1,370 @@
+use std::{
+
+    borrow::{Cow},
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+};
+
+use serde::{Deserialize};
+use thiserror::{Error};
+
+use crate::client::{
+
+    repository::{
+
+        reference::{HandleReference},
+        commit::{HandleCommit},
+
+        HandleRepository,
+    },
+
+    client::{
+
+        ClientResponseError,
+        ClientError,
+        Client,
+    },
+
+    models::common::deployment::{
+
+        DeploymentStatusContent,
+        DeploymentContent,
+        PendingDeployment,
+        DeploymentStatus,
+        DeploymentState,
+        ReviewState,
+        Deployment,
+    },
+
+    GitHubProperties,
+    GitHubEndpoint,
+    GitHubResult,
+    Number,
+};
+
+#[derive(Error, Debug)]
+pub enum DeploymentError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Default branch was merged into the ref instead: '{message}'")]
+    Merged { message: String },
+    #[error("Deployment is still active: {number}")]
+    Active { number: Number },
+    #[error("Deployment not found: {number}")]
+    Nothing { number: Number },
+}
+
+/// What to deploy, either a reference or a specific commit.
+#[derive(Clone, Debug)]
+pub enum DeploymentReference {
+    Reference(HandleReference),
+    Commit(HandleCommit),
+}
+
+impl FmtDisplay for DeploymentReference {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        match self {
+            DeploymentReference::Reference(HandleReference::Branch { branch, .. }) => write!(fmt, "{branch}"),
+            DeploymentReference::Reference(HandleReference::Tag { tag, .. }) => write!(fmt, "{tag}"),
+            DeploymentReference::Reference(reference) => write!(fmt, "refs/{reference}"),
+            DeploymentReference::Commit(commit) => write!(fmt, "{commit}"),
+        }
+    }
+}
+
+impl From<HandleReference> for DeploymentReference {
+    fn from(reference: HandleReference) -> DeploymentReference {
+        DeploymentReference::Reference(reference)
+    }
+}
+
+impl From<&HandleReference> for DeploymentReference {
+    fn from(reference: &HandleReference) -> DeploymentReference {
+        DeploymentReference::Reference(reference.clone())
+    }
+}
+
+impl From<HandleCommit> for DeploymentReference {
+    fn from(commit: HandleCommit) -> DeploymentReference {
+        DeploymentReference::Commit(commit)
+    }
+}
+
+impl From<&HandleCommit> for DeploymentReference {
+    fn from(commit: &HandleCommit) -> DeploymentReference {
+        DeploymentReference::Commit(commit.clone())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleDeployment {
+    repository: HandleRepository,
+    number: Number,
+}
+
+impl HandleDeployment {
+    pub(crate) fn try_fetch(repository: &HandleRepository, number: impl Into<Number>) -> GitHubResult<HandleDeployment, DeploymentError> {
+        let number = number.into();
+
+        let Deployment { number, .. } = {
+
+            let result = {
+
+                repository.get_client()
+                    .get(format!("repos/{repository}/deployments/{number}"))?
+                    .send()
+            };
+
+            match result {
+                Err(ClientError::Response(ClientResponseError::Nothing { .. })) => {
+                    return Err(DeploymentError::Nothing { number })
+                },
+                Err(error) => return Err(error.into()),
+                Ok(response) => response.json()?,
+            }
+        };
+
+        Ok(HandleDeployment {
+            repository: repository.clone(),
+            number,
+        })
+    }
+
+    /// Lists deployments newest first, optionally only those of one environment.
+    pub(crate) fn try_fetch_all(repository: &HandleRepository, environment: Option<&str>) -> GitHubResult<Vec<HandleDeployment>, DeploymentError> {
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let deployments: Vec<Deployment> = {
+
+                let mut request = repository.get_client()
+                    .get(format!("repos/{repository}/deployments"))?
+                    .query(&[("per_page", 100), ("page", page)]);
+
+                if let Some(environment) = environment {
+                    request = request.query(&[("environment", environment)]);
+                }
+
+                request.send()?.json()?
+            };
+
+            let count = deployments.len();
+
+            collection.extend(deployments.into_iter()
+                .map(|Deployment { number, .. }| HandleDeployment {
+                    repository: repository.clone(),
+                    number,
+                }));
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub(crate) fn try_create(repository: &HandleRepository, reference: impl Into<DeploymentReference>, content: &DeploymentContent) -> GitHubResult<HandleDeployment, DeploymentError> {
+        let reference = reference.into();
+
+        let mut payload = serde_json::to_value(content)
+            .unwrap_or_default();
+
+        payload["ref"] = reference.to_string()
+            .into();
+
+        let response = {
+
+            repository.get_client()
+                .post(format!("repos/{repository}/deployments"))?
+                .json(&payload)
+                .send()?
+        };
+
+        // With auto-merge enabled GitHub may merge the default branch into
+        // the ref instead of creating a deployment, which is a 202 response.
+        if response.code() == 202 {
+
+            #[derive(Debug, Default)]
+            #[derive(Deserialize)]
+            struct Capsule {
+                message: String,
+            }
+
+            let Capsule { message } = response.json()
+                .unwrap_or_default();
+
+            return Err(DeploymentError::Merged { message })
+        }
+
+        let Deployment { number, .. } = response.json()?;
+
+        Ok(HandleDeployment {
+            repository: repository.clone(),
+            number,
+        })
+    }
+
+    /// Lists the deployments of a workflow run waiting for review, `run` is the run id.
+    pub(crate) fn try_fetch_pending(repository: &HandleRepository, run: Number) -> GitHubResult<Vec<PendingDeployment>, DeploymentError> {
+        Ok(repository.get_client()
+            .get(format!("repos/{repository}/actions/runs/{run}/pending_deployments"))?
+            .send()?.json()?)
+    }
+
+    /// Approves or rejects the pending deployments of a workflow run for the given
+    /// environments, returns the deployments which were reviewed.
+    pub(crate) fn try_review_pending<T: Into<Number>>(repository: &HandleRepository, run: Number, environments: impl IntoIterator<Item = T>, state: ReviewState, comment: impl AsRef<str>) -> GitHubResult<Vec<Deployment>, DeploymentError> {
+        let environments: Vec<Number> = environments.into_iter()
+            .map(|environment| environment.into())
+            .collect();
+
+        let payload = serde_json::json!({
+            "environment_ids": environments,
+            "state": state,
+            "comment": comment.as_ref(),
+        });
+
+        Ok(repository.get_client()
+            .post(format!("repos/{repository}/actions/runs/{run}/pending_deployments"))?
+            .json(&payload)
+            .send()?.json()?)
+    }
+
+    pub fn try_get_deployment(&self) -> GitHubResult<Deployment, DeploymentError> {
+        Ok(self.get_client()
+            .get(self.get_endpoint())?
+            .send()?.json()?)
+    }
+
+    /// Lists the statuses of the deployment, newest first.
+    pub fn try_get_statuses(&self) -> GitHubResult<Vec<DeploymentStatus>, DeploymentError> {
+        let endpoint = self.get_endpoint();
+
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let statuses: Vec<DeploymentStatus> = {
+
+                self.get_client()
+                    .get(format!("{endpoint}/statuses"))?
+                    .query(&[("per_page", 100), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = statuses.len();
+
+            collection.extend(statuses);
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub fn try_get_latest_status(&self) -> GitHubResult<Option<DeploymentStatus>, DeploymentError> {
+        let endpoint = self.get_endpoint();
+
+        let statuses: Vec<DeploymentStatus> = {
+
+            self.get_client()
+                .get(format!("{endpoint}/statuses"))?
+                .query(&[("per_page", 1)])
+                .send()?.json()?
+        };
+
+        Ok(statuses.into_iter().next())
+    }
+
+    pub fn try_create_status(&self, content: impl Into<DeploymentStatusContent>) -> GitHubResult<DeploymentStatus, DeploymentError> {
+        let endpoint = self.get_endpoint();
+        let content = content.into();
+
+        Ok(self.get_client()
+            .post(format!("{endpoint}/statuses"))?
+            .json(&content)
+            .send()?.json()?)
+    }
+
+    /// Marks the deployment inactive, which is required before it can be deleted.
+    pub fn try_deactivate(&self) -> GitHubResult<DeploymentStatus, DeploymentError> {
+        self.try_create_status(DeploymentState::Inactive)
+    }
+
+    /// Deletes the deployment, only inactive deployments can be deleted unless
+    /// it is the only deployment of the repository.
+    pub fn try_delete(&self) -> GitHubResult<(), DeploymentError> {
+        let result = {
+
+            self.get_client()
+                .delete(self.get_endpoint())?
+                .send()
+        };
+
+        match result {
+            Err(ClientError::Response(ClientResponseError::Validation { .. })) => {
+                Err(DeploymentError::Active { number: self.number })
+            },
+            Err(error) => Err(error.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+}
+
+impl From<&HandleDeployment> for Number {
+    fn from(deployment: &HandleDeployment) -> Number {
+        deployment.number
+    }
+}
+
+impl From<HandleDeployment> for Number {
+    fn from(deployment: HandleDeployment) -> Number {
+        deployment.number
+    }
+}
+
+impl<'a> GitHubEndpoint<'a> for HandleDeployment {
+    fn get_endpoint(&'a self) -> Cow<'a, str> {
+        let HandleDeployment { repository, number } = { self };
+        format!("repos/{repository}/deployments/{number}").into()
+    }
+}
+
+impl<'a> GitHubProperties<'a> for HandleDeployment {
+    type Content = Deployment;
+    type Parent = HandleRepository;
+
+    fn get_client(&'a self) -> &'a Client {
+        self.get_parent()
+            .get_client()
+    }
+
+    fn get_parent(&'a self) -> &'a Self::Parent {
+        &(self.repository)
+    }
+}
+
+impl FmtDisplay for HandleDeployment {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        write!(fmt, "{number}", number = self.number)
+    }
+}
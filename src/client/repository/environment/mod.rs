@@ -0,0 +1,257 @@
+use std::{
+
+    borrow::{Cow},
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+};
+
+use serde::{Deserialize};
+use thiserror::{Error};
+
+use crate::client::{
+
+    repository::{HandleRepository},
+    actions::{ActionsScope},
+
+    client::{
+
+        ClientResponseError,
+        ClientError,
+        Client,
+    },
+
+    models::common::environment::{
+
+        EnvironmentContent,
+        BranchPolicyKind,
+        BranchPolicy,
+        Environment,
+    },
+
+    common::{encode_segment},
+
+    GitHubProperties,
+    GitHubEndpoint,
+    GitHubResult,
+    Number,
+};
+
+#[derive(Error, Debug)]
+pub enum EnvironmentError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Environment not found: '{name}'")]
+    Nothing { name: String },
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleEnvironment {
+    repository: HandleRepository,
+    name: String,
+}
+
+impl HandleEnvironment {
+    pub(crate) fn try_fetch(repository: &HandleRepository, name: impl AsRef<str>) -> GitHubResult<HandleEnvironment, EnvironmentError> {
+        let name = name.as_ref();
+
+        let Environment { name, .. } = {
+
+            let result = {
+
+                repository.get_client()
+                    .get(format!("repos/{repository}/environments/{name}", name = encode_segment(name)))?
+                    .send()
+            };
+
+            match result {
+                Err(ClientError::Response(ClientResponseError::Nothing { .. })) => {
+                    return Err(EnvironmentError::Nothing { name: name.to_owned() })
+                },
+                Err(error) => return Err(error.into()),
+                Ok(response) => response.json()?,
+            }
+        };
+
+        Ok(HandleEnvironment {
+            repository: repository.clone(),
+            name,
+        })
+    }
+
+    pub(crate) fn try_fetch_all(repository: &HandleRepository) -> GitHubResult<Vec<HandleEnvironment>, EnvironmentError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            environments: Vec<Environment>,
+        }
+
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let Capsule { environments } = {
+
+                repository.get_client()
+                    .get(format!("repos/{repository}/environments"))?
+                    .query(&[("per_page", 100), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = environments.len();
+
+            collection.extend(environments.into_iter()
+                .map(|Environment { name, .. }| HandleEnvironment {
+                    repository: repository.clone(),
+                    name,
+                }));
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    /// Creates the environment or replaces the protection settings of an existing one.
+    pub(crate) fn try_create(repository: &HandleRepository, name: impl AsRef<str>, content: &EnvironmentContent) -> GitHubResult<HandleEnvironment, EnvironmentError> {
+        let environment = HandleEnvironment {
+            name: name.as_ref().to_owned(),
+            repository: repository.clone(),
+        };
+
+        environment.try_update(content)?;
+
+        Ok(environment)
+    }
+
+    pub fn try_get_environment(&self) -> GitHubResult<Environment, EnvironmentError> {
+        Ok(self.get_client()
+            .get(self.get_endpoint())?
+            .send()?.json()?)
+    }
+
+    /// Replaces the protection settings of the environment.
+    pub fn try_update(&self, content: &EnvironmentContent) -> GitHubResult<(), EnvironmentError> {
+        self.get_client()
+            .put(self.get_endpoint())?
+            .json(content)
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_delete(&self) -> GitHubResult<(), EnvironmentError> {
+        self.get_client()
+            .delete(self.get_endpoint())?
+            .send()?;
+
+        Ok(())
+    }
+
+    /// Gets the ref patterns allowed to deploy, requires custom branch policies.
+    pub fn try_get_branch_policies(&self) -> GitHubResult<Vec<BranchPolicy>, EnvironmentError> {
+        let endpoint = self.get_endpoint();
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            branch_policies: Vec<BranchPolicy>,
+        }
+
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let Capsule { branch_policies } = {
+
+                self.get_client()
+                    .get(format!("{endpoint}/deployment-branch-policies"))?
+                    .query(&[("per_page", 100), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = branch_policies.len();
+
+            collection.extend(branch_policies);
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub fn try_create_branch_policy(&self, pattern: impl AsRef<str>, kind: BranchPolicyKind) -> GitHubResult<BranchPolicy, EnvironmentError> {
+        let endpoint = self.get_endpoint();
+
+        let payload = serde_json::json!({
+            "name": pattern.as_ref(),
+            "type": kind,
+        });
+
+        Ok(self.get_client()
+            .post(format!("{endpoint}/deployment-branch-policies"))?
+            .json(&payload)
+            .send()?.json()?)
+    }
+
+    pub fn try_delete_branch_policy(&self, policy: impl Into<Number>) -> GitHubResult<(), EnvironmentError> {
+        let endpoint = self.get_endpoint();
+        let policy = policy.into();
+
+        self.get_client()
+            .delete(format!("{endpoint}/deployment-branch-policies/{policy}"))?
+            .send()?;
+
+        Ok(())
+    }
+
+    /// Scope for the secrets and variables of the environment.
+    pub fn get_actions_scope(&self) -> ActionsScope {
+        self.repository.get_environment_scope(self.name.as_str())
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl<'a> GitHubEndpoint<'a> for HandleEnvironment {
+    fn get_endpoint(&'a self) -> Cow<'a, str> {
+        let HandleEnvironment { repository, name } = { self };
+        format!("repos/{repository}/environments/{name}", name = encode_segment(name)).into()
+    }
+}
+
+impl<'a> GitHubProperties<'a> for HandleEnvironment {
+    type Content = Environment;
+    type Parent = HandleRepository;
+
+    fn get_client(&'a self) -> &'a Client {
+        self.get_parent()
+            .get_client()
+    }
+
+    fn get_parent(&'a self) -> &'a Self::Parent {
+        &(self.repository)
+    }
+}
+
+impl FmtDisplay for HandleEnvironment {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        write!(fmt, "{name}", name = self.name)
+    }
+}
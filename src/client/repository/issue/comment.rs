@@ -12,7 +12,16 @@ use std::{
 
 use crate::client::{
 
-    repository::issue::{HandleIssue},
+    repository::{
+
+        reaction::{
+
+            ReactionTarget,
+            ReactionError,
+        },
+
+        issue::{HandleIssue},
+    },
 
     client::{
 
@@ -21,7 +30,17 @@ use crate::client::{
         Client,
     },
 
-    models::common::issue::comment::{Comment},
+    models::common::{
+
+        reaction::{
+
+            ReactionContent,
+            Reaction,
+        },
+
+        issue::comment::{Comment},
+        user::{User},
+    },
     
     GitHubProperties,
     GitHubEndpoint,
@@ -37,10 +56,61 @@ pub enum IssueCommentError {
     Client(#[from] ClientError),
     #[error("Failed to fetch issue comment author: '{author}'")]
     Author { author: String },
+    #[error("Reaction error!")]
+    Reaction(#[from] ReactionError),
     #[error("Issue comment not found: {number}")]
     Nothing { number: Number },
 }
 
+/// How an existing comment is changed by an upsert.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum UpsertMode {
+    /// Adds the body below the existing one.
+    Append,
+    /// Replaces the existing body.
+    #[default] Replace,
+    /// Deletes the comment and posts a new one, which moves it to the bottom.
+    Recreate,
+}
+
+/// What an upsert does, given the comments of the issue.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum UpsertAction {
+    Create { body: String },
+    Update { number: Number, body: String },
+    Recreate { number: Number, body: String },
+}
+
+fn get_upsert_action(comments: &[Comment], marker: &str, body: &str, mode: UpsertMode, author: Option<&str>) -> UpsertAction {
+    let marker = format!("<!-- {marker} -->");
+
+    let existing = comments.iter()
+        .filter(|comment| match author {
+            Some(author) => comment.author.get_name().eq_ignore_ascii_case(author),
+            None => true,
+        })
+        .find(|comment| comment.body.contains(marker.as_str()));
+
+    let Some(comment) = existing else {
+        return UpsertAction::Create { body: format!("{marker}\n{body}") }
+    };
+
+    match mode {
+        UpsertMode::Append => UpsertAction::Update {
+            body: format!("{existing}\n\n{body}", existing = comment.body),
+            number: comment.number,
+        },
+        UpsertMode::Replace => UpsertAction::Update {
+            body: format!("{marker}\n{body}"),
+            number: comment.number,
+        },
+        UpsertMode::Recreate => UpsertAction::Recreate {
+            body: format!("{marker}\n{body}"),
+            number: comment.number,
+        },
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct HandleIssueComment {
     issue: HandleIssue,
@@ -48,6 +118,13 @@ pub struct HandleIssueComment {
 }
 
 impl HandleIssueComment {
+    pub(crate) fn new(issue: &HandleIssue, number: impl Into<Number>) -> HandleIssueComment {
+        HandleIssueComment {
+            issue: issue.clone(),
+            number: number.into(),
+        }
+    }
+
     pub(crate) fn try_fetch(issue: &HandleIssue, number: impl Into<Number>) -> GitHubResult<HandleIssueComment, IssueCommentError> {
         let Comment { number, .. } = {
 
@@ -76,7 +153,7 @@ impl HandleIssueComment {
         })
     }
 
-    pub(crate) fn try_fetch_all(issue: &HandleIssue) -> GitHubResult<Vec<HandleIssueComment>, IssueCommentError> {
+    pub(crate) fn try_fetch_all_content(issue: &HandleIssue) -> GitHubResult<Vec<Comment>, IssueCommentError> {
         let repository = issue.get_parent();
 
         let mut collection = Vec::new();
@@ -95,7 +172,7 @@ impl HandleIssueComment {
                 let result = {
                     
                     repository.get_client()
-                        .get(format!("repos/{repository}/issues{issue}/comments"))?
+                        .get(format!("repos/{repository}/issues/{issue}/comments"))?
                         .query(query)
                         .send()
                 };
@@ -116,8 +193,12 @@ impl HandleIssueComment {
             }
         }
 
+        Ok(collection)
+    }
+
+    pub(crate) fn try_fetch_all(issue: &HandleIssue) -> GitHubResult<Vec<HandleIssueComment>, IssueCommentError> {
         let mut issues = Vec::new();
-        for Comment { number, .. } in collection {
+        for Comment { number, .. } in HandleIssueComment::try_fetch_all_content(issue)? {
             issues.push(HandleIssueComment {
                 issue: issue.clone(), number
             });
@@ -149,6 +230,28 @@ impl HandleIssueComment {
         })
     }
 
+    /// Updates the comment carrying the marker as a hidden HTML comment, or
+    /// creates one. With an author, comments of other users are ignored.
+    pub(crate) fn try_upsert(issue: &HandleIssue, marker: impl AsRef<str>, body: impl AsRef<str>, mode: UpsertMode, author: Option<&str>) -> GitHubResult<HandleIssueComment, IssueCommentError> {
+        let comments = HandleIssueComment::try_fetch_all_content(issue)?;
+
+        match get_upsert_action(comments.as_slice(), marker.as_ref(), body.as_ref(), mode, author) {
+            UpsertAction::Create { body } => {
+                HandleIssueComment::try_create(issue, body)
+            },
+            UpsertAction::Update { number, body } => {
+                let handle = HandleIssueComment::new(issue, number);
+                handle.try_update(body)?;
+
+                Ok(handle)
+            },
+            UpsertAction::Recreate { number, body } => {
+                HandleIssueComment::try_delete(issue, number)?;
+                HandleIssueComment::try_create(issue, body)
+            },
+        }
+    }
+
     pub(crate) fn try_delete(issue: &HandleIssue, number: impl Into<Number>) -> GitHubResult<(), IssueCommentError> {
         let repository = issue.get_parent();
         let number = number.into();
@@ -162,6 +265,48 @@ impl HandleIssueComment {
 
         Ok(())
     }
+
+    pub fn try_get_comment(&self) -> GitHubResult<Comment, IssueCommentError> {
+        Ok(self.get_client()
+            .get(self.get_endpoint())?
+            .send()?.json()?)
+    }
+
+    /// Replaces the body of the comment.
+    pub fn try_update(&self, body: impl AsRef<str>) -> GitHubResult<Comment, IssueCommentError> {
+        let payload = serde_json::json!({
+            "body": body.as_ref(),
+        });
+
+        Ok(self.get_client()
+            .patch(self.get_endpoint())?
+            .json(&payload)
+            .send()?.json()?)
+    }
+
+    pub fn get_reaction_target(&self) -> ReactionTarget {
+        ReactionTarget::IssueComment { comment: self.clone() }
+    }
+
+    pub fn try_get_reactions(&self, content: Option<ReactionContent>) -> GitHubResult<Vec<Reaction>, IssueCommentError> {
+        Ok(self.get_reaction_target().try_get_reactions(content)?)
+    }
+
+    pub fn try_add_reaction(&self, content: ReactionContent) -> GitHubResult<Reaction, IssueCommentError> {
+        Ok(self.get_reaction_target().try_add_reaction(content)?)
+    }
+
+    pub fn try_remove_reaction(&self, user: &User, content: ReactionContent) -> GitHubResult<(), IssueCommentError> {
+        Ok(self.get_reaction_target().try_remove_reaction(user, content)?)
+    }
+
+    pub fn try_delete_reaction(&self, reaction: impl Into<Number>) -> GitHubResult<(), IssueCommentError> {
+        Ok(self.get_reaction_target().try_delete_reaction(reaction)?)
+    }
+
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
 }
 
 impl<'a> GitHubEndpoint<'a> for HandleIssueComment {
@@ -205,3 +350,48 @@ impl FmtDisplay for HandleIssueComment {
         })
     }
 }
+
+
+#[cfg(test)]
+mod tests {
+
+    use crate::client::models::common::issue::comment::{Comment};
+
+    use super::{
+
+        get_upsert_action,
+        UpsertAction,
+        UpsertMode,
+    };
+
+    #[test]
+    fn test_upsert_action() {
+        let comments: Vec<Comment> = serde_json::from_value(serde_json::json!([
+            { "id": 1, "body": "<!-- coverage -->\nby someone else", "user": { "type": "User", "login": "octocat", "id": 1 } },
+            { "id": 2, "body": "<!-- coverage-report -->\nother marker", "user": { "type": "Bot", "login": "my-app[bot]", "id": 2 } },
+            { "id": 3, "body": "<!-- coverage -->\n80%", "user": { "type": "Bot", "login": "my-app[bot]", "id": 2 } },
+        ])).unwrap();
+
+        let author = Some("my-app[bot]");
+
+        assert_eq!(get_upsert_action(&comments, "coverage", "90%", UpsertMode::Replace, author), UpsertAction::Update {
+            number: 3, body: "<!-- coverage -->\n90%".to_owned()
+        });
+
+        assert_eq!(get_upsert_action(&comments, "coverage", "90%", UpsertMode::Append, author), UpsertAction::Update {
+            number: 3, body: "<!-- coverage -->\n80%\n\n90%".to_owned()
+        });
+
+        assert_eq!(get_upsert_action(&comments, "coverage", "90%", UpsertMode::Recreate, author), UpsertAction::Recreate {
+            number: 3, body: "<!-- coverage -->\n90%".to_owned()
+        });
+
+        assert_eq!(get_upsert_action(&comments, "coverage", "90%", UpsertMode::Replace, None), UpsertAction::Update {
+            number: 1, body: "<!-- coverage -->\n90%".to_owned()
+        });
+
+        assert_eq!(get_upsert_action(&comments, "cover", "90%", UpsertMode::Replace, author), UpsertAction::Create {
+            body: "<!-- cover -->\n90%".to_owned()
+        });
+    }
+}
@@ -20,30 +20,64 @@ use crate::client::{
     
                 IssueCommentError,
                 HandleIssueComment,
+                UpsertMode,
             },
         },
 
+        reaction::{
+
+            ReactionTarget,
+            ReactionError,
+        },
+
         HandleRepository,
     },
 
     client::{
 
+        ClientResponseError,
         ClientError,
         Client,
     },
     
     models::common::{
         
-        issue::{Issue},
+        issue::{
+
+            filter::{IssueFilter},
+
+            timeline::{
+
+                TimelineEvent,
+                IssueEvent,
+            },
+
+            IssueStateReason,
+            IssueContent,
+            LockReason,
+            IssueDraft,
+            Issue,
+        },
+
+        reaction::{
+
+            ReactionContent,
+            Reaction,
+        },
+
+        label::{Label},
         user::{User},
     },
 
+    common::{encode_segment},
+
     GitHubProperties,
     GitHubResult, 
     Number, GitHubEndpoint,
 };
 
 use serde::{Deserialize};
+use serde_json::{Value};
 
 use thiserror::{Error};
 
@@ -55,6 +89,8 @@ pub enum IssueError {
     Client(#[from] ClientError),
     #[error("Issue comment error!")]
     Comment(#[from] IssueCommentError),
+    #[error("Reaction error!")]
+    Reaction(#[from] ReactionError),
     #[error("Not an issue: {number}")]
     Issue { number: Number },
     #[error("Failed to fetch issue author: '{author}'")]
@@ -63,6 +99,32 @@ pub enum IssueError {
     Assignee { assignee: String },
 }
 
+fn get_update_payload(content: &IssueContent) -> Value {
+    let mut payload = serde_json::json!({
+        "title": content.title,
+        "body": content.body,
+        "state": content.state,
+    });
+
+    if let Some(ref reason) = content.state_reason {
+        payload["state_reason"] = serde_json::json!(reason);
+    }
+
+    if let Some(ref assignees) = content.assignees {
+        payload["assignees"] = assignees.iter()
+            .map(|assignee| assignee.get_name())
+            .collect();
+    }
+
+    if let Some(ref labels) = content.labels {
+        payload["labels"] = labels.iter()
+            .map(|label| label.get_name())
+            .collect();
+    }
+
+    payload
+}
+
 #[derive(Clone, Debug)]
 pub struct HandleIssue {
     repository: HandleRepository,
@@ -70,6 +132,14 @@ pub struct HandleIssue {
 }
 
 impl HandleIssue {
+    /// Handle to an issue or pull request known to exist, as given by an event.
+    pub(crate) fn new(repository: &HandleRepository, number: impl Into<Number>) -> HandleIssue {
+        HandleIssue {
+            repository: repository.clone(),
+            number: number.into(),
+        }
+    }
+
     pub(crate) fn try_fetch(repository: &HandleRepository, number: Number) -> GitHubResult<HandleIssue, IssueError> {
 
         #[derive(Debug)]
@@ -153,6 +223,150 @@ impl HandleIssue {
         Ok(issues)
     }
 
+    /// Lists the issues matching the filter, pull requests are left out.
+    pub(crate) fn try_fetch_filtered(repository: &HandleRepository, filter: &IssueFilter) -> GitHubResult<Vec<Issue>, IssueError> {
+        let query = filter.get_query();
+
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let issues: Vec<Issue> = {
+
+                repository.get_client()
+                    .get(format!("repos/{repository}/issues"))?
+                    .query(&[("per_page", 100), ("page", page)])
+                    .query(&query)
+                    .send()?.json()?
+            };
+
+            let count = issues.len();
+
+            collection.extend(issues.into_iter()
+                .filter(|issue| issue.is_plain()));
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub(crate) fn try_create(repository: &HandleRepository, draft: impl Into<IssueDraft>) -> GitHubResult<HandleIssue, IssueError> {
+        let draft = draft.into();
+
+        let issue: Issue = {
+
+            repository.get_client()
+                .post(format!("repos/{repository}/issues"))?
+                .json(&draft)
+                .send()?.json()?
+        };
+
+        Ok(HandleIssue {
+            repository: repository.clone(),
+            number: issue.get_number(),
+        })
+    }
+
+    pub fn try_get_issue(&self) -> GitHubResult<Issue, IssueError> {
+        Ok(self.get_client()
+            .get(self.get_endpoint())?
+            .send()?.json()?)
+    }
+
+    /// Writes the title, body, state, assignees and labels of the content to the
+    /// issue, assignees and labels are left alone when they were never set.
+    pub fn try_update(&self, content: &IssueContent) -> GitHubResult<Issue, IssueError> {
+        Ok(self.get_client()
+            .patch(self.get_endpoint())?
+            .json(&get_update_payload(content))
+            .send()?.json()?)
+    }
+
+    pub fn try_close(&self, reason: IssueStateReason) -> GitHubResult<Issue, IssueError> {
+        let payload = serde_json::json!({
+            "state": "closed",
+            "state_reason": reason,
+        });
+
+        Ok(self.get_client()
+            .patch(self.get_endpoint())?
+            .json(&payload)
+            .send()?.json()?)
+    }
+
+    pub fn try_reopen(&self) -> GitHubResult<Issue, IssueError> {
+        let payload = serde_json::json!({
+            "state": "open",
+            "state_reason": IssueStateReason::Reopened,
+        });
+
+        Ok(self.get_client()
+            .patch(self.get_endpoint())?
+            .json(&payload)
+            .send()?.json()?)
+    }
+
+    /// Locks the conversation so only collaborators can comment.
+    pub fn try_lock(&self, reason: Option<LockReason>) -> GitHubResult<(), IssueError> {
+        let endpoint = self.get_endpoint();
+
+        let mut payload = serde_json::json!({});
+
+        if let Some(reason) = reason {
+            payload["lock_reason"] = serde_json::json!(reason);
+        }
+
+        self.get_client()
+            .put(format!("{endpoint}/lock"))?
+            .json(&payload)
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_unlock(&self) -> GitHubResult<(), IssueError> {
+        let endpoint = self.get_endpoint();
+
+        self.get_client()
+            .delete(format!("{endpoint}/lock"))?
+            .send()?;
+
+        Ok(())
+    }
+
+    /// Sets the milestone of the issue, either a handle or a milestone number.
+    pub fn try_set_milestone(&self, milestone: impl Into<Number>) -> GitHubResult<(), IssueError> {
+        let payload = serde_json::json!({
+            "milestone": milestone.into(),
+        });
+
+        self.get_client()
+            .patch(self.get_endpoint())?
+            .json(&payload)
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_clear_milestone(&self) -> GitHubResult<(), IssueError> {
+        let payload = serde_json::json!({
+            "milestone": null,
+        });
+
+        self.get_client()
+            .patch(self.get_endpoint())?
+            .json(&payload)
+            .send()?;
+
+        Ok(())
+    }
+
     pub fn try_set_assignees<T: FmtDisplay>(&self, assignees: impl AsRef<[T]>) -> GitHubResult<(), IssueError> {
         let repository = self.get_parent();
 
@@ -171,6 +385,25 @@ impl HandleIssue {
         Ok(())
     }
 
+    pub fn try_remove_assignees<T: FmtDisplay>(&self, assignees: impl AsRef<[T]>) -> GitHubResult<(), IssueError> {
+        let endpoint = self.get_endpoint();
+
+        let assignees: Vec<String> = assignees.as_ref()
+            .iter().map(|assignee| assignee.to_string())
+            .collect();
+
+        let payload = serde_json::json!({
+            "assignees": assignees,
+        });
+
+        self.get_client()
+            .delete(format!("{endpoint}/assignees"))?
+            .json(&payload)
+            .send()?;
+
+        Ok(())
+    }
+
     pub fn try_get_assignees(&self) -> GitHubResult<Vec<User>, IssueError> {
         let repository = self.get_parent();
 
@@ -189,6 +422,192 @@ impl HandleIssue {
         Ok(assignees)
     }
 
+    pub fn try_get_labels(&self) -> GitHubResult<Vec<Label>, IssueError> {
+        let endpoint = self.get_endpoint();
+
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let labels: Vec<Label> = {
+
+                self.get_client()
+                    .get(format!("{endpoint}/labels"))?
+                    .query(&[("per_page", 100), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = labels.len();
+
+            collection.extend(labels);
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    /// Adds labels to the issue, labels missing from the repository are created.
+    pub fn try_add_labels<T: AsRef<str>>(&self, labels: impl AsRef<[T]>) -> GitHubResult<Vec<Label>, IssueError> {
+        let endpoint = self.get_endpoint();
+
+        let labels: Vec<&str> = labels.as_ref().iter()
+            .map(|label| label.as_ref())
+            .collect();
+
+        let payload = serde_json::json!({
+            "labels": labels,
+        });
+
+        Ok(self.get_client()
+            .post(format!("{endpoint}/labels"))?
+            .json(&payload)
+            .send()?.json()?)
+    }
+
+    /// Replaces all labels of the issue, an empty list removes them all.
+    pub fn try_set_labels<T: AsRef<str>>(&self, labels: impl AsRef<[T]>) -> GitHubResult<Vec<Label>, IssueError> {
+        let endpoint = self.get_endpoint();
+
+        let labels: Vec<&str> = labels.as_ref().iter()
+            .map(|label| label.as_ref())
+            .collect();
+
+        let payload = serde_json::json!({
+            "labels": labels,
+        });
+
+        Ok(self.get_client()
+            .put(format!("{endpoint}/labels"))?
+            .json(&payload)
+            .send()?.json()?)
+    }
+
+    /// Removes a label from the issue, returns whether the issue had the label.
+    pub fn try_remove_label(&self, label: impl AsRef<str>) -> GitHubResult<bool, IssueError> {
+        let endpoint = self.get_endpoint();
+        let label = encode_segment(label);
+
+        let result = {
+
+            self.get_client()
+                .delete(format!("{endpoint}/labels/{label}"))?
+                .send()
+        };
+
+        match result {
+            Err(ClientError::Response(ClientResponseError::Nothing { .. })) => Ok(false),
+            Err(error) => Err(error.into()),
+            Ok(_) => Ok(true),
+        }
+    }
+
+    pub fn try_clear_labels(&self) -> GitHubResult<(), IssueError> {
+        let endpoint = self.get_endpoint();
+
+        self.get_client()
+            .delete(format!("{endpoint}/labels"))?
+            .send()?;
+
+        Ok(())
+    }
+
+    /// Gets everything that happened to the issue in order, including comments.
+    pub fn try_get_timeline(&self) -> GitHubResult<Vec<TimelineEvent>, IssueError> {
+        let endpoint = self.get_endpoint();
+
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let events: Vec<TimelineEvent> = {
+
+                self.get_client()
+                    .get(format!("{endpoint}/timeline"))?
+                    .query(&[("per_page", 100), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = events.len();
+
+            collection.extend(events);
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    /// Gets the events of the issue, unlike the timeline without comments and cross references.
+    pub fn try_get_events(&self) -> GitHubResult<Vec<IssueEvent>, IssueError> {
+        let endpoint = self.get_endpoint();
+
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let events: Vec<IssueEvent> = {
+
+                self.get_client()
+                    .get(format!("{endpoint}/events"))?
+                    .query(&[("per_page", 100), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = events.len();
+
+            collection.extend(events);
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    /// Gets the events of all issues of a repository, newest first.
+    pub(crate) fn try_fetch_events(repository: &HandleRepository) -> GitHubResult<Vec<IssueEvent>, IssueError> {
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let events: Vec<IssueEvent> = {
+
+                repository.get_client()
+                    .get(format!("repos/{repository}/issues/events"))?
+                    .query(&[("per_page", 100), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = events.len();
+
+            collection.extend(events);
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
     pub fn try_get_comment(&self, number: Number) -> GitHubResult<HandleIssueComment, IssueError>
    {
         Ok(HandleIssueComment::try_fetch(self, number)?)
@@ -218,9 +637,41 @@ impl HandleIssue {
         Ok(HandleIssueComment::try_create(self, content.as_ref())?)
     }
 
+    /// Keeps a single comment up to date across runs, the comment is found by
+    /// a hidden `<!-- marker -->` and its body replaced.
+    pub fn try_upsert_comment(&self, marker: impl AsRef<str>, body: impl AsRef<str>) -> GitHubResult<HandleIssueComment, IssueError> {
+        Ok(HandleIssueComment::try_upsert(self, marker, body, UpsertMode::Replace, None)?)
+    }
+
+    /// Like `try_upsert_comment`, with an author comments by other users are
+    /// never touched. Workflows using `GITHUB_TOKEN` comment as `github-actions[bot]`.
+    pub fn try_upsert_comment_with(&self, marker: impl AsRef<str>, body: impl AsRef<str>, mode: UpsertMode, author: Option<&str>) -> GitHubResult<HandleIssueComment, IssueError> {
+        Ok(HandleIssueComment::try_upsert(self, marker, body, mode, author)?)
+    }
+
     pub fn try_delete_comment(&self, number: impl Into<Number>) -> GitHubResult<(), IssueError> {
         Ok(HandleIssueComment::try_delete(self, number)?)
     }
+
+    pub fn get_reaction_target(&self) -> ReactionTarget {
+        ReactionTarget::Issue { issue: self.clone() }
+    }
+
+    pub fn try_get_reactions(&self, content: Option<ReactionContent>) -> GitHubResult<Vec<Reaction>, IssueError> {
+        Ok(self.get_reaction_target().try_get_reactions(content)?)
+    }
+
+    pub fn try_add_reaction(&self, content: ReactionContent) -> GitHubResult<Reaction, IssueError> {
+        Ok(self.get_reaction_target().try_add_reaction(content)?)
+    }
+
+    pub fn try_remove_reaction(&self, user: &User, content: ReactionContent) -> GitHubResult<(), IssueError> {
+        Ok(self.get_reaction_target().try_remove_reaction(user, content)?)
+    }
+
+    pub fn try_delete_reaction(&self, reaction: impl Into<Number>) -> GitHubResult<(), IssueError> {
+        Ok(self.get_reaction_target().try_delete_reaction(reaction)?)
+    }
 }
 
 impl Into<Number> for &HandleIssue {
@@ -263,3 +714,37 @@ impl FmtDisplay for HandleIssue {
         })
     }
 }
+
+
+#[cfg(test)]
+mod tests {
+
+    use crate::client::models::common::issue::{
+
+        IssueStateReason,
+        Issue,
+    };
+
+    use super::{get_update_payload};
+
+    #[test]
+    fn test_update_payload() {
+        let Issue::Plain(content) = serde_json::from_str(include_str!("../../models/common/issue/test_data/plain.json")).unwrap() else {
+            panic!("expected a plain issue")
+        };
+
+        let closed = content.as_closed_as(IssueStateReason::Duplicate);
+        let payload = get_update_payload(&closed);
+
+        assert_eq!(payload["state"], "closed");
+        assert_eq!(payload["state_reason"], "duplicate");
+
+        let payload = get_update_payload(&closed.as_open());
+
+        assert_eq!(payload["state"], "open");
+        assert!(payload.get("state_reason").is_none());
+
+        let reason: IssueStateReason = serde_json::from_str(r#""superseded""#).unwrap();
+        assert_eq!(serde_json::to_string(&reason).unwrap(), r#""superseded""#);
+    }
+}
@@ -0,0 +1,434 @@
+use std::{
+
+    borrow::{Cow},
+
+    path::{Path},
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+};
+
+use serde::{Deserialize};
+use thiserror::{Error};
+
+use crate::client::{
+
+    repository::{HandleRepository},
+
+    client::{
+
+        ClientResponseError,
+        ClientError,
+        Client,
+    },
+
+    models::common::label::{
+
+        LabelDefinition,
+        Label,
+    },
+
+    common::{encode_segment},
+
+    GitHubProperties,
+    GitHubEndpoint,
+    GitHubResult,
+    Number,
+};
+
+#[derive(Error, Debug)]
+pub enum LabelError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Failed to read label definitions!")]
+    File(#[from] std::io::Error),
+    #[error("Failed to parse label definitions!")]
+    Definition(#[from] serde_yaml::Error),
+    #[error("Label defined more than once: '{name}'")]
+    Duplicate { name: String },
+    #[error("Label not found: '{name}'")]
+    Nothing { name: String },
+}
+
+/// Outcome of synchronizing labels, renamed and merged labels are recorded as `(from, to)`.
+#[derive(Clone, Debug, Default)]
+pub struct LabelReport {
+    pub(crate) created: Vec<String>,
+    pub(crate) updated: Vec<String>,
+    pub(crate) renamed: Vec<(String, String)>,
+    pub(crate) merged: Vec<(String, String)>,
+    pub(crate) deleted: Vec<String>,
+    pub(crate) unchanged: Vec<String>,
+}
+
+impl LabelReport {
+    pub fn get_created(&self) -> &[String] {
+        self.created.as_slice()
+    }
+
+    pub fn get_updated(&self) -> &[String] {
+        self.updated.as_slice()
+    }
+
+    pub fn get_renamed(&self) -> &[(String, String)] {
+        self.renamed.as_slice()
+    }
+
+    /// Alias labels whose issues were moved to the existing label of the definition.
+    pub fn get_merged(&self) -> &[(String, String)] {
+        self.merged.as_slice()
+    }
+
+    pub fn get_deleted(&self) -> &[String] {
+        self.deleted.as_slice()
+    }
+
+    pub fn get_unchanged(&self) -> &[String] {
+        self.unchanged.as_slice()
+    }
+
+    pub fn has_changes(&self) -> bool {
+        !(self.created.is_empty() && self.updated.is_empty()
+            && self.renamed.is_empty() && self.merged.is_empty() && self.deleted.is_empty())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleLabel {
+    repository: HandleRepository,
+    name: String,
+}
+
+impl HandleLabel {
+    pub(crate) fn try_fetch(repository: &HandleRepository, name: impl AsRef<str>) -> GitHubResult<HandleLabel, LabelError> {
+        let name = name.as_ref();
+
+        let Label { name, .. } = {
+
+            let result = {
+
+                repository.get_client()
+                    .get(format!("repos/{repository}/labels/{name}", name = encode_segment(name)))?
+                    .send()
+            };
+
+            match result {
+                Err(ClientError::Response(ClientResponseError::Nothing { .. })) => {
+                    return Err(LabelError::Nothing { name: name.to_owned() })
+                },
+                Err(error) => return Err(error.into()),
+                Ok(response) => response.json()?,
+            }
+        };
+
+        Ok(HandleLabel {
+            repository: repository.clone(),
+            name,
+        })
+    }
+
+    pub(crate) fn try_fetch_all_content(repository: &HandleRepository) -> GitHubResult<Vec<Label>, LabelError> {
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let labels: Vec<Label> = {
+
+                repository.get_client()
+                    .get(format!("repos/{repository}/labels"))?
+                    .query(&[("per_page", 100), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = labels.len();
+
+            collection.extend(labels);
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub(crate) fn try_fetch_all(repository: &HandleRepository) -> GitHubResult<Vec<HandleLabel>, LabelError> {
+        Ok(HandleLabel::try_fetch_all_content(repository)?.into_iter()
+            .map(|Label { name, .. }| HandleLabel {
+                repository: repository.clone(),
+                name,
+            }).collect())
+    }
+
+    pub(crate) fn try_create(repository: &HandleRepository, name: impl AsRef<str>, color: impl AsRef<str>, description: Option<&str>) -> GitHubResult<HandleLabel, LabelError> {
+        let mut payload = serde_json::json!({
+            "name": name.as_ref(),
+            "color": color.as_ref().trim_start_matches('#'),
+        });
+
+        if let Some(description) = description {
+            payload["description"] = description.into();
+        }
+
+        let Label { name, .. } = {
+
+            repository.get_client()
+                .post(format!("repos/{repository}/labels"))?
+                .json(&payload)
+                .send()?.json()?
+        };
+
+        Ok(HandleLabel {
+            repository: repository.clone(),
+            name,
+        })
+    }
+
+    /// Makes the labels of a repository match the definitions. Labels named by an
+    /// alias are renamed rather than recreated so issues keep them, with `prune`
+    /// labels without a definition are deleted. When both an alias and the label
+    /// itself exist, pruning first moves the issues of the alias over.
+    pub(crate) fn try_sync(repository: &HandleRepository, definitions: impl AsRef<[LabelDefinition]>, prune: bool) -> GitHubResult<LabelReport, LabelError> {
+        let definitions = definitions.as_ref();
+
+        for (index, definition) in definitions.iter().enumerate() {
+            let duplicate = definitions[..index].iter().any(|other| {
+                other.name.eq_ignore_ascii_case(definition.name.as_str())
+            });
+
+            if duplicate {
+                return Err(LabelError::Duplicate {
+                    name: definition.get_name()
+                })
+            }
+        }
+
+        let mut existing = HandleLabel::try_fetch_all_content(repository)?;
+        let mut report = LabelReport::default();
+
+        for definition in definitions {
+            // An empty description clears the one of the label.
+            let description = Some(definition.description.as_deref().unwrap_or_default());
+            let color = definition.get_color();
+
+            // Label names are case insensitive, an exact match wins over an alias.
+            let position = existing.iter().position(|label| {
+                label.name.eq_ignore_ascii_case(definition.name.as_str())
+            }).or_else(|| existing.iter().position(|label| {
+                definition.aliases.iter().any(|alias| label.name.eq_ignore_ascii_case(alias))
+            }));
+
+            let Some(position) = position else {
+                HandleLabel::try_create(repository, definition.name.as_str(), color.as_str(), description)?;
+                report.created.push(definition.get_name());
+
+                continue
+            };
+
+            let label = existing.remove(position);
+
+            let (aliases, rest): (Vec<Label>, Vec<Label>) = existing.into_iter().partition(|label| {
+                definition.aliases.iter().any(|alias| label.name.eq_ignore_ascii_case(alias))
+            });
+
+            existing = rest;
+
+            let handle = HandleLabel {
+                repository: repository.clone(),
+                name: label.get_name(),
+            };
+
+            if label.name != definition.name {
+                handle.try_update(Some(definition.name.as_str()), Some(color.as_str()), description)?;
+                report.renamed.push((label.get_name(), definition.get_name()));
+            }
+
+            else if !(definition.is_satisfied_by(&label)) {
+                handle.try_update(None, Some(color.as_str()), description)?;
+                report.updated.push(definition.get_name());
+            }
+
+            else {
+                report.unchanged.push(definition.get_name());
+            }
+
+            if prune {
+                for alias in aliases {
+                    HandleLabel::try_move_issues(repository, alias.name.as_str(), definition.name.as_str())?;
+
+                    HandleLabel {
+                        repository: repository.clone(),
+                        name: alias.get_name(),
+                    }.try_delete()?;
+
+                    report.merged.push((alias.get_name(), definition.get_name()));
+                }
+            }
+        }
+
+        if prune {
+            for label in existing {
+                HandleLabel {
+                    repository: repository.clone(),
+                    name: label.get_name(),
+                }.try_delete()?;
+
+                report.deleted.push(label.get_name());
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Adds the `to` label to every issue and pull request labeled `from`.
+    fn try_move_issues(repository: &HandleRepository, from: &str, to: &str) -> GitHubResult<(), LabelError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleLabel { name: String }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule { number: Number, labels: Vec<CapsuleLabel> }
+
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let issues: Vec<Capsule> = {
+
+                let mut request = repository.get_client()
+                    .get(format!("repos/{repository}/issues"))?
+                    .query(&[("state", "all")])
+                    .query(&[("per_page", 100), ("page", page)]);
+
+                // GitHub splits the label filter on commas, such names
+                // are only matched below.
+                if !(from.contains(',')) {
+                    request = request.query(&[("labels", from)]);
+                }
+
+                request.send()?.json()?
+            };
+
+            let count = issues.len();
+
+            collection.extend(issues.into_iter().filter(|issue| {
+                issue.labels.iter().any(|label| label.name.eq_ignore_ascii_case(from))
+            }));
+
+            if count < 100 {
+                break
+            }
+        }
+
+        for Capsule { number, .. } in collection {
+            repository.get_client()
+                .post(format!("repos/{repository}/issues/{number}/labels"))?
+                .json(&serde_json::json!({ "labels": [to] }))
+                .send()?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads label definitions from a YAML or JSON file holding a list of labels.
+    pub fn try_read_definitions(path: impl AsRef<Path>) -> GitHubResult<Vec<LabelDefinition>, LabelError> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(serde_yaml::from_str(content.as_str())?)
+    }
+
+    pub fn try_get_label(&self) -> GitHubResult<Label, LabelError> {
+        Ok(self.get_client()
+            .get(self.get_endpoint())?
+            .send()?.json()?)
+    }
+
+    /// Updates the given properties of the label, renaming keeps it on its issues.
+    pub fn try_update(&self, name: Option<&str>, color: Option<&str>, description: Option<&str>) -> GitHubResult<HandleLabel, LabelError> {
+        let mut payload = serde_json::json!({});
+
+        if let Some(name) = name {
+            payload["new_name"] = name.into();
+        }
+
+        if let Some(color) = color {
+            payload["color"] = color.trim_start_matches('#').into();
+        }
+
+        if let Some(description) = description {
+            payload["description"] = description.into();
+        }
+
+        let Label { name, .. } = {
+
+            self.get_client()
+                .patch(self.get_endpoint())?
+                .json(&payload)
+                .send()?.json()?
+        };
+
+        Ok(HandleLabel {
+            repository: self.repository.clone(),
+            name,
+        })
+    }
+
+    pub fn try_rename(&self, name: impl AsRef<str>) -> GitHubResult<HandleLabel, LabelError> {
+        self.try_update(Some(name.as_ref()), None, None)
+    }
+
+    pub fn try_delete(&self) -> GitHubResult<(), LabelError> {
+        self.get_client()
+            .delete(self.get_endpoint())?
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl AsRef<str> for HandleLabel {
+    fn as_ref(&self) -> &str {
+        self.name.as_ref()
+    }
+}
+
+impl<'a> GitHubEndpoint<'a> for HandleLabel {
+    fn get_endpoint(&'a self) -> Cow<'a, str> {
+        let HandleLabel { repository, name } = { self };
+        format!("repos/{repository}/labels/{name}", name = encode_segment(name)).into()
+    }
+}
+
+impl<'a> GitHubProperties<'a> for HandleLabel {
+    type Content = Label;
+    type Parent = HandleRepository;
+
+    fn get_client(&'a self) -> &'a Client {
+        self.get_parent()
+            .get_client()
+    }
+
+    fn get_parent(&'a self) -> &'a Self::Parent {
+        &(self.repository)
+    }
+}
+
+impl FmtDisplay for HandleLabel {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        write!(fmt, "{name}", name = self.name)
+    }
+}
@@ -0,0 +1,231 @@
+use std::{
+
+    borrow::{Cow},
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+};
+
+use thiserror::{Error};
+
+use crate::client::{
+
+    repository::{HandleRepository},
+
+    client::{
+
+        ClientResponseError,
+        ClientError,
+        Client,
+    },
+
+    models::common::{
+
+        issue::{
+
+            filter::{
+
+                IssueStateFilter,
+                SortDirection,
+            },
+
+            IssueState,
+        },
+
+        milestone::{
+
+            MilestoneContent,
+            MilestoneSort,
+            Milestone,
+        },
+    },
+
+    GitHubProperties,
+    GitHubEndpoint,
+    GitHubResult,
+    Number,
+};
+
+#[derive(Error, Debug)]
+pub enum MilestoneError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Milestone not found: {number}")]
+    Nothing { number: Number },
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleMilestone {
+    repository: HandleRepository,
+    number: Number,
+}
+
+impl HandleMilestone {
+    pub(crate) fn try_fetch(repository: &HandleRepository, number: impl Into<Number>) -> GitHubResult<HandleMilestone, MilestoneError> {
+        let number = number.into();
+
+        let Milestone { number, .. } = {
+
+            let result = {
+
+                repository.get_client()
+                    .get(format!("repos/{repository}/milestones/{number}"))?
+                    .send()
+            };
+
+            match result {
+                Err(ClientError::Response(ClientResponseError::Nothing { .. })) => {
+                    return Err(MilestoneError::Nothing { number })
+                },
+                Err(error) => return Err(error.into()),
+                Ok(response) => response.json()?,
+            }
+        };
+
+        Ok(HandleMilestone {
+            repository: repository.clone(),
+            number,
+        })
+    }
+
+    /// Lists milestones with their issue counts.
+    pub(crate) fn try_fetch_all_content(repository: &HandleRepository, state: IssueStateFilter, sort: MilestoneSort, direction: SortDirection) -> GitHubResult<Vec<Milestone>, MilestoneError> {
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let milestones: Vec<Milestone> = {
+
+                repository.get_client()
+                    .get(format!("repos/{repository}/milestones"))?
+                    .query(&[("per_page", 100), ("page", page)])
+                    .query(&[
+                        ("state", state.as_str()),
+                        ("sort", sort.as_str()),
+                        ("direction", direction.as_str()),
+                    ])
+                    .send()?.json()?
+            };
+
+            let count = milestones.len();
+
+            collection.extend(milestones);
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub(crate) fn try_fetch_all(repository: &HandleRepository) -> GitHubResult<Vec<HandleMilestone>, MilestoneError> {
+        let milestones = {
+            HandleMilestone::try_fetch_all_content(repository, IssueStateFilter::All, MilestoneSort::default(), SortDirection::Ascending)?
+        };
+
+        Ok(milestones.into_iter()
+            .map(|Milestone { number, .. }| HandleMilestone {
+                repository: repository.clone(),
+                number,
+            }).collect())
+    }
+
+    pub(crate) fn try_create(repository: &HandleRepository, content: impl Into<MilestoneContent>) -> GitHubResult<HandleMilestone, MilestoneError> {
+        let content = content.into();
+
+        let Milestone { number, .. } = {
+
+            repository.get_client()
+                .post(format!("repos/{repository}/milestones"))?
+                .json(&content)
+                .send()?.json()?
+        };
+
+        Ok(HandleMilestone {
+            repository: repository.clone(),
+            number,
+        })
+    }
+
+    pub fn try_get_milestone(&self) -> GitHubResult<Milestone, MilestoneError> {
+        Ok(self.get_client()
+            .get(self.get_endpoint())?
+            .send()?.json()?)
+    }
+
+    /// Updates the fields set in the content.
+    pub fn try_update(&self, content: &MilestoneContent) -> GitHubResult<Milestone, MilestoneError> {
+        Ok(self.get_client()
+            .patch(self.get_endpoint())?
+            .json(content)
+            .send()?.json()?)
+    }
+
+    pub fn try_close(&self) -> GitHubResult<Milestone, MilestoneError> {
+        self.try_update(&MilestoneContent::default().with_state(IssueState::Closed))
+    }
+
+    pub fn try_reopen(&self) -> GitHubResult<Milestone, MilestoneError> {
+        self.try_update(&MilestoneContent::default().with_state(IssueState::Open))
+    }
+
+    /// Deletes the milestone, its issues are kept without a milestone.
+    pub fn try_delete(&self) -> GitHubResult<(), MilestoneError> {
+        self.get_client()
+            .delete(self.get_endpoint())?
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+}
+
+impl From<&HandleMilestone> for Number {
+    fn from(milestone: &HandleMilestone) -> Number {
+        milestone.number
+    }
+}
+
+impl From<HandleMilestone> for Number {
+    fn from(milestone: HandleMilestone) -> Number {
+        milestone.number
+    }
+}
+
+impl<'a> GitHubEndpoint<'a> for HandleMilestone {
+    fn get_endpoint(&'a self) -> Cow<'a, str> {
+        let HandleMilestone { repository, number } = { self };
+        format!("repos/{repository}/milestones/{number}").into()
+    }
+}
+
+impl<'a> GitHubProperties<'a> for HandleMilestone {
+    type Content = Milestone;
+    type Parent = HandleRepository;
+
+    fn get_client(&'a self) -> &'a Client {
+        self.get_parent()
+            .get_client()
+    }
+
+    fn get_parent(&'a self) -> &'a Self::Parent {
+        &(self.repository)
+    }
+}
+
+impl FmtDisplay for HandleMilestone {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        write!(fmt, "{number}", number = self.number)
+    }
+}
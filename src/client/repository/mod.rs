@@ -1,6 +1,7 @@
 use std::{
 
     borrow::{Cow}, 
+    time::{Duration},
 
     fmt::{
     
@@ -12,8 +13,41 @@ use std::{
 
 use crate::client::{
 
-    client::{Client, ClientError, ClientResponseError},
-    account::{Account},
+    client::{Client, ClientError, ClientResponseError, GitHubResponse},
+    account::{Account, organization::{HandleOrganization}},
+
+    actions::{
+
+        variable::{
+
+            VariableReport,
+            VariableError,
+            HandleVariable,
+        },
+
+        secret::{
+
+            SecretError,
+            SecretValue,
+            HandleSecret,
+        },
+
+        ActionsScope,
+    },
+
+    ruleset::{
+
+        RulesetScope,
+        RulesetError,
+        HandleRuleset,
+    },
+
+    hook::{
+
+        HookScope,
+        HookError,
+        HandleHook,
+    },
     
     repository::{
 
@@ -29,11 +63,39 @@ use crate::client::{
             HandleCommit,
         },
 
+        deployment::{
+
+            DeploymentReference,
+            DeploymentError,
+            HandleDeployment,
+        },
+
+        environment::{
+
+            EnvironmentError,
+            HandleEnvironment,
+        },
+
         issue::{
 
             IssueError,
             HandleIssue,
         },
+
+        label::{
+
+            LabelReport,
+            LabelError,
+            HandleLabel,
+        },
+
+        milestone::{
+
+            MilestoneError,
+            HandleMilestone,
+        },
+
+        reaction::{ReactionTarget},
         
         tree::{
     
@@ -51,7 +113,71 @@ use crate::client::{
         sha::{Sha},
     }, 
     
-    models::common::repository::{Repository},
+    models::common::{
+
+        deployment::{
+
+            DeploymentContent,
+            PendingDeployment,
+            ReviewState,
+            Deployment,
+        },
+        environment::{EnvironmentContent},
+        permission::{Permission},
+
+        collaborator::{
+
+            CollaboratorAffiliation,
+            CollaboratorPermission,
+            RepositoryInvitation,
+            Collaborator,
+        },
+
+        repository::{
+
+            RepositoryDraft,
+            TemplateDraft,
+            Repository,
+        },
+
+        user::{User},
+
+        issue::{
+
+            filter::{
+
+                IssueStateFilter,
+                SortDirection,
+                IssueFilter,
+            },
+
+            timeline::{IssueEvent},
+
+            IssueDraft,
+            Issue,
+        },
+
+        milestone::{
+
+            MilestoneContent,
+            MilestoneSort,
+            Milestone,
+        },
+
+        ruleset::{
+
+            BranchRule,
+            Ruleset,
+        },
+
+        hook::{Hook},
+
+        label::{
+
+            LabelDefinition,
+            Label,
+        },
+    },
     
     GitHubProperties,
 };
@@ -62,19 +188,26 @@ use serde::{
     Serialize,
 };
 
+use serde_json::{Value};
 use thiserror::{Error};
 use zip::result::{ZipError};
 
 pub mod properties;
 pub mod reference;
+pub mod deployment;
+pub mod environment;
 pub mod commit;
 pub mod issue;
+pub mod label;
+pub mod milestone;
+pub mod reaction;
 pub mod tree;
 pub mod blob;
 pub mod sha;
 
-use crate::client::{GitHubResult};
+use crate::client::{GitHubResult, Number};
 
+use self::properties::{RepositoryUpdate};
 use super::{GitHubEndpoint};
 
 #[derive(Clone, Debug)]
@@ -147,6 +280,22 @@ pub enum HandleRepositoryError {
     Commit(#[from] CommitError),
     #[error("Issue error!")]
     Issue(#[from] IssueError),
+    #[error("Label error!")]
+    Label(#[from] LabelError),
+    #[error("Milestone error!")]
+    Milestone(#[from] MilestoneError),
+    #[error("Deployment error!")]
+    Deployment(#[from] DeploymentError),
+    #[error("Environment error!")]
+    Environment(#[from] EnvironmentError),
+    #[error("Ruleset error!")]
+    Ruleset(#[from] RulesetError),
+    #[error("Hook error!")]
+    Hook(#[from] HookError),
+    #[error("Secret error!")]
+    Secret(#[from] SecretError),
+    #[error("Variable error!")]
+    Variable(#[from] VariableError),
     #[error("Blob error!")]
     Blob(#[from] BlobError),
     #[error("Tree error!")]
@@ -163,6 +312,14 @@ pub enum HandleRepositoryError {
     Archive(#[from] ZipError),
     #[error("Repository not found: '{name}'")]
     Nothing { name: String },
+    #[error("Not a template repository: '{name}'")]
+    Template { name: String },
+    #[error("Deletion of '{name}' not confirmed, got: '{confirmation}'")]
+    Confirmation { name: String, confirmation: String },
+    #[error("Repository not ready in time: '{name}'")]
+    Timeout { name: String },
+    #[error("Repositories can only be created for the authenticated user, not: '{owner}'")]
+    Owner { owner: String },
 }
 
 #[derive(Clone, Debug)]
@@ -245,6 +402,493 @@ impl HandleRepository {
         }).collect())
     }
 
+    /// Reads the handle of the repository a response describes, such as a new
+    /// repository or fork.
+    fn try_from_response(client: &Client, response: GitHubResponse) -> GitHubResult<HandleRepository, HandleRepositoryError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            name: String,
+            owner: User,
+        }
+
+        let Capsule { name, owner } = response.json()?;
+
+        let Some(owner) = Account::from_user(client, &owner) else {
+            return Err(HandleRepositoryError::Nothing { name })
+        };
+
+        Ok(HandleRepository {
+            name: name.to_lowercase(),
+            owner,
+        })
+    }
+
+    /// Creates a repository, users can only create repositories for themselves.
+    pub(crate) fn try_create(owner: &Account, draft: &RepositoryDraft) -> GitHubResult<HandleRepository, HandleRepositoryError> {
+        let endpoint = match owner {
+            Account::Organization(organization) => format!("orgs/{organization}/repos"),
+            Account::User(user) => {
+                let authenticated = owner.get_client()
+                    .try_get_authenticated_user()?;
+
+                if !authenticated.get_name().eq_ignore_ascii_case(&user.to_string()) {
+                    return Err(HandleRepositoryError::Owner {
+                        owner: user.to_string()
+                    })
+                }
+
+                "user/repos".to_owned()
+            },
+        };
+
+        let response = {
+
+            owner.get_client()
+                .post(endpoint)?
+                .json(draft)
+                .send()?
+        };
+
+        HandleRepository::try_from_response(owner.get_client(), response)
+    }
+
+    /// Whether the repository can be used with `try_generate`, the same flag as
+    /// `RepositoryProperties::has_template`.
+    pub fn try_is_template(&self) -> GitHubResult<bool, HandleRepositoryError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            is_template: bool,
+        }
+
+        let Capsule { is_template } = {
+
+            self.get_client()
+                .get(self.get_endpoint())?
+                .send()?.json()?
+        };
+
+        Ok(is_template)
+    }
+
+    /// Creates a repository from the content of this template repository.
+    pub fn try_generate(&self, draft: &TemplateDraft) -> GitHubResult<HandleRepository, HandleRepositoryError> {
+        if !(self.try_is_template()?) {
+            return Err(HandleRepositoryError::Template {
+                name: self.to_string()
+            })
+        }
+
+        let response = {
+
+            self.get_client()
+                .post(format!("repos/{self}/generate"))?
+                .json(draft)
+                .send()?
+        };
+
+        HandleRepository::try_from_response(self.get_client(), response)
+    }
+
+    /// Forks the repository into the authenticated user's account or into an
+    /// organization. Forking happens in the background, see `try_wait_ready`.
+    pub fn try_fork(&self, organization: Option<&HandleOrganization>, name: Option<&str>) -> GitHubResult<HandleRepository, HandleRepositoryError> {
+        let mut payload = serde_json::json!({});
+
+        if let Some(organization) = organization {
+            payload["organization"] = organization.to_string().into();
+        }
+
+        if let Some(name) = name {
+            payload["name"] = name.into();
+        }
+
+        let response = {
+
+            self.get_client()
+                .post(format!("repos/{self}/forks"))?
+                .json(&payload)
+                .send()?
+        };
+
+        HandleRepository::try_from_response(self.get_client(), response)
+    }
+
+    /// Waits until the commits of the repository can be read, such as for a new fork.
+    pub fn try_wait_ready(&self, timeout: Duration) -> GitHubResult<(), HandleRepositoryError> {
+        let start = std::time::Instant::now();
+        let mut delay = Duration::from_secs(1);
+
+        loop {
+
+            let result = {
+
+                self.get_client()
+                    .get(format!("repos/{self}/commits"))?
+                    .query(&[("per_page", 1)])
+                    .send()
+            };
+
+            match result {
+                Err(ClientError::Response(ClientResponseError::Nothing { .. })) |
+                Err(ClientError::Response(ClientResponseError::Unhandled { code: 409, .. })) => {},
+                Err(error) => return Err(error.into()),
+                Ok(_) => return Ok(()),
+            }
+
+            if start.elapsed() + delay > timeout {
+                return Err(HandleRepositoryError::Timeout {
+                    name: self.to_string()
+                })
+            }
+
+            std::thread::sleep(delay);
+
+            delay = (delay * 2).min(Duration::from_secs(15));
+        }
+    }
+
+    /// Transfers the repository to another user or organization, optionally
+    /// renaming it. The new owner may have to accept the transfer first.
+    pub fn try_transfer(&self, owner: impl FmtDisplay, name: Option<&str>) -> GitHubResult<HandleRepository, HandleRepositoryError> {
+        let mut payload = serde_json::json!({ "new_owner": owner.to_string() });
+
+        if let Some(name) = name {
+            payload["new_name"] = name.into();
+        }
+
+        self.get_client()
+            .post(format!("repos/{self}/transfer"))?
+            .json(&payload)
+            .send()?;
+
+        // The response still names the former owner, the transfer runs in the background.
+        let user: User = {
+
+            self.get_client()
+                .get(format!("users/{owner}"))?
+                .send()?.json()?
+        };
+
+        let name = name.map(|name| name.to_lowercase())
+            .unwrap_or_else(|| self.name.to_lowercase());
+
+        let Some(owner) = Account::from_user(self.get_client(), &user) else {
+            return Err(HandleRepositoryError::Nothing { name })
+        };
+
+        Ok(HandleRepository { owner, name })
+    }
+
+    /// Renames the repository, the returned handle uses the new name.
+    pub fn try_rename(&self, name: impl AsRef<str>) -> GitHubResult<HandleRepository, HandleRepositoryError> {
+        let payload = serde_json::json!({ "name": name.as_ref() });
+
+        let response = {
+
+            self.get_client()
+                .patch(self.get_endpoint())?
+                .json(&payload)
+                .send()?
+        };
+
+        HandleRepository::try_from_response(self.get_client(), response)
+    }
+
+    /// Makes the repository read-only.
+    pub fn try_archive(&self) -> GitHubResult<(), HandleRepositoryError> {
+        self.try_set_properties(serde_json::json!({ "archived": true }))?;
+        Ok(())
+    }
+
+    pub fn try_unarchive(&self) -> GitHubResult<(), HandleRepositoryError> {
+        self.try_set_properties(serde_json::json!({ "archived": false }))?;
+        Ok(())
+    }
+
+    /// Applies the settings which differ from the current ones, and returns
+    /// the names of those changed. Nothing is sent when all already match.
+    pub fn try_update_settings(&self, update: &RepositoryUpdate) -> GitHubResult<Vec<String>, HandleRepositoryError> {
+        if update.is_empty() {
+            return Ok(Vec::new())
+        }
+
+        let current: Value = {
+
+            self.get_client()
+                .get(self.get_endpoint())?
+                .send()?.json()?
+        };
+
+        let mut changes = update.get_changes(&current);
+        let mut changed: Vec<String> = changes.keys().cloned().collect();
+
+        // An archived repository is read-only, so unarchiving has to come
+        // first and archiving last, each on its own.
+        let archived = changes.remove("archived");
+
+        if let Some(Value::Bool(false)) = archived {
+            self.try_unarchive()?;
+        }
+
+        if !(changes.is_empty()) {
+            self.try_set_properties(changes)?;
+        }
+
+        if let Some(topics) = update.get_topic_changes(&current) {
+            self.try_set_topics(topics)?;
+            changed.push("topics".to_owned());
+        }
+
+        if let Some(Value::Bool(true)) = archived {
+            self.try_archive()?;
+        }
+
+        Ok(changed)
+    }
+
+    pub fn try_get_topics(&self) -> GitHubResult<Vec<String>, HandleRepositoryError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule { names: Vec<String> }
+
+        let Capsule { names } = {
+
+            self.get_client()
+                .get(format!("{endpoint}/topics", endpoint = self.get_endpoint()))?
+                .send()?.json()?
+        };
+
+        Ok(names)
+    }
+
+    /// Replaces all topics of the repository, an empty list removes them.
+    pub fn try_set_topics<T>(&self, topics: impl IntoIterator<Item = T>) -> GitHubResult<(), HandleRepositoryError>
+    where T: AsRef<str> {
+        let names: Vec<String> = topics.into_iter()
+            .map(|topic| topic.as_ref().to_lowercase())
+            .collect();
+
+        self.get_client()
+            .put(format!("{endpoint}/topics", endpoint = self.get_endpoint()))?
+            .json(&serde_json::json!({ "names": names }))
+            .send()?;
+
+        Ok(())
+    }
+
+    /// Deletes the repository for good. The confirmation must be the name of
+    /// the repository as `owner/name`, as when deleting it on GitHub.
+    pub fn try_delete(&self, confirmation: impl AsRef<str>) -> GitHubResult<(), HandleRepositoryError> {
+        let confirmation = confirmation.as_ref();
+        let name = self.to_string();
+
+        if !(confirmation.eq_ignore_ascii_case(name.as_str())) {
+            return Err(HandleRepositoryError::Confirmation {
+                confirmation: confirmation.to_owned(),
+                name,
+            })
+        }
+
+        self.get_client()
+            .delete(self.get_endpoint())?
+            .send()?;
+
+        Ok(())
+    }
+
+    pub(crate) fn try_fetch_number(&self) -> GitHubResult<Number, ClientError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            id: Number,
+        }
+
+        let Capsule { id } = {
+
+            self.get_client()
+                .get(self.get_endpoint())?
+                .send()?.json()?
+        };
+
+        Ok(id)
+    }
+
+    pub fn try_get_number(&self) -> GitHubResult<Number, HandleRepositoryError> {
+        Ok(self.try_fetch_number()?)
+    }
+
+    /// Gets the effective permission of a user on the repository, including
+    /// access through teams and the organization, `None` without any access.
+    pub fn try_get_permission(&self, user: impl AsRef<str>) -> GitHubResult<Option<Permission>, HandleRepositoryError> {
+        Ok(self.try_get_collaborator_permission(user)?
+            .and_then(|permission| permission.get_permission()))
+    }
+
+    /// Gets the effective permission and role of a user, `None` if the user does not exist.
+    pub fn try_get_collaborator_permission(&self, user: impl AsRef<str>) -> GitHubResult<Option<CollaboratorPermission>, HandleRepositoryError> {
+        let user = user.as_ref();
+
+        let result = {
+
+            self.get_client()
+                .get(format!("repos/{self}/collaborators/{user}/permission"))?
+                .send()
+        };
+
+        match result {
+            Err(ClientError::Response(ClientResponseError::Nothing { .. })) => Ok(None),
+            Err(error) => Err(error.into()),
+            Ok(response) => Ok(Some(response.json()?)),
+        }
+    }
+
+    /// Whether the user has at least the permission, such as `Permission::Push`
+    /// before running a deployment on their behalf.
+    pub fn try_user_can(&self, user: impl AsRef<str>, permission: Permission) -> GitHubResult<bool, HandleRepositoryError> {
+        Ok(self.try_get_permission(user)?
+            .is_some_and(|granted| granted.includes(permission)))
+    }
+
+    pub fn try_get_collaborators(&self, affiliation: CollaboratorAffiliation) -> GitHubResult<Vec<Collaborator>, HandleRepositoryError> {
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let collaborators: Vec<Collaborator> = {
+
+                self.get_client()
+                    .get(format!("repos/{self}/collaborators"))?
+                    .query(&[("affiliation", affiliation.as_str())])
+                    .query(&[("per_page", 100), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = collaborators.len();
+
+            collection.extend(collaborators);
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub fn try_get_all_collaborators(&self) -> GitHubResult<Vec<Collaborator>, HandleRepositoryError> {
+        self.try_get_collaborators(CollaboratorAffiliation::All)
+    }
+
+    /// Whether the user is a collaborator, including through teams and the organization.
+    pub fn try_is_collaborator(&self, user: impl AsRef<str>) -> GitHubResult<bool, HandleRepositoryError> {
+        let user = user.as_ref();
+
+        let result = {
+
+            self.get_client()
+                .get(format!("repos/{self}/collaborators/{user}"))?
+                .send()
+        };
+
+        match result {
+            Err(ClientError::Response(ClientResponseError::Nothing { .. })) => Ok(false),
+            Err(error) => Err(error.into()),
+            Ok(_) => Ok(true),
+        }
+    }
+
+    /// Adds a collaborator or changes their permission. Users who are not
+    /// collaborators yet get an invitation, which is returned.
+    pub fn try_add_collaborator(&self, user: impl AsRef<str>, permission: Permission) -> GitHubResult<Option<RepositoryInvitation>, HandleRepositoryError> {
+        let user = user.as_ref();
+        let payload = serde_json::json!({ "permission": permission });
+
+        let response = {
+
+            self.get_client()
+                .put(format!("repos/{self}/collaborators/{user}"))?
+                .json(&payload)
+                .send()?
+        };
+
+        match response.code() {
+            201 => Ok(Some(response.json()?)),
+            _ => Ok(None),
+        }
+    }
+
+    pub fn try_remove_collaborator(&self, user: impl AsRef<str>) -> GitHubResult<(), HandleRepositoryError> {
+        let user = user.as_ref();
+
+        self.get_client()
+            .delete(format!("repos/{self}/collaborators/{user}"))?
+            .send()?;
+
+        Ok(())
+    }
+
+    /// Lists the invitations which were neither accepted nor declined.
+    pub fn try_get_invitations(&self) -> GitHubResult<Vec<RepositoryInvitation>, HandleRepositoryError> {
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let invitations: Vec<RepositoryInvitation> = {
+
+                self.get_client()
+                    .get(format!("repos/{self}/invitations"))?
+                    .query(&[("per_page", 100), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = invitations.len();
+
+            collection.extend(invitations);
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub fn try_update_invitation(&self, invitation: impl Into<Number>, permission: Permission) -> GitHubResult<RepositoryInvitation, HandleRepositoryError> {
+        let invitation = invitation.into();
+
+        // Invitations name the pull and push permissions after their roles.
+        let permission = match permission {
+            Permission::Pull => "read",
+            Permission::Push => "write",
+            permission => permission.as_str(),
+        };
+
+        let payload = serde_json::json!({ "permissions": permission });
+
+        Ok(self.get_client()
+            .patch(format!("repos/{self}/invitations/{invitation}"))?
+            .json(&payload)
+            .send()?.json()?)
+    }
+
+    pub fn try_delete_invitation(&self, invitation: impl Into<Number>) -> GitHubResult<(), HandleRepositoryError> {
+        let invitation = invitation.into();
+
+        self.get_client()
+            .delete(format!("repos/{self}/invitations/{invitation}"))?
+            .send()?;
+
+        Ok(())
+    }
+
     pub fn try_submit_dependency_snapshot(&self, ref payload: impl Serialize) -> GitHubResult<(), HandleRepositoryError> {
         let _ = {
 
@@ -345,6 +989,203 @@ impl HandleRepository {
         Ok(collection)
     }
 
+    pub fn get_actions_scope(&self) -> ActionsScope {
+        ActionsScope::Repository { repository: self.clone() }
+    }
+
+    pub fn get_environment_scope(&self, environment: impl AsRef<str>) -> ActionsScope {
+        ActionsScope::Environment {
+            environment: environment.as_ref().to_owned(),
+            repository: self.clone(),
+        }
+    }
+
+    pub fn try_get_secret(&self, name: impl AsRef<str>) -> GitHubResult<HandleSecret, HandleRepositoryError> {
+        Ok(self.get_actions_scope().try_get_secret(name)?)
+    }
+
+    pub fn try_has_secret(&self, name: impl AsRef<str>) -> GitHubResult<bool, HandleRepositoryError> {
+        Ok(self.get_actions_scope().try_has_secret(name)?)
+    }
+
+    pub fn try_get_all_secrets(&self) -> GitHubResult<Vec<HandleSecret>, HandleRepositoryError> {
+        Ok(self.get_actions_scope().try_get_all_secrets()?)
+    }
+
+    pub fn try_create_secret(&self, name: impl AsRef<str>, value: &SecretValue) -> GitHubResult<HandleSecret, HandleRepositoryError> {
+        Ok(self.get_actions_scope().try_create_secret(name, value)?)
+    }
+
+    pub fn try_delete_secret(&self, secret: HandleSecret) -> GitHubResult<(), HandleRepositoryError> {
+        Ok(secret.try_delete()?)
+    }
+
+    pub fn try_get_variable(&self, name: impl AsRef<str>) -> GitHubResult<HandleVariable, HandleRepositoryError> {
+        Ok(self.get_actions_scope().try_get_variable(name)?)
+    }
+
+    pub fn try_has_variable(&self, name: impl AsRef<str>) -> GitHubResult<bool, HandleRepositoryError> {
+        Ok(self.get_actions_scope().try_has_variable(name)?)
+    }
+
+    pub fn try_get_all_variables(&self) -> GitHubResult<Vec<HandleVariable>, HandleRepositoryError> {
+        Ok(self.get_actions_scope().try_get_all_variables()?)
+    }
+
+    pub fn try_create_variable(&self, name: impl AsRef<str>, value: impl AsRef<str>) -> GitHubResult<HandleVariable, HandleRepositoryError> {
+        Ok(self.get_actions_scope().try_create_variable(name, value)?)
+    }
+
+    pub fn try_delete_variable(&self, variable: HandleVariable) -> GitHubResult<(), HandleRepositoryError> {
+        Ok(variable.try_delete()?)
+    }
+
+    pub fn try_ensure_variables<K, V>(&self, variables: impl IntoIterator<Item = (K, V)>) -> GitHubResult<VariableReport, HandleRepositoryError>
+    where K: AsRef<str>, V: AsRef<str> {
+        Ok(self.get_actions_scope().try_ensure_variables(variables)?)
+    }
+
+    pub fn try_get_deployment(&self, number: impl Into<Number>) -> GitHubResult<HandleDeployment, HandleRepositoryError> {
+        Ok(HandleDeployment::try_fetch(self, number)?)
+    }
+
+    /// Gets all deployments of the repository, newest first.
+    pub fn try_get_all_deployments(&self) -> GitHubResult<Vec<HandleDeployment>, HandleRepositoryError> {
+        Ok(HandleDeployment::try_fetch_all(self, None)?)
+    }
+
+    /// Gets all deployments to an environment, newest first.
+    pub fn try_get_environment_deployments(&self, environment: impl AsRef<str>) -> GitHubResult<Vec<HandleDeployment>, HandleRepositoryError> {
+        Ok(HandleDeployment::try_fetch_all(self, Some(environment.as_ref()))?)
+    }
+
+    /// Creates a deployment of a reference or commit, fails with a merge error when
+    /// auto-merge brought the ref up to date instead.
+    pub fn try_create_deployment(&self, reference: impl Into<DeploymentReference>, content: &DeploymentContent) -> GitHubResult<HandleDeployment, HandleRepositoryError> {
+        Ok(HandleDeployment::try_create(self, reference, content)?)
+    }
+
+    pub fn try_delete_deployment(&self, deployment: HandleDeployment) -> GitHubResult<(), HandleRepositoryError> {
+        Ok(deployment.try_delete()?)
+    }
+
+    /// Deletes the deployments to an environment whose latest status is inactive,
+    /// returns the numbers of the deleted deployments.
+    pub fn try_delete_inactive_deployments(&self, environment: impl AsRef<str>) -> GitHubResult<Vec<Number>, HandleRepositoryError> {
+        let mut deleted = Vec::new();
+
+        for deployment in self.try_get_environment_deployments(environment)? {
+            let inactive = deployment.try_get_latest_status()?
+                .map(|status| status.get_state().is_inactive())
+                .unwrap_or(false);
+
+            if inactive {
+                deployment.try_delete()?;
+                deleted.push(deployment.get_number());
+            }
+        }
+
+        Ok(deleted)
+    }
+
+    /// Gets the deployments of a workflow run waiting for review, `run` is the run id
+    /// as found in `GITHUB_RUN_ID` rather than the run number.
+    pub fn try_get_pending_deployments(&self, run: Number) -> GitHubResult<Vec<PendingDeployment>, HandleRepositoryError> {
+        Ok(HandleDeployment::try_fetch_pending(self, run)?)
+    }
+
+    /// Approves or rejects the pending deployments of a workflow run for the given environments.
+    pub fn try_review_pending_deployments<T: Into<Number>>(&self, run: Number, environments: impl IntoIterator<Item = T>, state: ReviewState, comment: impl AsRef<str>) -> GitHubResult<Vec<Deployment>, HandleRepositoryError> {
+        Ok(HandleDeployment::try_review_pending(self, run, environments, state, comment)?)
+    }
+
+    pub fn try_approve_pending_deployments<T: Into<Number>>(&self, run: Number, environments: impl IntoIterator<Item = T>, comment: impl AsRef<str>) -> GitHubResult<Vec<Deployment>, HandleRepositoryError> {
+        self.try_review_pending_deployments(run, environments, ReviewState::Approved, comment)
+    }
+
+    pub fn try_reject_pending_deployments<T: Into<Number>>(&self, run: Number, environments: impl IntoIterator<Item = T>, comment: impl AsRef<str>) -> GitHubResult<Vec<Deployment>, HandleRepositoryError> {
+        self.try_review_pending_deployments(run, environments, ReviewState::Rejected, comment)
+    }
+
+    pub fn try_get_environment(&self, name: impl AsRef<str>) -> GitHubResult<HandleEnvironment, HandleRepositoryError> {
+        Ok(HandleEnvironment::try_fetch(self, name)?)
+    }
+
+    pub fn try_has_environment(&self, name: impl AsRef<str>) -> GitHubResult<bool, HandleRepositoryError> {
+        match HandleEnvironment::try_fetch(self, name) {
+            Err(EnvironmentError::Nothing { .. }) => Ok(false),
+            Err(error) => Err(error.into()),
+            Ok(_) => Ok(true),
+        }
+    }
+
+    pub fn try_get_all_environments(&self) -> GitHubResult<Vec<HandleEnvironment>, HandleRepositoryError> {
+        Ok(HandleEnvironment::try_fetch_all(self)?)
+    }
+
+    /// Creates an environment or replaces the protection settings of an existing one.
+    pub fn try_create_environment(&self, name: impl AsRef<str>, content: &EnvironmentContent) -> GitHubResult<HandleEnvironment, HandleRepositoryError> {
+        Ok(HandleEnvironment::try_create(self, name, content)?)
+    }
+
+    pub fn try_delete_environment(&self, environment: HandleEnvironment) -> GitHubResult<(), HandleRepositoryError> {
+        Ok(environment.try_delete()?)
+    }
+
+    pub fn get_ruleset_scope(&self) -> RulesetScope {
+        RulesetScope::Repository { repository: self.clone() }
+    }
+
+    pub fn try_get_ruleset(&self, number: impl Into<Number>) -> GitHubResult<HandleRuleset, HandleRepositoryError> {
+        Ok(self.get_ruleset_scope().try_get_ruleset(number)?)
+    }
+
+    pub fn try_get_all_rulesets(&self) -> GitHubResult<Vec<HandleRuleset>, HandleRepositoryError> {
+        Ok(self.get_ruleset_scope().try_get_all_rulesets()?)
+    }
+
+    pub fn try_create_ruleset(&self, ruleset: &Ruleset) -> GitHubResult<HandleRuleset, HandleRepositoryError> {
+        Ok(self.get_ruleset_scope().try_create_ruleset(ruleset)?)
+    }
+
+    pub fn try_delete_ruleset(&self, ruleset: HandleRuleset) -> GitHubResult<(), HandleRepositoryError> {
+        Ok(ruleset.try_delete()?)
+    }
+
+    pub fn get_hook_scope(&self) -> HookScope {
+        HookScope::Repository { repository: self.clone() }
+    }
+
+    pub fn try_get_hook(&self, number: impl Into<Number>) -> GitHubResult<HandleHook, HandleRepositoryError> {
+        Ok(self.get_hook_scope().try_get_hook(number)?)
+    }
+
+    pub fn try_has_hook(&self, number: impl Into<Number>) -> GitHubResult<bool, HandleRepositoryError> {
+        Ok(self.get_hook_scope().try_has_hook(number)?)
+    }
+
+    pub fn try_get_all_hooks(&self) -> GitHubResult<Vec<HandleHook>, HandleRepositoryError> {
+        Ok(self.get_hook_scope().try_get_all_hooks()?)
+    }
+
+    /// Gets all hooks with their configuration, such as to find the one for a URL.
+    pub fn try_get_all_hook_content(&self) -> GitHubResult<Vec<Hook>, HandleRepositoryError> {
+        Ok(self.get_hook_scope().try_get_all_hook_content()?)
+    }
+
+    pub fn try_create_hook(&self, hook: &Hook) -> GitHubResult<HandleHook, HandleRepositoryError> {
+        Ok(self.get_hook_scope().try_create_hook(hook)?)
+    }
+
+    pub fn try_delete_hook(&self, hook: HandleHook) -> GitHubResult<(), HandleRepositoryError> {
+        Ok(hook.try_delete()?)
+    }
+
+    /// Gets the rules that apply to a branch, including those of organization rulesets.
+    pub fn try_get_branch_rules(&self, branch: impl AsRef<str>) -> GitHubResult<Vec<BranchRule>, HandleRepositoryError> {
+        Ok(HandleRuleset::try_fetch_branch_rules(self, branch)?)
+    }
+
     pub fn try_get_issue(&self, id: usize) -> GitHubResult<HandleIssue, HandleRepositoryError> {
         Ok(HandleIssue::try_fetch(self, id)?)
     }
@@ -353,6 +1194,97 @@ impl HandleRepository {
         Ok(HandleIssue::try_fetch_all(self)?)
     }
 
+    /// Lists the issues matching the filter with their content, without pull requests.
+    pub fn try_get_issues(&self, filter: &IssueFilter) -> GitHubResult<Vec<Issue>, HandleRepositoryError> {
+        Ok(HandleIssue::try_fetch_filtered(self, filter)?)
+    }
+
+    /// Gets the events of all issues and pull requests of the repository, newest first.
+    pub fn try_get_issue_events(&self) -> GitHubResult<Vec<IssueEvent>, HandleRepositoryError> {
+        Ok(HandleIssue::try_fetch_events(self)?)
+    }
+
+    /// Opens an issue from a title or an [`IssueDraft`].
+    pub fn try_create_issue(&self, draft: impl Into<IssueDraft>) -> GitHubResult<HandleIssue, HandleRepositoryError> {
+        Ok(HandleIssue::try_create(self, draft)?)
+    }
+
+    /// Reactions of a pull request review comment, given by its number.
+    pub fn get_review_comment_reactions(&self, number: impl Into<Number>) -> ReactionTarget {
+        ReactionTarget::ReviewComment {
+            repository: self.clone(),
+            number: number.into(),
+        }
+    }
+
+    pub fn try_get_milestone(&self, number: impl Into<Number>) -> GitHubResult<HandleMilestone, HandleRepositoryError> {
+        Ok(HandleMilestone::try_fetch(self, number)?)
+    }
+
+    pub fn try_has_milestone(&self, number: impl Into<Number>) -> GitHubResult<bool, HandleRepositoryError> {
+        match HandleMilestone::try_fetch(self, number) {
+            Err(MilestoneError::Nothing { .. }) => Ok(false),
+            Err(error) => Err(error.into()),
+            Ok(_) => Ok(true),
+        }
+    }
+
+    pub fn try_get_all_milestones(&self) -> GitHubResult<Vec<HandleMilestone>, HandleRepositoryError> {
+        Ok(HandleMilestone::try_fetch_all(self)?)
+    }
+
+    /// Lists milestones with their open and closed issue counts.
+    pub fn try_get_milestones(&self, state: IssueStateFilter, sort: MilestoneSort, direction: SortDirection) -> GitHubResult<Vec<Milestone>, HandleRepositoryError> {
+        Ok(HandleMilestone::try_fetch_all_content(self, state, sort, direction)?)
+    }
+
+    pub fn try_create_milestone(&self, content: impl Into<MilestoneContent>) -> GitHubResult<HandleMilestone, HandleRepositoryError> {
+        Ok(HandleMilestone::try_create(self, content)?)
+    }
+
+    pub fn try_delete_milestone(&self, milestone: HandleMilestone) -> GitHubResult<(), HandleRepositoryError> {
+        Ok(milestone.try_delete()?)
+    }
+
+    pub fn try_get_label(&self, name: impl AsRef<str>) -> GitHubResult<HandleLabel, HandleRepositoryError> {
+        Ok(HandleLabel::try_fetch(self, name)?)
+    }
+
+    pub fn try_has_label(&self, name: impl AsRef<str>) -> GitHubResult<bool, HandleRepositoryError> {
+        match HandleLabel::try_fetch(self, name) {
+            Err(LabelError::Nothing { .. }) => Ok(false),
+            Err(error) => Err(error.into()),
+            Ok(_) => Ok(true),
+        }
+    }
+
+    pub fn try_get_all_labels(&self) -> GitHubResult<Vec<HandleLabel>, HandleRepositoryError> {
+        Ok(HandleLabel::try_fetch_all(self)?)
+    }
+
+    /// Gets all labels of the repository including their colors and descriptions.
+    pub fn try_get_all_label_content(&self) -> GitHubResult<Vec<Label>, HandleRepositoryError> {
+        Ok(HandleLabel::try_fetch_all_content(self)?)
+    }
+
+    pub fn try_create_label(&self, name: impl AsRef<str>, color: impl AsRef<str>, description: Option<&str>) -> GitHubResult<HandleLabel, HandleRepositoryError> {
+        Ok(HandleLabel::try_create(self, name, color, description)?)
+    }
+
+    pub fn try_delete_label(&self, label: HandleLabel) -> GitHubResult<(), HandleRepositoryError> {
+        Ok(label.try_delete()?)
+    }
+
+    /// Makes the labels of the repository match the definitions, see [`HandleLabel::try_read_definitions`].
+    pub fn try_sync_labels(&self, definitions: impl AsRef<[LabelDefinition]>, prune: bool) -> GitHubResult<LabelReport, HandleRepositoryError> {
+        Ok(HandleLabel::try_sync(self, definitions, prune)?)
+    }
+
+    pub fn try_sync_labels_from_file(&self, path: impl AsRef<std::path::Path>, prune: bool) -> GitHubResult<LabelReport, HandleRepositoryError> {
+        let definitions = HandleLabel::try_read_definitions(path)?;
+        Ok(HandleLabel::try_sync(self, definitions, prune)?)
+    }
+
     pub fn try_has_tag(&self, tag: impl AsRef<str>) -> GitHubResult<bool, HandleRepositoryError> {
         Ok(self.try_get_some_tag(tag)?.is_some())
     }
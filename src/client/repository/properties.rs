@@ -1,5 +1,8 @@
+use std::collections::{BTreeMap};
+
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
+use serde_json::{Map, Value};
 
 use crate::client::{
 
@@ -334,4 +337,260 @@ impl RepositoryProperties {
     pub fn get_date_pushed(&self) -> Option<DateTime<Utc>> {
         self.date_pushed.clone()
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum SquashMergeCommitTitle {
+    #[serde(rename = "PR_TITLE")]
+    PullRequestTitle,
+    /// The commit message for single commit pull requests, the title otherwise.
+    #[serde(rename = "COMMIT_OR_PR_TITLE")]
+    CommitOrPullRequestTitle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum SquashMergeCommitMessage {
+    #[serde(rename = "PR_BODY")]
+    PullRequestBody,
+    #[serde(rename = "COMMIT_MESSAGES")]
+    CommitMessages,
+    #[serde(rename = "BLANK")]
+    Blank,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum MergeCommitTitle {
+    #[serde(rename = "PR_TITLE")]
+    PullRequestTitle,
+    /// The classic `Merge pull request #123 from branch` title.
+    #[serde(rename = "MERGE_MESSAGE")]
+    MergeMessage,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum MergeCommitMessage {
+    #[serde(rename = "PR_BODY")]
+    PullRequestBody,
+    #[serde(rename = "PR_TITLE")]
+    PullRequestTitle,
+    #[serde(rename = "BLANK")]
+    Blank,
+}
+
+/// Changes to the settings of a repository. Only the settings given here are
+/// sent, and of those only the ones which differ from the current settings.
+/// Repositories are renamed with `try_rename` instead, as it gives a new handle.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RepositoryUpdate {
+    pub(crate) fields: BTreeMap<&'static str, Value>,
+    pub(crate) topics: Option<Vec<String>>,
+}
+
+impl RepositoryUpdate {
+    pub fn new() -> RepositoryUpdate {
+        RepositoryUpdate::default()
+    }
+
+    fn with_field(mut self, key: &'static str, value: impl Serialize) -> Self {
+        self.fields.insert(key, serde_json::to_value(value).unwrap_or_default());
+        self
+    }
+
+    fn with_security_field(mut self, key: &'static str, status: bool) -> Self {
+        let security = self.fields.entry("security_and_analysis")
+            .or_insert_with(|| serde_json::json!({}));
+
+        security[key] = serde_json::json!({ "status": Status::from(status) });
+        self
+    }
+
+    pub fn with_description(self, description: impl AsRef<str>) -> Self {
+        self.with_field("description", description.as_ref())
+    }
+
+    pub fn with_homepage(self, homepage: impl AsRef<str>) -> Self {
+        self.with_field("homepage", homepage.as_ref())
+    }
+
+    pub fn with_default_branch(self, branch: impl AsRef<str>) -> Self {
+        self.with_field("default_branch", branch.as_ref())
+    }
+
+    pub fn with_visibility(self, visibility: Visibility) -> Self {
+        self.with_field("visibility", visibility)
+    }
+
+    pub fn with_issues(self, issues: bool) -> Self {
+        self.with_field("has_issues", issues)
+    }
+
+    pub fn with_projects(self, projects: bool) -> Self {
+        self.with_field("has_projects", projects)
+    }
+
+    pub fn with_wiki(self, wiki: bool) -> Self {
+        self.with_field("has_wiki", wiki)
+    }
+
+    pub fn with_discussions(self, discussions: bool) -> Self {
+        self.with_field("has_discussions", discussions)
+    }
+
+    pub fn with_template(self, template: bool) -> Self {
+        self.with_field("is_template", template)
+    }
+
+    pub fn with_forking(self, forking: bool) -> Self {
+        self.with_field("allow_forking", forking)
+    }
+
+    pub fn with_signoff(self, signoff: bool) -> Self {
+        self.with_field("web_commit_signoff_required", signoff)
+    }
+
+    pub fn with_archived(self, archived: bool) -> Self {
+        self.with_field("archived", archived)
+    }
+
+    pub fn with_merge_commit(self, allow: bool) -> Self {
+        self.with_field("allow_merge_commit", allow)
+    }
+
+    pub fn with_squash_merge(self, allow: bool) -> Self {
+        self.with_field("allow_squash_merge", allow)
+    }
+
+    pub fn with_rebase_merge(self, allow: bool) -> Self {
+        self.with_field("allow_rebase_merge", allow)
+    }
+
+    pub fn with_auto_merge(self, allow: bool) -> Self {
+        self.with_field("allow_auto_merge", allow)
+    }
+
+    /// Suggests updating pull request branches which are behind their base.
+    pub fn with_update_branch(self, allow: bool) -> Self {
+        self.with_field("allow_update_branch", allow)
+    }
+
+    pub fn with_delete_branch_on_merge(self, delete: bool) -> Self {
+        self.with_field("delete_branch_on_merge", delete)
+    }
+
+    pub fn with_squash_merge_commit(self, title: SquashMergeCommitTitle, message: SquashMergeCommitMessage) -> Self {
+        self.with_field("squash_merge_commit_title", title)
+            .with_field("squash_merge_commit_message", message)
+    }
+
+    pub fn with_merge_commit_defaults(self, title: MergeCommitTitle, message: MergeCommitMessage) -> Self {
+        self.with_field("merge_commit_title", title)
+            .with_field("merge_commit_message", message)
+    }
+
+    pub fn with_advanced_security(self, status: bool) -> Self {
+        self.with_security_field("advanced_security", status)
+    }
+
+    pub fn with_secret_scanning(self, status: bool) -> Self {
+        self.with_security_field("secret_scanning", status)
+    }
+
+    pub fn with_secret_scanning_push_protection(self, status: bool) -> Self {
+        self.with_security_field("secret_scanning_push_protection", status)
+    }
+
+    /// Replaces all topics, they are lowercased as GitHub stores them.
+    pub fn with_topics<T>(mut self, topics: impl IntoIterator<Item = T>) -> Self
+    where T: AsRef<str> {
+        self.topics = Some(topics.into_iter()
+            .map(|topic| topic.as_ref().to_lowercase())
+            .collect());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.topics.is_none()
+    }
+
+    /// The fields that would be sent given the current settings, as returned
+    /// when getting the repository.
+    pub(crate) fn get_changes(&self, current: &Value) -> Map<String, Value> {
+        self.fields.iter()
+            .filter(|(key, value)| !(is_satisfied(value, &(current[**key]))))
+            .map(|(key, value)| (key.to_string(), value.clone()))
+            .collect()
+    }
+
+    pub(crate) fn get_topic_changes(&self, current: &Value) -> Option<Vec<String>> {
+        let topics = self.topics.as_ref()?;
+
+        let mut current: Vec<String> = serde_json::from_value(current["topics"].clone())
+            .unwrap_or_default();
+
+        let mut requested = topics.clone();
+
+        current.sort();
+        requested.sort();
+        requested.dedup();
+
+        (current != requested).then(|| topics.clone())
+    }
+}
+
+/// Whether the current value already has everything requested, objects may
+/// hold more fields than were requested.
+fn is_satisfied(requested: &Value, current: &Value) -> bool {
+    match (requested, current) {
+        (Value::Object(requested), Value::Object(current)) => {
+            requested.iter().all(|(key, value)| {
+                current.get(key)
+                    .is_some_and(|current| is_satisfied(value, current))
+            })
+        },
+        (requested, current) => requested == current,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{
+
+        SquashMergeCommitMessage,
+        SquashMergeCommitTitle,
+        RepositoryUpdate,
+    };
+
+    #[test]
+    fn test_changes() {
+        let current = serde_json::json!({
+            "has_wiki": true,
+            "allow_squash_merge": true,
+            "squash_merge_commit_title": "COMMIT_OR_PR_TITLE",
+            "squash_merge_commit_message": "COMMIT_MESSAGES",
+            "security_and_analysis": {
+                "secret_scanning": { "status": "enabled" },
+                "advanced_security": { "status": "disabled" }
+            },
+            "topics": ["rust", "github"]
+        });
+
+        let update = RepositoryUpdate::new()
+            .with_wiki(false)
+            .with_squash_merge(true)
+            .with_squash_merge_commit(SquashMergeCommitTitle::PullRequestTitle, SquashMergeCommitMessage::CommitMessages)
+            .with_secret_scanning(true)
+            .with_topics(["GitHub", "rust"]);
+
+        assert_eq!(serde_json::Value::Object(update.get_changes(&current)), serde_json::json!({
+            "has_wiki": false,
+            "squash_merge_commit_title": "PR_TITLE",
+        }));
+
+        assert_eq!(update.get_topic_changes(&current), None);
+    }
+}
@@ -0,0 +1,177 @@
+use std::{
+
+    borrow::{Cow},
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+};
+
+use thiserror::{Error};
+
+use crate::client::{
+
+    repository::{
+
+        issue::{
+
+            comment::{HandleIssueComment},
+
+            HandleIssue,
+        },
+
+        HandleRepository,
+    },
+
+    client::{
+
+        ClientError,
+        Client,
+    },
+
+    models::common::{
+
+        reaction::{
+
+            ReactionContent,
+            Reaction,
+        },
+
+        user::{User},
+    },
+
+    GitHubProperties,
+    GitHubEndpoint,
+    GitHubResult,
+    Number,
+};
+
+#[derive(Error, Debug)]
+pub enum ReactionError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+}
+
+/// What a reaction is attached to, pull request review comments are given by number.
+#[derive(Clone, Debug)]
+pub enum ReactionTarget {
+    Issue { issue: HandleIssue },
+    IssueComment { comment: HandleIssueComment },
+    ReviewComment { repository: HandleRepository, number: Number },
+}
+
+impl ReactionTarget {
+    pub(crate) fn get_client(&self) -> &Client {
+        match self {
+            ReactionTarget::Issue { issue } => issue.get_client(),
+            ReactionTarget::IssueComment { comment } => comment.get_client(),
+            ReactionTarget::ReviewComment { repository, .. } => repository.get_client(),
+        }
+    }
+
+    /// Lists the reactions, optionally only those of one content type.
+    pub fn try_get_reactions(&self, content: Option<ReactionContent>) -> GitHubResult<Vec<Reaction>, ReactionError> {
+        let endpoint = self.get_endpoint();
+
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let reactions: Vec<Reaction> = {
+
+                let mut request = self.get_client()
+                    .get(endpoint.as_ref())?
+                    .query(&[("per_page", 100), ("page", page)]);
+
+                if let Some(content) = content {
+                    request = request.query(&[("content", content.as_str())]);
+                }
+
+                request.send()?.json()?
+            };
+
+            let count = reactions.len();
+
+            collection.extend(reactions);
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    /// Reacts as the authenticated user, an existing reaction of the same content is returned as is.
+    pub fn try_add_reaction(&self, content: ReactionContent) -> GitHubResult<Reaction, ReactionError> {
+        let payload = serde_json::json!({
+            "content": content,
+        });
+
+        Ok(self.get_client()
+            .post(self.get_endpoint())?
+            .json(&payload)
+            .send()?.json()?)
+    }
+
+    pub fn try_delete_reaction(&self, reaction: impl Into<Number>) -> GitHubResult<(), ReactionError> {
+        let endpoint = self.get_endpoint();
+        let reaction = reaction.into();
+
+        self.get_client()
+            .delete(format!("{endpoint}/{reaction}"))?
+            .send()?;
+
+        Ok(())
+    }
+
+    /// Removes the reaction of the user with the given content, nothing is
+    /// sent when the user has not reacted with it.
+    pub fn try_remove_reaction(&self, user: &User, content: ReactionContent) -> GitHubResult<(), ReactionError> {
+        let reactions = self.try_get_reactions(Some(content))?;
+
+        for reaction in reactions.iter().filter(|reaction| reaction.user.as_ref() == Some(user)) {
+            self.try_delete_reaction(reaction.number)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> GitHubEndpoint<'a> for ReactionTarget {
+    fn get_endpoint(&'a self) -> Cow<'a, str> {
+        match self {
+            ReactionTarget::Issue { issue } => format!("{endpoint}/reactions", endpoint = issue.get_endpoint()).into(),
+            ReactionTarget::IssueComment { comment } => format!("{endpoint}/reactions", endpoint = comment.get_endpoint()).into(),
+            ReactionTarget::ReviewComment { repository, number } => format!("repos/{repository}/pulls/comments/{number}/reactions").into(),
+        }
+    }
+}
+
+impl FmtDisplay for ReactionTarget {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        match self {
+            ReactionTarget::Issue { issue } => write!(fmt, "{issue}"),
+            ReactionTarget::IssueComment { comment } => write!(fmt, "{comment}"),
+            ReactionTarget::ReviewComment { number, .. } => write!(fmt, "{number}"),
+        }
+    }
+}
+
+impl From<HandleIssue> for ReactionTarget {
+    fn from(issue: HandleIssue) -> ReactionTarget {
+        ReactionTarget::Issue { issue }
+    }
+}
+
+impl From<HandleIssueComment> for ReactionTarget {
+    fn from(comment: HandleIssueComment) -> ReactionTarget {
+        ReactionTarget::IssueComment { comment }
+    }
+}
@@ -30,6 +30,12 @@ use crate::client::{
         ClientError,
         Client,
     },
+
+    models::common::{
+
+        protection::{BranchProtection},
+        ruleset::{BranchRule},
+    },
     
     Number, GitHubProperties,
 };
@@ -258,6 +264,100 @@ impl HandleReference {
         repository.try_get_commit(sha)
     }
 
+    /// Gets the protection of a branch, `None` when the branch is not protected.
+    pub fn try_get_protection(&self) -> GitHubResult<Option<BranchProtection>, HandleRepositoryError> {
+        let endpoint = self.get_protection_endpoint()?;
+
+        let result = {
+
+            self.get_client()
+                .get(endpoint)?
+                .send()
+        };
+
+        match result {
+            Err(ClientError::Response(ClientResponseError::Nothing { .. })) => Ok(None),
+            Err(error) => Err(error.into()),
+            Ok(response) => Ok(Some(response.json()?)),
+        }
+    }
+
+    /// Protects a branch, replacing any protection it already has.
+    pub fn try_set_protection(&self, protection: &BranchProtection) -> GitHubResult<(), HandleRepositoryError> {
+        let endpoint = self.get_protection_endpoint()?;
+
+        // Required signatures have their own endpoint and are not accepted here.
+        let mut payload = serde_json::to_value(protection)
+            .unwrap_or_default();
+
+        if let Some(payload) = payload.as_object_mut() {
+            payload.remove("required_signatures");
+        }
+
+        self.get_client()
+            .put(endpoint.as_str())?
+            .json(&payload)
+            .send()?;
+
+        if protection.requires_signatures() {
+            self.get_client()
+                .post(format!("{endpoint}/required_signatures"))?
+                .send()?;
+        }
+
+        else {
+
+            let result = {
+
+                self.get_client()
+                    .delete(format!("{endpoint}/required_signatures"))?
+                    .send()
+            };
+
+            match result {
+                Err(ClientError::Response(ClientResponseError::Nothing { .. })) => (),
+                Err(error) => return Err(error.into()),
+                Ok(_) => (),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes all protection from a branch.
+    pub fn try_remove_protection(&self) -> GitHubResult<(), HandleRepositoryError> {
+        let endpoint = self.get_protection_endpoint()?;
+
+        self.get_client()
+            .delete(endpoint)?
+            .send()?;
+
+        Ok(())
+    }
+
+    /// Gets the rules that apply to a branch from the rulesets of the repository and organization.
+    pub fn try_get_rules(&self) -> GitHubResult<Vec<BranchRule>, HandleRepositoryError> {
+        match self {
+            HandleReference::Branch { repository, branch } => {
+                repository.try_get_branch_rules(branch)
+            },
+            _ => Err(HandleRepositoryError::InvalidBranch {
+                name: self.to_string()
+            }),
+        }
+    }
+
+    fn get_protection_endpoint(&self) -> GitHubResult<String, HandleRepositoryError> {
+        match self {
+            HandleReference::Branch { repository, branch } => {
+                Ok(format!("repos/{repository}/branches/{branch}/protection"))
+            },
+            _ => Err(HandleRepositoryError::InvalidBranch {
+                name: self.to_string()
+            }),
+        }
+    }
+
     pub(crate) fn try_delete(&self) -> GitHubResult<(), ReferenceError> {
         let repository = self.get_repository();
 
@@ -0,0 +1,299 @@
+use std::{
+
+    borrow::{Cow},
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+};
+
+use thiserror::{Error};
+
+use crate::client::{
+
+    account::organization::{HandleOrganization},
+    repository::{HandleRepository},
+
+    client::{
+
+        ClientResponseError,
+        ClientError,
+        Client,
+    },
+
+    models::common::ruleset::{
+
+        BranchRule,
+        Ruleset,
+    },
+
+    GitHubProperties,
+    GitHubEndpoint,
+    GitHubResult,
+    Number,
+};
+
+#[derive(Error, Debug)]
+pub enum RulesetError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Ruleset not found: {number}")]
+    Nothing { number: Number },
+}
+
+/// Where a ruleset is defined, organization rulesets can target many repositories.
+#[derive(Clone, Debug)]
+pub enum RulesetScope {
+    Organization { organization: HandleOrganization },
+    Repository { repository: HandleRepository },
+}
+
+impl RulesetScope {
+    pub(crate) fn get_client(&self) -> &Client {
+        match self {
+            RulesetScope::Organization { organization } => organization.get_client(),
+            RulesetScope::Repository { repository } => repository.get_client(),
+        }
+    }
+
+    pub fn is_organization(&self) -> bool {
+        matches!(self, RulesetScope::Organization { .. })
+    }
+
+    pub fn is_repository(&self) -> bool {
+        matches!(self, RulesetScope::Repository { .. })
+    }
+
+    pub fn try_get_ruleset(&self, number: impl Into<Number>) -> GitHubResult<HandleRuleset, RulesetError> {
+        HandleRuleset::try_fetch(self, number)
+    }
+
+    pub fn try_has_ruleset(&self, number: impl Into<Number>) -> GitHubResult<bool, RulesetError> {
+        match HandleRuleset::try_fetch(self, number) {
+            Err(RulesetError::Nothing { .. }) => Ok(false),
+            Err(error) => Err(error),
+            Ok(_) => Ok(true),
+        }
+    }
+
+    pub fn try_get_all_rulesets(&self) -> GitHubResult<Vec<HandleRuleset>, RulesetError> {
+        HandleRuleset::try_fetch_all(self)
+    }
+
+    pub fn try_create_ruleset(&self, ruleset: &Ruleset) -> GitHubResult<HandleRuleset, RulesetError> {
+        HandleRuleset::try_create(self, ruleset)
+    }
+
+    pub fn try_delete_ruleset(&self, number: impl Into<Number>) -> GitHubResult<(), RulesetError> {
+        HandleRuleset::try_fetch(self, number)?
+            .try_delete()
+    }
+}
+
+impl<'a> GitHubEndpoint<'a> for RulesetScope {
+    fn get_endpoint(&'a self) -> Cow<'a, str> {
+        match self {
+            RulesetScope::Organization { organization } => format!("orgs/{organization}/rulesets").into(),
+            RulesetScope::Repository { repository } => format!("repos/{repository}/rulesets").into(),
+        }
+    }
+}
+
+impl FmtDisplay for RulesetScope {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        match self {
+            RulesetScope::Organization { organization } => write!(fmt, "{organization}"),
+            RulesetScope::Repository { repository } => write!(fmt, "{repository}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleRuleset {
+    scope: RulesetScope,
+    number: Number,
+}
+
+impl HandleRuleset {
+    pub(crate) fn try_fetch(scope: &RulesetScope, number: impl Into<Number>) -> GitHubResult<HandleRuleset, RulesetError> {
+        let endpoint = scope.get_endpoint();
+        let number = number.into();
+
+        let Ruleset { number, .. } = {
+
+            let result = {
+
+                scope.get_client()
+                    .get(format!("{endpoint}/{number}"))?
+                    .send()
+            };
+
+            match result {
+                Err(ClientError::Response(ClientResponseError::Nothing { .. })) => {
+                    return Err(RulesetError::Nothing { number })
+                },
+                Err(error) => return Err(error.into()),
+                Ok(response) => response.json()?,
+            }
+        };
+
+        Ok(HandleRuleset {
+            scope: scope.clone(),
+            number,
+        })
+    }
+
+    /// Lists the rulesets defined in the scope, without those inherited from the organization.
+    pub(crate) fn try_fetch_all(scope: &RulesetScope) -> GitHubResult<Vec<HandleRuleset>, RulesetError> {
+        let endpoint = scope.get_endpoint();
+
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let rulesets: Vec<Ruleset> = {
+
+                scope.get_client()
+                    .get(endpoint.as_ref())?
+                    .query(&[("per_page", 100), ("page", page)])
+                    .query(&[("includes_parents", false)])
+                    .send()?.json()?
+            };
+
+            let count = rulesets.len();
+
+            collection.extend(rulesets.into_iter()
+                .map(|Ruleset { number, .. }| HandleRuleset {
+                    scope: scope.clone(),
+                    number,
+                }));
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub(crate) fn try_create(scope: &RulesetScope, ruleset: &Ruleset) -> GitHubResult<HandleRuleset, RulesetError> {
+        let endpoint = scope.get_endpoint();
+
+        let Ruleset { number, .. } = {
+
+            scope.get_client()
+                .post(endpoint.as_ref())?
+                .json(ruleset)
+                .send()?.json()?
+        };
+
+        Ok(HandleRuleset {
+            scope: scope.clone(),
+            number,
+        })
+    }
+
+    /// Gets the rules that apply to a branch of a repository, from all of its
+    /// active rulesets including those of the organization.
+    pub(crate) fn try_fetch_branch_rules(repository: &HandleRepository, branch: impl AsRef<str>) -> GitHubResult<Vec<BranchRule>, RulesetError> {
+        let branch = branch.as_ref();
+
+        let mut collection = Vec::new();
+        let mut page = 0;
+
+        loop {
+
+            page += 1;
+
+            let rules: Vec<BranchRule> = {
+
+                repository.get_client()
+                    .get(format!("repos/{repository}/rules/branches/{branch}"))?
+                    .query(&[("per_page", 100), ("page", page)])
+                    .send()?.json()?
+            };
+
+            let count = rules.len();
+
+            collection.extend(rules);
+
+            if count < 100 {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub fn try_get_ruleset(&self) -> GitHubResult<Ruleset, RulesetError> {
+        Ok(self.get_client()
+            .get(self.get_endpoint())?
+            .send()?.json()?)
+    }
+
+    /// Replaces the ruleset, typically with a modified copy from `try_get_ruleset`.
+    pub fn try_update(&self, ruleset: &Ruleset) -> GitHubResult<(), RulesetError> {
+        self.get_client()
+            .put(self.get_endpoint())?
+            .json(ruleset)
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_delete(&self) -> GitHubResult<(), RulesetError> {
+        self.get_client()
+            .delete(self.get_endpoint())?
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+}
+
+impl From<&HandleRuleset> for Number {
+    fn from(ruleset: &HandleRuleset) -> Number {
+        ruleset.number
+    }
+}
+
+impl From<HandleRuleset> for Number {
+    fn from(ruleset: HandleRuleset) -> Number {
+        ruleset.number
+    }
+}
+
+impl<'a> GitHubEndpoint<'a> for HandleRuleset {
+    fn get_endpoint(&'a self) -> Cow<'a, str> {
+        let HandleRuleset { scope, number } = { self };
+        format!("{endpoint}/{number}", endpoint = scope.get_endpoint()).into()
+    }
+}
+
+impl<'a> GitHubProperties<'a> for HandleRuleset {
+    type Content = Ruleset;
+    type Parent = RulesetScope;
+
+    fn get_client(&'a self) -> &'a Client {
+        self.scope.get_client()
+    }
+
+    fn get_parent(&'a self) -> &'a Self::Parent {
+        &(self.scope)
+    }
+}
+
+impl FmtDisplay for HandleRuleset {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        write!(fmt, "{number}", number = self.number)
+    }
+}
@@ -0,0 +1,183 @@
+use std::{
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+        Debug as FmtDebug,
+    },
+
+    time::{Duration},
+};
+
+use serde::de::{DeserializeOwned};
+use thiserror::{Error};
+
+use crate::client::{
+
+    client::{
+
+        ClientResponseError,
+        ClientError,
+        Client,
+    },
+
+    models::common::search::{
+
+        SearchResults,
+        SearchQuery,
+    },
+
+    GitHubResult,
+};
+
+/// Search never returns more results than this, however many match.
+pub const SEARCH_LIMIT: usize = 1000;
+
+/// Longest wait for the search rate limit to reset before giving up, the
+/// search bucket resets every minute.
+const SEARCH_WAIT: u64 = 90;
+
+/// Most times a rate limited search request is retried.
+const SEARCH_RETRIES: usize = 3;
+
+#[derive(Error, Debug)]
+pub enum SearchError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    /// `reset` is zero when GitHub gave no time for the limit to reset.
+    #[error("Search rate limit exceeded until: {reset}")]
+    RateLimited { reset: u64 },
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum SearchKind {
+    Code,
+    Issues,
+    Commits,
+    Repositories,
+    Users,
+}
+
+impl FmtDisplay for SearchKind {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        match self {
+            SearchKind::Code => write!(fmt, "code"),
+            SearchKind::Issues => write!(fmt, "issues"),
+            SearchKind::Commits => write!(fmt, "commits"),
+            SearchKind::Repositories => write!(fmt, "repositories"),
+            SearchKind::Users => write!(fmt, "users"),
+        }
+    }
+}
+
+/// Seconds until the given time since the epoch, at least one.
+fn get_wait(reset: u64) -> u64 {
+    let now = chrono::Utc::now()
+        .timestamp()
+        .max(0) as u64;
+
+    reset.saturating_sub(now)
+        .max(1)
+}
+
+/// Collects up to `limit` results, capped at [`SEARCH_LIMIT`]. When the search
+/// rate limit runs out the search waits for it to reset.
+pub(crate) fn try_search<T>(client: &Client, kind: SearchKind, query: &SearchQuery, limit: usize) -> GitHubResult<SearchResults<T>, SearchError>
+where T: DeserializeOwned + FmtDebug {
+    let limit = limit.min(SEARCH_LIMIT);
+    let query = query.get_query();
+
+    let mut collection: Option<SearchResults<T>> = None;
+    let mut retries = 0;
+    let mut page = 0;
+
+    let per_page = limit.clamp(1, 100);
+
+    loop {
+
+        page += 1;
+
+        let response = {
+
+            let result = {
+
+                client.get(format!("search/{kind}"))?
+                    .query(&query)
+                    .query(&[("per_page", per_page), ("page", page)])
+                    .send()
+            };
+
+            match result {
+                Err(ClientError::Response(ClientResponseError::RateLimited { reset, .. })) => {
+                    let Some(reset) = reset.filter(|_| retries < SEARCH_RETRIES) else {
+                        return Err(SearchError::RateLimited {
+                            reset: reset.unwrap_or_default()
+                        })
+                    };
+
+                    let wait = get_wait(reset);
+
+                    if wait > SEARCH_WAIT {
+                        return Err(SearchError::RateLimited { reset })
+                    }
+
+                    std::thread::sleep(Duration::from_secs(wait));
+
+                    retries += 1;
+                    page -= 1;
+                    continue
+                },
+                Err(error) => return Err(error.into()),
+                Ok(response) => response,
+            }
+        };
+
+        let remaining = response.get_rate_limit_remaining();
+        let reset = response.get_rate_limit_reset();
+
+        let results: SearchResults<T> = response.json()?;
+        let count = results.items.len();
+
+        let collected = match collection {
+            Some(ref mut collection) => {
+                collection.items.extend(results.items);
+                collection.incomplete |= results.incomplete;
+                collection.items.len()
+            },
+            None => {
+                collection = Some(results);
+                count
+            },
+        };
+
+        let total = collection.as_ref()
+            .map(|collection| collection.total)
+            .unwrap_or_default();
+
+        if count < per_page || collected >= limit || collected >= total {
+            break
+        }
+
+        if let (Some(0), Some(reset)) = (remaining, reset) {
+            let wait = get_wait(reset);
+
+            if wait > SEARCH_WAIT {
+                return Err(SearchError::RateLimited { reset })
+            }
+
+            std::thread::sleep(Duration::from_secs(wait));
+        }
+    }
+
+    let mut collection = collection.unwrap_or(SearchResults {
+        total: 0,
+        incomplete: false,
+        items: Vec::new(),
+    });
+
+    collection.items.truncate(limit);
+
+    Ok(collection)
+}
@@ -0,0 +1,180 @@
+use hmac::{
+
+    Hmac,
+    Mac,
+};
+
+use http::{HeaderMap};
+use sha2::{Sha256};
+use thiserror::{Error};
+
+use crate::client::{
+
+    models::events::{
+
+        EventError,
+        Event,
+    },
+
+    GitHubResult,
+};
+
+pub const HEADER_SIGNATURE: &str = "x-hub-signature-256";
+pub const HEADER_EVENT: &str = "x-github-event";
+pub const HEADER_DELIVERY: &str = "x-github-delivery";
+pub const HEADER_HOOK: &str = "x-github-hook-id";
+
+#[derive(Error, Debug)]
+pub enum WebhookError {
+    #[error("Event error!")]
+    Event(#[from] EventError),
+    #[error("Missing header: '{name}'")]
+    Header { name: String },
+    #[error("Malformed signature: '{signature}'")]
+    Malformed { signature: String },
+    #[error("Signature does not match the payload!")]
+    Signature,
+}
+
+/// Checks a `sha256=<hex>` signature of the payload against the secret, the
+/// comparison takes the same time wherever the signatures differ.
+pub fn verify_signature(secret: impl AsRef<[u8]>, payload: impl AsRef<[u8]>, signature: impl AsRef<str>) -> GitHubResult<(), WebhookError> {
+    let signature = signature.as_ref().trim();
+
+    let digest = signature.strip_prefix("sha256=")
+        .and_then(|digest| hex::decode(digest).ok())
+        .ok_or_else(|| WebhookError::Malformed { signature: signature.to_owned() })?;
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_ref())
+        .expect("HMAC accepts keys of any length");
+
+    mac.update(payload.as_ref());
+    mac.verify_slice(digest.as_slice())
+        .map_err(|_| WebhookError::Signature)
+}
+
+/// A webhook delivery, the payload is read into the same [`Event`] as the one
+/// of a workflow run.
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+    pub(crate) event: String,
+    pub(crate) delivery: String,
+    pub(crate) hook: Option<String>,
+    pub(crate) payload: Event,
+}
+
+impl WebhookDelivery {
+    /// Reads a delivery from its headers and body. With a secret the body must
+    /// be signed with it, without one the signature is not checked.
+    pub fn try_from_request(headers: &HeaderMap, body: impl AsRef<[u8]>, secret: Option<&str>) -> GitHubResult<WebhookDelivery, WebhookError> {
+        let header = |name: &str| {
+
+            headers.get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.to_owned())
+        };
+
+        let require = |name: &str| header(name).ok_or_else(|| WebhookError::Header {
+            name: name.to_owned()
+        });
+
+        if let Some(secret) = secret {
+            verify_signature(secret, body.as_ref(), require(HEADER_SIGNATURE)?)?;
+        }
+
+        Ok(WebhookDelivery {
+            payload: Event::try_from_payload(require(HEADER_EVENT)?, body)?,
+            event: require(HEADER_EVENT)?,
+            delivery: require(HEADER_DELIVERY)?,
+            hook: header(HEADER_HOOK),
+        })
+    }
+
+    /// Name of the event, such as `issue_comment`.
+    pub fn get_event(&self) -> String {
+        self.event.clone()
+    }
+
+    /// Unique id of the delivery, redeliveries keep the id.
+    pub fn get_delivery(&self) -> String {
+        self.delivery.clone()
+    }
+
+    pub fn get_hook(&self) -> Option<String> {
+        self.hook.clone()
+    }
+
+    pub fn get_payload(&self) -> &Event {
+        &(self.payload)
+    }
+
+    pub fn into_payload(self) -> Event {
+        self.payload
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use hmac::{
+
+        Hmac,
+        Mac,
+    };
+
+    use http::{HeaderMap};
+    use sha2::{Sha256};
+
+    use crate::client::models::events::{Event};
+
+    use super::{
+
+        verify_signature,
+        WebhookDelivery,
+        WebhookError,
+        HEADER_SIGNATURE,
+        HEADER_DELIVERY,
+        HEADER_EVENT,
+        HEADER_HOOK,
+    };
+
+    #[test]
+    fn test_verify_signature() {
+        let signature = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+
+        assert!(verify_signature("It's a Secret to Everybody", "Hello, World!", signature).is_ok());
+        assert!(matches!(verify_signature("Wrong", "Hello, World!", signature), Err(WebhookError::Signature)));
+        assert!(matches!(verify_signature("Wrong", "Hello, World!", "sha1=abc"), Err(WebhookError::Malformed { .. })));
+    }
+
+    #[test]
+    fn test_delivery() {
+        let body = r#"{"zen":"Keep it logically awesome.","hook_id":12345678,"hook":{"type":"Repository","id":12345678}}"#;
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"secret").unwrap();
+        mac.update(body.as_bytes());
+
+        let mut headers = HeaderMap::new();
+
+        headers.insert(HEADER_EVENT, "ping".parse().unwrap());
+        headers.insert(HEADER_DELIVERY, "72d3162e-cc78-11e3-81ab-4c9367dc0958".parse().unwrap());
+        headers.insert(HEADER_HOOK, "12345678".parse().unwrap());
+        headers.insert(HEADER_SIGNATURE, format!("sha256={signature}", signature = {
+            hex::encode(mac.finalize().into_bytes())
+        }).parse().unwrap());
+
+        let delivery = WebhookDelivery::try_from_request(&headers, body, Some("secret")).unwrap();
+
+        assert_eq!(delivery.get_event(), "ping");
+        assert_eq!(delivery.get_delivery(), "72d3162e-cc78-11e3-81ab-4c9367dc0958");
+        assert_eq!(delivery.get_hook().as_deref(), Some("12345678"));
+        assert_eq!(delivery.get_payload().get_name(), "ping");
+        assert!(matches!(delivery.get_payload(), Event::Other { payload, .. } if payload["hook_id"] == 12345678));
+
+        let result = WebhookDelivery::try_from_request(&headers, body, Some("wrong"));
+        assert!(matches!(result, Err(WebhookError::Signature)));
+
+        let result = WebhookDelivery::try_from_request(&headers, "{", None);
+        assert!(matches!(result, Err(WebhookError::Event(_))));
+    }
+}
@@ -7,6 +7,12 @@ pub fn debug(message: impl AsRef<str>)  {
     }
 }
 
+pub fn info(message: impl AsRef<str>) {
+    println!("{message}", message = {
+        message.as_ref()
+    })
+}
+
 pub fn notice(message: impl AsRef<str>) {
     match super::command::issue_message("notice", message.as_ref()) {
         Err(_) => log::info!("{message}", message = {
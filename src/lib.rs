@@ -1,2 +1,3 @@
 pub mod client;
+pub mod cache;
 pub mod core;
\ No newline at end of file